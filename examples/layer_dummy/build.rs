@@ -0,0 +1,11 @@
+use ash_layer::ash::vk;
+use ash_layer::manifest::ManifestBuilder;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    ManifestBuilder::new("VK_LAYER_ASH_LAYER_dummy", 1)
+        .api_version(vk::API_VERSION_1_0)
+        .write()
+        .unwrap();
+}
@@ -0,0 +1,151 @@
+use ash_layer::ash::extensions::khr;
+use ash_layer::ash::{self, vk};
+use ash_layer::registry::commands::vkQueueSubmit;
+use ash_layer::*;
+use dashmap::DashMap;
+use once_cell::sync::Lazy;
+use std::ops::ControlFlow;
+
+macro_rules! function {
+    () => {{
+        fn f() {}
+        fn type_name_of<T>(_: T) -> &'static str {
+            std::any::type_name::<T>()
+        }
+        let name = type_name_of(f);
+
+        // Find and cut the rest of the path
+        match &name[..name.len() - 3].rfind(':') {
+            Some(pos) => &name[pos + 1..name.len() - 3],
+            None => &name[..name.len() - 3],
+        }
+    }};
+}
+
+macro_rules! log {
+    ($($arg:tt)+) =>
+    (eprintln!("[Ash Layer Dummy][{}] {}", function!(), format_args!($($arg)*)));
+}
+
+#[allow(dead_code)]
+struct LayerInstance {
+    ash_instance: ash::Instance,
+}
+
+#[allow(dead_code)]
+struct LayerDevice {
+    ash_device: ash::Device,
+    khr_swapchain: khr::Swapchain,
+}
+
+// DashMap ensures thread-safely and can be consider as faster mutex guarded HashMap,
+// keyed by loader dispatch key so child handles (e.g. vk::Queue) resolve to their parent
+static INSTANCE_MAP: Lazy<DashMap<DispatchKey, LayerInstance>> = Lazy::new(DashMap::new);
+static DEVICE_MAP: Lazy<DashMap<DispatchKey, LayerDevice>> = Lazy::new(DashMap::new);
+
+hook!(DummyLayer, vkQueuePresentKHR =>
+    unsafe fn dummy_queue_present_khr(
+        queue: vk::Queue,
+        p_present_info: *const vk::PresentInfoKHR,
+    ) -> vk::Result {
+        let device = match queue.dispatch_key().and_then(|key| DEVICE_MAP.get(&key)) {
+            Some(v) => v,
+            None => return vk::Result::ERROR_DEVICE_LOST,
+        };
+        log!("presenting on {:?}", queue);
+        (device.khr_swapchain.fp().queue_present_khr)(queue, p_present_info)
+    }
+);
+
+unsafe fn dummy_pre_queue_submit(
+    _device: &DeviceDispatch,
+    &mut (queue, submit_count, p_submits, _): &mut Args<vkQueueSubmit>,
+) -> ControlFlow<vk::Result> {
+    let submits = slice_from_raw(p_submits, submit_count);
+    let command_buffers: u32 = submits.iter().map(|v| v.command_buffer_count).sum();
+    log!(
+        "submitting {} command buffers in {} batches on {:?}",
+        command_buffers,
+        submits.len(),
+        queue
+    );
+    ControlFlow::Continue(())
+}
+
+unsafe fn dummy_post_queue_submit(
+    _device: &DeviceDispatch,
+    (queue, ..): &Args<vkQueueSubmit>,
+    result: &mut vk::Result,
+) {
+    log!("submitted on {:?}: {:?}", queue, result);
+}
+
+const SUBMIT_LOGGER: Module<DummyLayer> = Module::new(
+    "submit logger",
+    &[Interceptor::new::<vkQueueSubmit>(
+        Some(dummy_pre_queue_submit),
+        Some(dummy_post_queue_submit),
+    )],
+);
+
+struct DummyLayer;
+
+declare_layer!(DummyLayer);
+
+impl Layer for DummyLayer {
+    const NAME: &'static str = "VK_LAYER_ASH_LAYER_dummy";
+    const DESCRIPTION: &'static str = "Ash Layer Example - dummy";
+    const HOOKS: HookTable = hook_table![vkQueuePresentKHR => dummy_queue_present_khr];
+    const MODULES: &'static [Module<Self>] = &[SUBMIT_LOGGER];
+
+    unsafe fn create_instance(
+        create_info: &vk::InstanceCreateInfo,
+        instance: &InstanceDispatch,
+    ) -> vk::Result {
+        log!("created {:?}", instance.handle());
+        for name in CStrList::instance_extensions(create_info).iter() {
+            log!("enabled {:?}", name);
+        }
+        INSTANCE_MAP.insert(
+            instance.key(),
+            LayerInstance {
+                ash_instance: instance.table().clone(),
+            },
+        );
+        vk::Result::SUCCESS
+    }
+
+    unsafe fn destroy_instance(instance: &InstanceDispatch) {
+        log!("destroying {:?}", instance.handle());
+        INSTANCE_MAP.remove(&instance.key());
+    }
+
+    unsafe fn create_device(
+        _create_info: &vk::DeviceCreateInfo,
+        instance: &InstanceDispatch,
+        device: &DeviceDispatch,
+    ) -> vk::Result {
+        log!(
+            "created {:?} on {:?}",
+            device.handle(),
+            device.physical_device()
+        );
+        DEVICE_MAP.insert(
+            device.key(),
+            LayerDevice {
+                ash_device: device.table().clone(),
+                khr_swapchain: khr::Swapchain::new(instance.table(), device.table()),
+            },
+        );
+        vk::Result::SUCCESS
+    }
+
+    unsafe fn destroy_device(device: &DeviceDispatch) {
+        log!("destroying {:?}", device.handle());
+        DEVICE_MAP.remove(&device.key());
+    }
+
+    fn enumerate_instance_extensions(extensions: &mut Vec<vk::ExtensionProperties>) {
+        log!("{} instance extensions", extensions.len());
+    }
+}
@@ -0,0 +1,295 @@
+//! Generates `src/registry/generated.rs` of `ash-layer` from the Vulkan registry.
+//!
+//! ```bash
+//! cargo run -p generator -- path/to/vk.xml
+//! ```
+
+mod xml;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::{env, fs, process};
+
+use xml::Element;
+
+const DEFAULT_OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src/registry/generated.rs");
+
+struct Command {
+    /// Command whose `PFN_*` type ash defines, differing for aliases.
+    pfn: String,
+    return_type: String,
+    dispatchable: Option<&'static str>,
+    success_codes: Vec<String>,
+    error_codes: Vec<String>,
+    providers: Vec<String>,
+}
+
+struct Registry {
+    version: String,
+    commands: BTreeMap<String, Command>,
+    instance_extensions: BTreeSet<String>,
+}
+
+fn main() {
+    let args: Vec<_> = env::args().skip(1).collect();
+    let (input, output) = match args.as_slice() {
+        [input] => (input.as_str(), DEFAULT_OUTPUT),
+        [input, output] => (input.as_str(), output.as_str()),
+        _ => {
+            eprintln!("usage: generator <vk.xml> [output]");
+            process::exit(2);
+        }
+    };
+    if let Err(err) = run(input, output) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+fn run(input: &str, output: &str) -> Result<(), String> {
+    let src = fs::read_to_string(input).map_err(|e| format!("{}: {}", input, e))?;
+    let root = xml::parse(&src)?;
+    let registry = Registry::load(&root)?;
+    fs::write(output, registry.generate()).map_err(|e| format!("{}: {}", output, e))
+}
+
+/// Whether an element applies to Vulkan rather than only to Vulkan SC.
+fn is_vulkan(element: &Element, attr: &str) -> bool {
+    element
+        .attr(attr)
+        .map_or(true, |v| v.split(',').any(|v| v == "vulkan"))
+}
+
+fn codes(element: &Element, attr: &str) -> Vec<String> {
+    let codes = element.attr(attr).unwrap_or_default().split(',');
+    codes
+        .filter_map(|v| v.strip_prefix("VK_"))
+        .map(str::to_string)
+        .collect()
+}
+
+fn child_text(element: &Element, name: &str) -> Result<String, String> {
+    let child = element
+        .child(name)
+        .ok_or_else(|| format!("<{}> without <{}>", element.name, name))?;
+    Ok(child.text().trim().to_string())
+}
+
+impl Registry {
+    fn load(root: &Element) -> Result<Self, String> {
+        let mut commands = BTreeMap::new();
+        let mut aliases = Vec::new();
+        let elements = root.elements_named("commands");
+        for element in elements.flat_map(|v| v.elements_named("command")) {
+            if !is_vulkan(element, "api") {
+                continue;
+            }
+            if let (Some(name), Some(alias)) = (element.attr("name"), element.attr("alias")) {
+                aliases.push((name.to_string(), alias.to_string()));
+                continue;
+            }
+            let proto = element.child("proto").ok_or("<command> without <proto>")?;
+            let name = child_text(proto, "name")?;
+            let first = element
+                .elements_named("param")
+                .find(|v| is_vulkan(v, "api"))
+                .map(|v| child_text(v, "type"))
+                .transpose()?;
+            let dispatchable = match first.as_deref() {
+                Some("VkInstance") => Some("Instance"),
+                Some("VkPhysicalDevice") => Some("PhysicalDevice"),
+                Some("VkDevice") => Some("Device"),
+                Some("VkQueue") => Some("Queue"),
+                Some("VkCommandBuffer") => Some("CommandBuffer"),
+                _ => None,
+            };
+            let command = Command {
+                pfn: name.clone(),
+                return_type: child_text(proto, "type")?,
+                dispatchable,
+                success_codes: codes(element, "successcodes"),
+                error_codes: codes(element, "errorcodes"),
+                providers: Vec::new(),
+            };
+            commands.insert(name, command);
+        }
+        for (name, alias) in aliases {
+            let target = commands
+                .get(&alias)
+                .ok_or_else(|| format!("{} aliases unknown {}", name, alias))?;
+            let command = Command {
+                pfn: target.pfn.clone(),
+                return_type: target.return_type.clone(),
+                dispatchable: target.dispatchable,
+                success_codes: target.success_codes.clone(),
+                error_codes: target.error_codes.clone(),
+                providers: Vec::new(),
+            };
+            commands.insert(name, command);
+        }
+
+        let mut version = (1, 0);
+        for feature in root.elements_named("feature") {
+            if !is_vulkan(feature, "api") {
+                continue;
+            }
+            let number = feature.attr("number").ok_or("<feature> without number")?;
+            let (major, minor) = number
+                .split_once('.')
+                .and_then(|(a, b)| Some((a.parse().ok()?, b.parse().ok()?)))
+                .ok_or_else(|| format!("malformed feature number {}", number))?;
+            version = version.max((major, minor));
+            let provider = format!("Core(vk::API_VERSION_{}_{})", major, minor);
+            add_provider(&mut commands, feature, &provider);
+        }
+
+        let mut instance_extensions = BTreeSet::new();
+        let elements = root.elements_named("extensions");
+        for extension in elements.flat_map(|v| v.elements_named("extension")) {
+            if !is_vulkan(extension, "supported") {
+                continue;
+            }
+            let name = extension.attr("name").ok_or("<extension> without name")?;
+            if extension.attr("type") == Some("instance") {
+                instance_extensions.insert(name.to_string());
+            }
+            let provider = format!("Extension({:?})", name);
+            add_provider(&mut commands, extension, &provider);
+        }
+        // commands of disabled extensions or Vulkan SC only
+        commands.retain(|_, v| !v.providers.is_empty());
+
+        let header = root
+            .elements_named("types")
+            .flat_map(|v| v.elements_named("type"))
+            .filter(|v| is_vulkan(v, "api"))
+            .find(|v| v.child("name").map(Element::text).as_deref() == Some("VK_HEADER_VERSION"))
+            .ok_or("VK_HEADER_VERSION not found")?;
+        let header = header.text();
+        let patch = header
+            .rsplit("VK_HEADER_VERSION")
+            .next()
+            .map(str::trim)
+            .filter(|v| v.parse::<u32>().is_ok())
+            .ok_or("malformed VK_HEADER_VERSION")?;
+
+        Ok(Self {
+            version: format!("{}.{}.{}", version.0, version.1, patch),
+            commands,
+            instance_extensions,
+        })
+    }
+
+    fn generate(&self) -> String {
+        let mut out = String::new();
+        let w = &mut out;
+        let _ = writeln!(
+            w,
+            "// Generated by the generator crate from vk.xml {}, do not edit.",
+            self.version
+        );
+        let _ = writeln!(w, "\nuse super::*;\n");
+        let _ = writeln!(w, "use Dispatchable::*;\nuse Provider::*;\n");
+
+        let _ = writeln!(w, "pub(super) const COMMANDS: &[Command] = &[");
+        for (name, command) in &self.commands {
+            let dispatchable = match command.dispatchable {
+                Some(v) => format!("Some({})", v),
+                None => "None".to_string(),
+            };
+            let _ = writeln!(
+                w,
+                "    Command::new({:?}, {}, &[{}], &[{}], &[{}]),",
+                name,
+                dispatchable,
+                command.providers.join(", "),
+                results(&command.success_codes),
+                results(&command.error_codes),
+            );
+        }
+        let _ = writeln!(w, "];\n");
+
+        let _ = writeln!(w, "pub(super) const INSTANCE_EXTENSIONS: &[&str] = &[");
+        for name in &self.instance_extensions {
+            let _ = writeln!(w, "    {:?},", name);
+        }
+        let _ = writeln!(w, "];\n");
+
+        let _ = writeln!(
+            w,
+            "/// Typed hook of every command, checking the signature of its function."
+        );
+        let _ = writeln!(w, "pub mod hooks {{");
+        let _ = writeln!(w, "    #![allow(non_snake_case)]\n");
+        let _ = writeln!(w, "    use crate::{{CommandLevel::*, Hook}};\n");
+        let _ = writeln!(w, "    use core::mem;\n");
+        let _ = writeln!(w, "    use ash::vk;\n");
+        for (name, command) in &self.commands {
+            let _ = writeln!(
+                w,
+                "    pub const fn {name}(pfn: vk::PFN_{pfn}) -> Hook {{ \
+                 Hook::new({level}, {name:?}, unsafe {{ \
+                 mem::transmute::<vk::PFN_{pfn}, unsafe extern \"system\" fn()>(pfn) }}) }}",
+                name = name,
+                pfn = command.pfn,
+                level = level(command),
+            );
+        }
+        let _ = writeln!(w, "}}\n");
+
+        let _ = writeln!(
+            w,
+            "/// Marker type of every command called on a dispatchable handle, but the loader ones."
+        );
+        let _ = writeln!(w, "pub mod commands {{");
+        let _ = writeln!(w, "    #![allow(non_camel_case_types)]\n");
+        let _ = writeln!(w, "    use crate::registry::TypedCommand;");
+        let _ = writeln!(w, "    use crate::CommandLevel::{{self, *}};\n");
+        let _ = writeln!(w, "    use ash::vk;\n");
+        for (name, command) in &self.commands {
+            if command.dispatchable.is_none() || command.return_type == "PFN_vkVoidFunction" {
+                continue;
+            }
+            let _ = writeln!(
+                w,
+                "    pub struct {name}; impl TypedCommand for {name} {{ \
+                 const NAME: &'static str = {name:?}; const LEVEL: CommandLevel = {level}; \
+                 type Pfn = vk::PFN_{pfn}; }}",
+                name = name,
+                level = level(command),
+                pfn = command.pfn,
+            );
+        }
+        let _ = writeln!(w, "}}");
+        out
+    }
+}
+
+fn add_provider(commands: &mut BTreeMap<String, Command>, element: &Element, provider: &str) {
+    let requires = element.elements_named("require");
+    let requires = requires.filter(|v| is_vulkan(v, "api"));
+    for name in requires
+        .flat_map(|v| v.elements_named("command"))
+        .filter_map(|v| v.attr("name"))
+    {
+        if let Some(command) = commands.get_mut(name) {
+            if !command.providers.iter().any(|v| v == provider) {
+                command.providers.push(provider.to_string());
+            }
+        }
+    }
+}
+
+fn level(command: &Command) -> &'static str {
+    match command.dispatchable {
+        None => "Global",
+        Some("Instance") => "Instance",
+        Some("PhysicalDevice") => "PhysicalDevice",
+        Some(_) => "Device",
+    }
+}
+
+fn results(codes: &[String]) -> String {
+    let codes = codes.iter().map(|v| format!("vk::Result::{}", v));
+    codes.collect::<Vec<_>>().join(", ")
+}
@@ -0,0 +1,226 @@
+//! Just enough of XML to read `vk.xml`.
+
+pub struct Element {
+    pub name: String,
+    pub attrs: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    fn new(name: String, attrs: Vec<(String, String)>) -> Self {
+        Self {
+            name,
+            attrs,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|v| match v {
+            Node::Element(v) => Some(v),
+            Node::Text(_) => None,
+        })
+    }
+
+    pub fn elements_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.elements().filter(move |v| v.name == name)
+    }
+
+    pub fn child<'a>(&'a self, name: &'a str) -> Option<&'a Element> {
+        self.elements_named(name).next()
+    }
+
+    /// Text of the element and its descendants.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        self.push_text(&mut text);
+        text
+    }
+
+    fn push_text(&self, text: &mut String) {
+        for child in &self.children {
+            match child {
+                Node::Element(v) => v.push_text(text),
+                Node::Text(v) => text.push_str(v),
+            }
+        }
+    }
+}
+
+/// Parses the root element of `src`.
+pub fn parse(src: &str) -> Result<Element, String> {
+    let mut parser = Parser { src, pos: 0 };
+    let mut stack = vec![Element::new(String::new(), Vec::new())];
+    while parser.pos < src.len() {
+        let rest = parser.rest();
+        if let Some(rest) = rest.strip_prefix("<!--") {
+            parser.skip_past(rest, "-->")?;
+        } else if let Some(rest) = rest.strip_prefix("<![CDATA[") {
+            let end = rest.find("]]>").ok_or("unterminated CDATA")?;
+            let text = rest[..end].to_string();
+            parser.pos += "<![CDATA[".len() + end + "]]>".len();
+            push(&mut stack, Node::Text(text));
+        } else if rest.starts_with("<?") || rest.starts_with("<!") {
+            parser.skip_past(rest, ">")?;
+        } else if let Some(rest) = rest.strip_prefix("</") {
+            let end = rest.find('>').ok_or("unterminated end tag")?;
+            let name = rest[..end].trim();
+            parser.pos += 2 + end + 1;
+            let element = stack.pop().filter(|_| !stack.is_empty());
+            match element {
+                Some(v) if v.name == name => push(&mut stack, Node::Element(v)),
+                _ => return Err(format!("unexpected end tag {}", name)),
+            }
+        } else if rest.starts_with('<') {
+            let (element, empty) = parser.start_tag()?;
+            if empty {
+                push(&mut stack, Node::Element(element));
+            } else {
+                stack.push(element);
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = unescape(&rest[..end]);
+            parser.pos += end;
+            push(&mut stack, Node::Text(text));
+        }
+    }
+    let document = stack
+        .pop()
+        .filter(|_| stack.is_empty())
+        .ok_or("unclosed element")?;
+    document
+        .children
+        .into_iter()
+        .find_map(|v| match v {
+            Node::Element(v) => Some(v),
+            Node::Text(_) => None,
+        })
+        .ok_or_else(|| "no root element".to_string())
+}
+
+fn push(stack: &mut [Element], node: Node) {
+    stack.last_mut().unwrap().children.push(node);
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+
+    fn skip_past(&mut self, rest: &str, end: &str) -> Result<(), String> {
+        let skipped = self.rest().len() - rest.len();
+        let found = rest.find(end).ok_or_else(|| format!("missing {}", end))?;
+        self.pos += skipped + found + end.len();
+        Ok(())
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn name(&mut self) -> &'a str {
+        let rest = self.rest();
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '/' | '>' | '='))
+            .unwrap_or(rest.len());
+        self.pos += end;
+        &rest[..end]
+    }
+
+    fn start_tag(&mut self) -> Result<(Element, bool), String> {
+        self.pos += 1;
+        let name = self.name().to_string();
+        let mut attrs = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.pos += 2;
+                break Ok((Element::new(name, attrs), true));
+            } else if rest.starts_with('>') {
+                self.pos += 1;
+                break Ok((Element::new(name, attrs), false));
+            }
+            let key = self.name().to_string();
+            if key.is_empty() {
+                break Err(format!("malformed tag {}", name));
+            }
+            self.skip_whitespace();
+            let rest = self
+                .rest()
+                .strip_prefix('=')
+                .ok_or_else(|| format!("attribute {} without value", key))?
+                .trim_start();
+            let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\''));
+            let quote = quote.ok_or_else(|| format!("unquoted attribute {}", key))?;
+            let end = rest[1..]
+                .find(quote)
+                .ok_or_else(|| format!("unterminated attribute {}", key))?;
+            let value = unescape(&rest[1..1 + end]);
+            self.pos += self.rest().len() - rest.len() + 1 + end + 1;
+            attrs.push((key, value));
+        }
+    }
+}
+
+fn unescape(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = match rest.find(';') {
+            Some(v) => v,
+            None => break,
+        };
+        let entity = &rest[1..end];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => match entity.strip_prefix("#x") {
+                Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                None => entity
+                    .strip_prefix('#')
+                    .and_then(|v| v.parse().ok())
+                    .and_then(char::from_u32),
+            },
+        };
+        match c {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,635 @@
+use crate::*;
+
+use core::cell::Cell;
+use core::ffi::{c_char, c_void, CStr};
+use core::mem;
+use core::ptr;
+use std::collections::BTreeMap;
+use std::ffi::CString;
+use std::sync::{Arc, RwLock};
+
+use ash::prelude::VkResult;
+use ash::vk;
+use ash::vk::Handle;
+
+/// Loader dispatch table pointer stored at the start of every dispatchable object.
+///
+/// Child objects share the key of their parent, physical devices that of their instance and
+/// queues and command buffers that of their device.
+#[repr(transparent)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DispatchKey(pub(crate) usize);
+impl DispatchKey {
+    #[inline]
+    pub const fn from_raw(x: usize) -> Self {
+        Self(x)
+    }
+    #[inline]
+    pub const fn as_raw(self) -> usize {
+        self.0
+    }
+}
+
+/// Handle of a dispatchable Vulkan object.
+pub trait DispatchableHandle: Handle + Copy {
+    /// Dispatch of the instance or device owning objects of this type.
+    type Dispatch: Dispatch;
+
+    /// Reads the loader dispatch key, `*(void **)handle`, or `None` for a null handle.
+    #[inline]
+    unsafe fn dispatch_key(self) -> Option<DispatchKey> {
+        let ptr = self.as_raw() as usize as *const *const c_void;
+        if ptr.is_null() {
+            None
+        } else {
+            Some(DispatchKey(ptr.read() as usize))
+        }
+    }
+}
+impl DispatchableHandle for vk::Instance {
+    type Dispatch = InstanceDispatch;
+}
+impl DispatchableHandle for vk::PhysicalDevice {
+    type Dispatch = InstanceDispatch;
+}
+impl DispatchableHandle for vk::Device {
+    type Dispatch = DeviceDispatch;
+}
+impl DispatchableHandle for vk::Queue {
+    type Dispatch = DeviceDispatch;
+}
+impl DispatchableHandle for vk::CommandBuffer {
+    type Dispatch = DeviceDispatch;
+}
+
+/// Registry of instance or device dispatches.
+pub trait Dispatch: Sized + 'static {
+    fn from_key(key: DispatchKey) -> Option<Arc<Self>>;
+
+    #[doc(hidden)]
+    fn interception(&self) -> &Interception;
+
+    /// Resolves command `name` of the next layer at the level of the dispatch.
+    #[doc(hidden)]
+    unsafe fn next_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction;
+
+    /// Looks up the dispatch owning `handle`, which may be a child object.
+    #[inline]
+    unsafe fn from_handle<H: DispatchableHandle<Dispatch = Self>>(handle: H) -> Option<Arc<Self>> {
+        Self::from_key(handle.dispatch_key()?)
+    }
+}
+
+/// Looks up the instance or device dispatch owning any dispatchable handle.
+#[inline]
+pub unsafe fn dispatch_of<H: DispatchableHandle>(handle: H) -> Option<Arc<H::Dispatch>> {
+    H::Dispatch::from_handle(handle)
+}
+
+static INSTANCES: RwLock<BTreeMap<DispatchKey, Arc<InstanceDispatch>>> =
+    RwLock::new(BTreeMap::new());
+static DEVICES: RwLock<BTreeMap<DispatchKey, Arc<DeviceDispatch>>> = RwLock::new(BTreeMap::new());
+
+thread_local! {
+    static LOADING_GET_INSTANCE_PROC_ADDR: Cell<Option<vk::PFN_vkGetInstanceProcAddr>> =
+        Cell::new(None);
+}
+
+// IMPORTANT: device commands must be resolved through the device specific vkGetDeviceProcAddr of
+//            the next layer rather than the one got from vkGetInstanceProcAddr, as the later would
+//            somehow crash on execution. So the instance table, which is also what ash extension
+//            loaders use, gets a vkGetDeviceProcAddr looking up the registered device dispatch.
+unsafe extern "system" fn loading_get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    if CStr::from_ptr(p_name).to_bytes() == b"vkGetDeviceProcAddr" {
+        let pfn: *const () = dispatch_next_get_device_proc_addr as _;
+        return mem::transmute::<*const (), vk::PFN_vkVoidFunction>(pfn);
+    }
+    let get_instance_proc_addr = LOADING_GET_INSTANCE_PROC_ADDR.with(Cell::get)?;
+    get_instance_proc_addr(instance, p_name)
+}
+const _: vk::PFN_vkGetInstanceProcAddr = loading_get_instance_proc_addr;
+
+unsafe extern "system" fn dispatch_next_get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    let dispatch = DeviceDispatch::from_handle(device)?;
+    (dispatch.get_device_proc_addr)(device, p_name)
+}
+const _: vk::PFN_vkGetDeviceProcAddr = dispatch_next_get_device_proc_addr;
+
+/// Next-layer entry points and function table of an instance.
+pub struct InstanceDispatch {
+    key: DispatchKey,
+    get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    get_physical_device_proc_addr: Option<PFN_vk_layerGetPhysicalDeviceProcAddr>,
+    set_instance_loader_data: Option<PFN_vkSetInstanceLoaderData>,
+    layer_device: Option<LayerInstanceCreateInfoLayerDevice>,
+    layer_get_instance_proc_addr: Option<vk::PFN_vkGetInstanceProcAddr>,
+    loader_features: LoaderFeatureFlagBits,
+    api_version: u32,
+    enabled_extensions: Vec<CString>,
+    interception: Interception,
+    table: ash::Instance,
+}
+
+impl InstanceDispatch {
+    /// Loads the function table of `instance` from the next layer's `vkGetInstanceProcAddr`.
+    pub unsafe fn new(
+        instance: vk::Instance,
+        get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+        get_physical_device_proc_addr: Option<PFN_vk_layerGetPhysicalDeviceProcAddr>,
+    ) -> Option<Self> {
+        let key = instance.dispatch_key()?;
+        LOADING_GET_INSTANCE_PROC_ADDR.with(|v| v.set(Some(get_instance_proc_addr)));
+        let table = ash::Instance::load(
+            &vk::StaticFn {
+                get_instance_proc_addr: loading_get_instance_proc_addr,
+            },
+            instance,
+        );
+        LOADING_GET_INSTANCE_PROC_ADDR.with(|v| v.set(None));
+        Some(Self {
+            key,
+            get_instance_proc_addr,
+            get_physical_device_proc_addr,
+            set_instance_loader_data: None,
+            layer_device: None,
+            layer_get_instance_proc_addr: None,
+            loader_features: LoaderFeatureFlagBits::empty(),
+            api_version: vk::API_VERSION_1_0,
+            enabled_extensions: Vec::new(),
+            interception: Interception::default(),
+            table,
+        })
+    }
+
+    /// Keeps the API version and extensions enabled by `create_info` along with the loader
+    /// callbacks from its chain, which stay valid for the lifetime of the instance.
+    pub(crate) unsafe fn capture_create_info(&mut self, create_info: &vk::InstanceCreateInfo) {
+        if let Some(app_info) = create_info.p_application_info.as_ref() {
+            self.api_version = api_version_or_default(app_info.api_version);
+        }
+        self.enabled_extensions = to_owned(CStrList::instance_extensions(create_info));
+        let function = LayerFunction::LOADER_DATA_CALLBACK;
+        if let Ok(info) = get_instance_chain_info(create_info, function) {
+            if let InstanceChainKind::LoaderDataCallback(v) = info.as_ref().kind() {
+                self.set_instance_loader_data = v;
+            }
+        }
+        let function = LayerFunction::LOADER_LAYER_CREATE_DEVICE_CALLBACK;
+        if let Ok(info) = get_instance_chain_info(create_info, function) {
+            if let InstanceChainKind::CreateDeviceCallback(v) = info.as_ref().kind() {
+                self.layer_device = Some(v);
+            }
+        }
+        let function = LayerFunction::LOADER_FEATURES;
+        if let Ok(info) = get_instance_chain_info(create_info, function) {
+            if let InstanceChainKind::LoaderFeatures(v) = info.as_ref().kind() {
+                self.loader_features = v;
+            }
+        }
+    }
+
+    /// Sets the `vkGetInstanceProcAddr` of the layer owning the dispatch, which identifies it to
+    /// the loader in [`Self::create_layer_device`].
+    pub(crate) fn set_layer_get_instance_proc_addr(
+        &mut self,
+        get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    ) {
+        self.layer_get_instance_proc_addr = Some(get_instance_proc_addr);
+    }
+
+    /// Resolves the next layer's commands intercepted by the modules of `L`.
+    pub(crate) unsafe fn intercept<L: Layer>(&mut self) {
+        let levels = &[CommandLevel::Instance, CommandLevel::PhysicalDevice];
+        self.interception = Interception::new::<L>(levels, |name, level| match level {
+            CommandLevel::PhysicalDevice => self.get_physical_device_proc_addr(name),
+            _ => self.get_instance_proc_addr(name),
+        });
+    }
+
+    /// Registers the dispatch, replacing any previous one with the same key.
+    pub fn register(self) -> Arc<Self> {
+        let this = Arc::new(self);
+        INSTANCES.write().unwrap().insert(this.key, this.clone());
+        this
+    }
+
+    pub fn unregister(key: DispatchKey) -> Option<Arc<Self>> {
+        INSTANCES.write().unwrap().remove(&key)
+    }
+
+    #[inline]
+    pub fn key(&self) -> DispatchKey {
+        self.key
+    }
+
+    #[inline]
+    pub fn handle(&self) -> vk::Instance {
+        self.table.handle()
+    }
+
+    #[inline]
+    pub fn table(&self) -> &ash::Instance {
+        &self.table
+    }
+
+    /// Next layer's `vkGetInstanceProcAddr`.
+    #[inline]
+    pub fn next_get_instance_proc_addr(&self) -> vk::PFN_vkGetInstanceProcAddr {
+        self.get_instance_proc_addr
+    }
+
+    /// Next layer's `vk_layerGetPhysicalDeviceProcAddr`, if the loader provided one.
+    #[inline]
+    pub fn next_get_physical_device_proc_addr(
+        &self,
+    ) -> Option<PFN_vk_layerGetPhysicalDeviceProcAddr> {
+        self.get_physical_device_proc_addr
+    }
+
+    #[inline]
+    pub unsafe fn get_instance_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction {
+        (self.get_instance_proc_addr)(self.handle(), name.as_ptr())
+    }
+
+    /// Resolves physical-device command `name` of the next layer, through its
+    /// `vk_layerGetPhysicalDeviceProcAddr` first as needed for commands unknown to the loader.
+    pub unsafe fn get_physical_device_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction {
+        let pfn = match self.get_physical_device_proc_addr {
+            Some(v) => v(self.handle(), name.as_ptr()),
+            None => None,
+        };
+        pfn.or_else(|| self.get_instance_proc_addr(name))
+    }
+
+    /// Features of the loader, empty if it predates passing them.
+    ///
+    /// With [`LoaderFeatureFlagBits::PHYSICAL_DEVICE_SORTING`] the loader already sorts physical
+    /// devices and groups, so layers reordering them should not expect their order to be kept.
+    #[inline]
+    pub fn loader_features(&self) -> LoaderFeatureFlagBits {
+        self.loader_features
+    }
+
+    /// API version requested by the application, 1.0 if not specified.
+    #[inline]
+    pub fn api_version(&self) -> u32 {
+        self.api_version
+    }
+
+    /// Instance extensions enabled by the application.
+    #[inline]
+    pub fn enabled_extensions(&self) -> &[CString] {
+        &self.enabled_extensions
+    }
+
+    #[inline]
+    pub fn is_extension_enabled(&self, name: &str) -> bool {
+        self.enabled_extensions
+            .iter()
+            .any(|v| v.as_bytes() == name.as_bytes())
+    }
+
+    /// Whether `vkGetInstanceProcAddr` may return command `name`.
+    ///
+    /// Commands of instance extensions must have been enabled, while core commands of any version
+    /// and device extension commands are returned as loaders do. Commands unknown to
+    /// [`registry`] are assumed enabled.
+    pub fn is_command_enabled(&self, name: &CStr) -> bool {
+        let command = match registry::command(name) {
+            Some(v) => v,
+            None => return true,
+        };
+        command.providers.iter().any(|v| match *v {
+            registry::Provider::Core(_) => true,
+            registry::Provider::Extension(ext) => {
+                !registry::is_instance_extension(ext) || self.is_extension_enabled(ext)
+            }
+        })
+    }
+
+    /// Loader callback setting the dispatch of objects created by the layer, if the loader
+    /// provided one.
+    #[inline]
+    pub fn set_instance_loader_data(&self) -> Option<PFN_vkSetInstanceLoaderData> {
+        self.set_instance_loader_data
+    }
+
+    /// Sets the loader dispatch of `handle`, created by the layer itself rather than returned
+    /// through the loader, so it can be passed down the chain.
+    pub unsafe fn init_dispatchable<H: DispatchableHandle<Dispatch = Self>>(
+        &self,
+        handle: H,
+    ) -> VkResult<()> {
+        let set_loader_data = self
+            .set_instance_loader_data
+            .map(|v| move |object| v(self.handle(), object));
+        init_dispatchable(self.key, handle, set_loader_data)
+    }
+
+    /// Loader callbacks creating devices private to a layer, if the loader provided them.
+    #[inline]
+    pub fn layer_device_callbacks(&self) -> Option<LayerInstanceCreateInfoLayerDevice> {
+        self.layer_device
+    }
+
+    /// Creates a device through the layers below this one only, hidden from the application and
+    /// the layers above, e.g. to run helper work on another physical device.
+    ///
+    /// The device must be destroyed with [`Self::destroy_layer_device`].
+    pub unsafe fn create_layer_device(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        create_info: &vk::DeviceCreateInfo,
+        allocator: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<ash::Device> {
+        let function = LayerFunction::LOADER_LAYER_CREATE_DEVICE_CALLBACK;
+        let create_device = self
+            .layer_device
+            .and_then(|v| v.pfn_layer_create_device)
+            .ok_or(ChainError::MissingChainInfo(function))?;
+        // only set for dispatches created by the vkCreateInstance of a layer
+        let layer_get_instance_proc_addr = self
+            .layer_get_instance_proc_addr
+            .ok_or(vk::Result::ERROR_INITIALIZATION_FAILED)?;
+
+        let mut device = vk::Device::null();
+        let mut get_device_proc_addr = None;
+        create_device(
+            self.handle(),
+            physical_device,
+            create_info,
+            allocator.map_or(ptr::null(), |v| v),
+            &mut device,
+            Some(layer_get_instance_proc_addr),
+            &mut get_device_proc_addr,
+        )
+        .result()?;
+
+        let mut instance_fn = self.table.fp_v1_0().clone();
+        // always set by the loader on success
+        instance_fn.get_device_proc_addr =
+            get_device_proc_addr.ok_or(vk::Result::ERROR_INITIALIZATION_FAILED)?;
+        Ok(ash::Device::load(&instance_fn, device))
+    }
+
+    /// Destroys a device created by [`Self::create_layer_device`].
+    pub unsafe fn destroy_layer_device(
+        &self,
+        device: &ash::Device,
+        allocator: Option<&vk::AllocationCallbacks>,
+    ) {
+        let p_allocator = allocator.map_or(ptr::null(), |v| v as *const _);
+        let destroy_device = device.fp_v1_0().destroy_device;
+        match self.layer_device.and_then(|v| v.pfn_layer_destroy_device) {
+            Some(layer_destroy_device) => {
+                layer_destroy_device(device.handle(), p_allocator, destroy_device)
+            }
+            None => destroy_device(device.handle(), p_allocator),
+        }
+    }
+}
+
+impl Dispatch for InstanceDispatch {
+    fn from_key(key: DispatchKey) -> Option<Arc<Self>> {
+        INSTANCES.read().unwrap().get(&key).cloned()
+    }
+
+    fn interception(&self) -> &Interception {
+        &self.interception
+    }
+
+    unsafe fn next_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction {
+        // falls back to vkGetInstanceProcAddr for other than physical-device commands
+        self.get_physical_device_proc_addr(name)
+    }
+}
+
+/// Next-layer entry points and function table of a device.
+pub struct DeviceDispatch {
+    key: DispatchKey,
+    instance_key: DispatchKey,
+    physical_device: vk::PhysicalDevice,
+    get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    set_device_loader_data: Option<PFN_vkSetDeviceLoaderData>,
+    api_version: u32,
+    enabled_extensions: Vec<CString>,
+    interception: Interception,
+    table: ash::Device,
+}
+
+impl DeviceDispatch {
+    /// Loads the function table of `device` from the next layer's `vkGetDeviceProcAddr`.
+    pub unsafe fn new(
+        instance: &InstanceDispatch,
+        physical_device: vk::PhysicalDevice,
+        device: vk::Device,
+        get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    ) -> Option<Self> {
+        let key = device.dispatch_key()?;
+        // the device is not registered yet, load directly from the next vkGetDeviceProcAddr
+        let mut instance_fn = instance.table.fp_v1_0().clone();
+        instance_fn.get_device_proc_addr = get_device_proc_addr;
+        let table = ash::Device::load(&instance_fn, device);
+        Some(Self {
+            key,
+            instance_key: instance.key,
+            physical_device,
+            get_device_proc_addr,
+            set_device_loader_data: None,
+            api_version: vk::API_VERSION_1_0,
+            enabled_extensions: Vec::new(),
+            interception: Interception::default(),
+            table,
+        })
+    }
+
+    /// Keeps the extensions enabled by `create_info` along with the loader callbacks from its
+    /// chain, which stay valid for the lifetime of the device.
+    pub(crate) unsafe fn capture_create_info(
+        &mut self,
+        instance: &InstanceDispatch,
+        create_info: &vk::DeviceCreateInfo,
+    ) {
+        let properties = instance
+            .table
+            .get_physical_device_properties(self.physical_device);
+        self.api_version = instance.api_version.min(properties.api_version);
+        self.enabled_extensions = to_owned(CStrList::device_extensions(create_info));
+        let function = LayerFunction::LOADER_DATA_CALLBACK;
+        if let Ok(info) = get_device_chain_info(create_info, function) {
+            if let DeviceChainKind::LoaderDataCallback(v) = info.as_ref().kind() {
+                self.set_device_loader_data = v;
+            }
+        }
+    }
+
+    /// Resolves the next layer's commands intercepted by the modules of `L`.
+    pub(crate) unsafe fn intercept<L: Layer>(&mut self) {
+        let levels = &[CommandLevel::Device];
+        self.interception =
+            Interception::new::<L>(levels, |name, _| self.get_device_proc_addr(name));
+    }
+
+    /// Registers the dispatch, replacing any previous one with the same key.
+    pub fn register(self) -> Arc<Self> {
+        let this = Arc::new(self);
+        DEVICES.write().unwrap().insert(this.key, this.clone());
+        this
+    }
+
+    pub fn unregister(key: DispatchKey) -> Option<Arc<Self>> {
+        DEVICES.write().unwrap().remove(&key)
+    }
+
+    #[inline]
+    pub fn key(&self) -> DispatchKey {
+        self.key
+    }
+
+    #[inline]
+    pub fn handle(&self) -> vk::Device {
+        self.table.handle()
+    }
+
+    #[inline]
+    pub fn physical_device(&self) -> vk::PhysicalDevice {
+        self.physical_device
+    }
+
+    /// Dispatch of the instance owning this device.
+    #[inline]
+    pub fn instance(&self) -> Option<Arc<InstanceDispatch>> {
+        InstanceDispatch::from_key(self.instance_key)
+    }
+
+    #[inline]
+    pub fn table(&self) -> &ash::Device {
+        &self.table
+    }
+
+    /// Next layer's `vkGetDeviceProcAddr`.
+    #[inline]
+    pub fn next_get_device_proc_addr(&self) -> vk::PFN_vkGetDeviceProcAddr {
+        self.get_device_proc_addr
+    }
+
+    #[inline]
+    pub unsafe fn get_device_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction {
+        (self.get_device_proc_addr)(self.handle(), name.as_ptr())
+    }
+
+    /// Effective API version, the lower of those of the instance and the physical device.
+    #[inline]
+    pub fn api_version(&self) -> u32 {
+        self.api_version
+    }
+
+    /// Device extensions enabled by the application.
+    #[inline]
+    pub fn enabled_extensions(&self) -> &[CString] {
+        &self.enabled_extensions
+    }
+
+    #[inline]
+    pub fn is_extension_enabled(&self, name: &str) -> bool {
+        self.enabled_extensions
+            .iter()
+            .any(|v| v.as_bytes() == name.as_bytes())
+    }
+
+    /// Whether `vkGetDeviceProcAddr` may return command `name`.
+    ///
+    /// Device-level commands must be core in the effective API version or provided by an
+    /// enabled instance or device extension. Commands unknown to [`registry`] are assumed
+    /// enabled.
+    pub fn is_command_enabled(&self, name: &CStr) -> bool {
+        let command = match registry::command(name) {
+            Some(v) => v,
+            None => return true,
+        };
+        if command.level != CommandLevel::Device {
+            return false;
+        }
+        let instance = self.instance();
+        command.providers.iter().any(|v| match *v {
+            registry::Provider::Core(version) => version <= self.api_version,
+            registry::Provider::Extension(ext) => {
+                self.is_extension_enabled(ext)
+                    || instance
+                        .as_ref()
+                        .map_or(false, |v| v.is_extension_enabled(ext))
+            }
+        })
+    }
+
+    /// Loader callback setting the dispatch of objects created by the layer, if the loader
+    /// provided one.
+    #[inline]
+    pub fn set_device_loader_data(&self) -> Option<PFN_vkSetDeviceLoaderData> {
+        self.set_device_loader_data
+    }
+
+    /// Sets the loader dispatch of `handle`, e.g. a queue or command buffer the layer got by
+    /// calling down itself, so it can be passed down the chain.
+    pub unsafe fn init_dispatchable<H: DispatchableHandle<Dispatch = Self>>(
+        &self,
+        handle: H,
+    ) -> VkResult<()> {
+        let set_loader_data = self
+            .set_device_loader_data
+            .map(|v| move |object| v(self.handle(), object));
+        init_dispatchable(self.key, handle, set_loader_data)
+    }
+}
+
+fn api_version_or_default(api_version: u32) -> u32 {
+    if api_version == 0 {
+        vk::API_VERSION_1_0
+    } else {
+        api_version
+    }
+}
+
+fn to_owned(names: CStrList) -> Vec<CString> {
+    names.iter().map(CStr::to_owned).collect()
+}
+
+unsafe fn init_dispatchable<H: DispatchableHandle>(
+    key: DispatchKey,
+    handle: H,
+    set_loader_data: Option<impl FnOnce(*mut c_void) -> vk::Result>,
+) -> VkResult<()> {
+    let object = handle.as_raw() as usize as *mut c_void;
+    if object.is_null() {
+        return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+    }
+    match set_loader_data {
+        Some(set_loader_data) => set_loader_data(object).result(),
+        // loaders predating the callback expect the parent's dispatch to be copied
+        None => {
+            object.cast::<usize>().write(key.0);
+            Ok(())
+        }
+    }
+}
+
+impl Dispatch for DeviceDispatch {
+    fn from_key(key: DispatchKey) -> Option<Arc<Self>> {
+        DEVICES.read().unwrap().get(&key).cloned()
+    }
+
+    fn interception(&self) -> &Interception {
+        &self.interception
+    }
+
+    unsafe fn next_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction {
+        self.get_device_proc_addr(name)
+    }
+}
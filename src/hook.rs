@@ -0,0 +1,405 @@
+use core::ffi::CStr;
+
+/// Object a command is dispatched on, deciding which `vkGet*ProcAddr` returns it.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum CommandLevel {
+    /// Called without an instance, e.g. `vkCreateInstance`.
+    Global,
+    Instance,
+    PhysicalDevice,
+    /// Called on a device, queue or command buffer.
+    Device,
+}
+
+/// Command intercepted by a layer.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy)]
+pub struct Hook {
+    pub level: CommandLevel,
+    pub name: &'static str,
+    pub pfn: unsafe extern "system" fn(),
+}
+
+impl Hook {
+    #[inline]
+    pub const fn new(
+        level: CommandLevel,
+        name: &'static str,
+        pfn: unsafe extern "system" fn(),
+    ) -> Self {
+        Self { level, name, pfn }
+    }
+}
+
+const EMPTY_SLOT: u16 = u16::MAX;
+
+/// Hooks looked up by a perfect hash of their names computed at compile time, see
+/// [`hook_table!`](crate::hook_table).
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy)]
+pub struct HookTable {
+    hooks: &'static [Hook],
+    seeds: &'static [u32],
+    slots: &'static [u16],
+}
+
+impl HookTable {
+    pub const EMPTY: Self = Self {
+        hooks: &[],
+        seeds: &[],
+        slots: &[],
+    };
+
+    /// Assembles a table from the [`HookTable::index`] of `hooks`, use
+    /// [`hook_table!`](crate::hook_table) instead.
+    #[doc(hidden)]
+    pub const fn new(hooks: &'static [Hook], seeds: &'static [u32], slots: &'static [u16]) -> Self {
+        assert!(seeds.len() == hooks.len() && slots.len() >= hooks.len());
+        Self {
+            hooks,
+            seeds,
+            slots,
+        }
+    }
+
+    /// Finds a seed per bucket of names, `B` being the number of hooks, so that every name
+    /// hashes to its own slot out of `M`.
+    ///
+    /// Panics on duplicated names, which fails the build when evaluated in a constant.
+    #[doc(hidden)]
+    pub const fn index<const B: usize, const M: usize>(hooks: &[Hook]) -> ([u32; B], [u16; M]) {
+        let n = hooks.len();
+        assert!(B == n && M >= n && n < EMPTY_SLOT as usize);
+        let mut hashes = [0; B];
+        let mut sizes = [0; B];
+        let mut max_size = 0;
+        let mut i = 0;
+        while i < n {
+            hashes[i] = hash(hooks[i].name.as_bytes());
+            let b = bucket(hashes[i], B);
+            sizes[b] += 1;
+            if sizes[b] > max_size {
+                max_size = sizes[b];
+            }
+            i += 1;
+        }
+        // names grouped by bucket, those of bucket b in members[starts[b]..starts[b] + sizes[b]]
+        let mut starts = [0; B];
+        let mut b = 1;
+        while b < B {
+            starts[b] = starts[b - 1] + sizes[b - 1];
+            b += 1;
+        }
+        let mut members = [0; B];
+        let mut filled = [0; B];
+        let mut i = 0;
+        while i < n {
+            let b = bucket(hashes[i], B);
+            members[starts[b] + filled[b]] = i;
+            filled[b] += 1;
+            i += 1;
+        }
+
+        let mut seeds = [0; B];
+        let mut slots = [EMPTY_SLOT; M];
+        // largest buckets first, while most slots are free
+        let mut size = max_size;
+        while size > 0 {
+            let mut b = 0;
+            while b < B {
+                if sizes[b] == size {
+                    let (start, end) = (starts[b], starts[b] + size);
+                    let mut seed = 0;
+                    while !fits(hooks, &hashes, &members, start, end, seed, &slots) {
+                        seed += 1;
+                    }
+                    seeds[b] = seed;
+                    let mut k = start;
+                    while k < end {
+                        let i = members[k];
+                        slots[slot(hashes[i], seed, M)] = i as u16;
+                        k += 1;
+                    }
+                }
+                b += 1;
+            }
+            size -= 1;
+        }
+        (seeds, slots)
+    }
+
+    /// Hook of command `name`.
+    pub fn get(&self, name: &CStr) -> Option<&'static Hook> {
+        if self.hooks.is_empty() {
+            return None;
+        }
+        let name = name.to_bytes();
+        let hash = hash(name);
+        let seed = self.seeds[bucket(hash, self.seeds.len())];
+        let index = self.slots[slot(hash, seed, self.slots.len())];
+        let hook = self.hooks.get(index as usize)?;
+        (hook.name.as_bytes() == name).then_some(hook)
+    }
+
+    /// Function of command `name` if hooked at one of `levels`.
+    #[inline]
+    pub fn pfn(&self, name: &CStr, levels: &[CommandLevel]) -> Option<unsafe extern "system" fn()> {
+        let hook = self.get(name)?;
+        levels.contains(&hook.level).then_some(hook.pfn)
+    }
+
+    #[inline]
+    pub fn hooks(&self) -> &'static [Hook] {
+        self.hooks
+    }
+}
+
+/// Function pointer of the `system` ABI, taking up to 15 parameters.
+pub trait SystemFn: Copy + 'static {}
+
+macro_rules! impl_system_fn {
+    ($($arg:ident),*) => {
+        impl<$($arg,)* R> SystemFn for unsafe extern "system" fn($($arg),*) -> R
+        where
+            $($arg: 'static,)*
+            R: 'static,
+        {
+        }
+    };
+}
+
+impl_system_fn!();
+impl_system_fn!(A0);
+impl_system_fn!(A0, A1);
+impl_system_fn!(A0, A1, A2);
+impl_system_fn!(A0, A1, A2, A3);
+impl_system_fn!(A0, A1, A2, A3, A4);
+impl_system_fn!(A0, A1, A2, A3, A4, A5);
+impl_system_fn!(A0, A1, A2, A3, A4, A5, A6);
+impl_system_fn!(A0, A1, A2, A3, A4, A5, A6, A7);
+impl_system_fn!(A0, A1, A2, A3, A4, A5, A6, A7, A8);
+impl_system_fn!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_system_fn!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+impl_system_fn!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+impl_system_fn!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
+impl_system_fn!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13);
+impl_system_fn!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14);
+
+/// Passes `pfn` through, failing the build unless it is a [`SystemFn`], for
+/// [`hook_table!`](crate::hook_table).
+#[inline]
+pub const fn system_fn<F: SystemFn>(pfn: F) -> F {
+    pfn
+}
+
+/// Builds a [`HookTable`] in a constant, failing the build on duplicated names.
+///
+/// Commands are named as in [`registry::hooks`](crate::registry::hooks), which checks the
+/// signature of their function. Commands unknown to the registry are given with their level
+/// and name instead, functions being cast to their `PFN_*` type, which must be a [`SystemFn`].
+/// Hooks are written with [`hook!`](crate::hook), which keeps a panic from unwinding into the
+/// loader.
+///
+/// ```
+/// use ash_layer::ash::vk;
+/// use ash_layer::*;
+/// use std::ffi::CStr;
+/// use std::mem;
+///
+/// struct ExampleLayer;
+///
+/// impl Layer for ExampleLayer {}
+///
+/// hook!(ExampleLayer, vkQueuePresentKHR =>
+///     unsafe fn queue_present(
+///         queue: vk::Queue,
+///         p_present_info: *const vk::PresentInfoKHR,
+///     ) -> vk::Result {
+///         let device = match dispatch_of(queue) {
+///             Some(v) => v,
+///             None => return vk::Result::ERROR_DEVICE_LOST,
+///         };
+///         // extension commands are resolved from the next layer
+///         let name = CStr::from_bytes_with_nul_unchecked(b"vkQueuePresentKHR\0");
+///         let queue_present = match device.get_device_proc_addr(name) {
+///             Some(v) => mem::transmute::<_, vk::PFN_vkQueuePresentKHR>(v),
+///             None => return vk::Result::ERROR_EXTENSION_NOT_PRESENT,
+///         };
+///         queue_present(queue, p_present_info)
+///     }
+/// );
+///
+/// const HOOKS: HookTable = hook_table![
+///     vkQueuePresentKHR => queue_present,
+/// ];
+/// const UNKNOWN_HOOKS: HookTable = hook_table![
+///     Device "vkQueuePresentFOO" => queue_present as vk::PFN_vkQueuePresentKHR,
+/// ];
+/// ```
+#[macro_export]
+macro_rules! hook_table {
+    (@hooks $($hook:expr),*) => {{
+        const HOOKS: &[$crate::Hook] = &[$($hook),*];
+        const INDEX: ([u32; HOOKS.len()], [u16; 2 * HOOKS.len()]) =
+            $crate::HookTable::index(HOOKS);
+        const SEEDS: &[u32] = &INDEX.0;
+        const SLOTS: &[u16] = &INDEX.1;
+        $crate::HookTable::new(HOOKS, SEEDS, SLOTS)
+    }};
+    ($($name:ident => $pfn:expr),* $(,)?) => {
+        $crate::hook_table!(@hooks $($crate::registry::hooks::$name($pfn)),*)
+    };
+    ($($level:ident $name:literal => $pfn:expr),* $(,)?) => {
+        $crate::hook_table!(@hooks $(
+            $crate::Hook::new($crate::CommandLevel::$level, $name, {
+                let pfn = $crate::system_fn($pfn);
+                // a no-op for hooks taking no parameter
+                #[allow(clippy::useless_transmute)]
+                let pfn = unsafe { ::core::mem::transmute::<_, unsafe extern "system" fn()>(pfn) };
+                pfn
+            })
+        ),*)
+    };
+}
+
+/// Defines a hook for [`hook_table!`](crate::hook_table) whose body runs under
+/// [`catch_panic`](crate::catch_panic) with the panic policy of a layer, as a panic must not
+/// unwind into the loader.
+///
+/// Commands are named as in [`registry::hooks`](crate::registry::hooks), which checks the
+/// signature, or given as a string. The first parameter must be a dispatchable handle, through
+/// which [`PanicPolicy::PassThrough`](crate::PanicPolicy::PassThrough) calls down.
+///
+/// ```
+/// use ash_layer::ash::vk;
+/// use ash_layer::*;
+/// use std::ffi::CStr;
+/// use std::mem;
+///
+/// struct ExampleLayer;
+///
+/// impl Layer for ExampleLayer {
+///     const HOOKS: HookTable = hook_table![vkQueuePresentKHR => queue_present];
+/// }
+///
+/// hook!(ExampleLayer, vkQueuePresentKHR =>
+///     unsafe fn queue_present(
+///         queue: vk::Queue,
+///         p_present_info: *const vk::PresentInfoKHR,
+///     ) -> vk::Result {
+///         let device = match dispatch_of(queue) {
+///             Some(v) => v,
+///             None => return vk::Result::ERROR_DEVICE_LOST,
+///         };
+///         // extension commands are resolved from the next layer
+///         let name = CStr::from_bytes_with_nul_unchecked(b"vkQueuePresentKHR\0");
+///         let queue_present = match device.get_device_proc_addr(name) {
+///             Some(v) => mem::transmute::<_, vk::PFN_vkQueuePresentKHR>(v),
+///             None => return vk::Result::ERROR_EXTENSION_NOT_PRESENT,
+///         };
+///         queue_present(queue, p_present_info)
+///     }
+/// );
+/// ```
+#[macro_export]
+macro_rules! hook {
+    ($layer:ty, $command:ident => $(#[$attr:meta])* $vis:vis unsafe fn $name:ident $($rest:tt)*) => {
+        $crate::hook!(
+            $layer,
+            <$crate::registry::commands::$command as $crate::registry::TypedCommand>::NAME =>
+            $(#[$attr])* $vis unsafe fn $name $($rest)*
+        );
+        const _: <$crate::registry::commands::$command as $crate::registry::TypedCommand>::Pfn =
+            $name;
+    };
+    (
+        $layer:ty, $command:expr =>
+        $(#[$attr:meta])*
+        $vis:vis unsafe fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)? $body:block
+    ) => {
+        $(#[$attr])*
+        $vis unsafe extern "system" fn $name($($arg: $ty),*) $(-> $ret)? {
+            unsafe fn $name($($arg: $ty),*) $(-> $ret)? $body
+            $crate::call_hook::<$layer, unsafe extern "system" fn($($ty),*) $(-> $ret)?>(
+                $command,
+                ($($arg,)*),
+                |($($arg,)*)| $name($($arg),*),
+            )
+        }
+    };
+}
+
+// FNV-1a
+const fn hash(bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+const fn bucket(hash: u64, buckets: usize) -> usize {
+    (hash % buckets as u64) as usize
+}
+
+const fn slot(hash: u64, seed: u32, slots: usize) -> usize {
+    let mut x = hash ^ (seed as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    x ^= x >> 33;
+    (x % slots as u64) as usize
+}
+
+/// Whether the names in `members[start..end]` get free and distinct slots with `seed`.
+///
+/// Panics on duplicated names, which never do.
+const fn fits<const B: usize, const M: usize>(
+    hooks: &[Hook],
+    hashes: &[u64; B],
+    members: &[usize; B],
+    start: usize,
+    end: usize,
+    seed: u32,
+    slots: &[u16; M],
+) -> bool {
+    let mut k = start;
+    while k < end {
+        let i = members[k];
+        let s = slot(hashes[i], seed, M);
+        if slots[s] != EMPTY_SLOT {
+            return false;
+        }
+        let mut l = start;
+        while l < k {
+            let j = members[l];
+            if hashes[i] == hashes[j] && str_eq(hooks[i].name, hooks[j].name) {
+                panic!("duplicated hook name");
+            }
+            if slot(hashes[j], seed, M) == s {
+                return false;
+            }
+            l += 1;
+        }
+        k += 1;
+    }
+    true
+}
+
+pub(crate) const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,407 @@
+use crate::registry::TypedCommand;
+use crate::*;
+
+use core::ffi::CStr;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::ControlFlow;
+use std::ffi::CString;
+
+use ash::vk;
+
+/// Return type of an intercepted command.
+pub trait CommandResult: Copy {
+    /// Returned when the layer fails the call, e.g. after a panic with
+    /// [`PanicPolicy::ReturnError`] or for an unknown dispatchable handle.
+    const ERROR: Self;
+}
+impl CommandResult for () {
+    const ERROR: Self = ();
+}
+impl CommandResult for vk::Result {
+    const ERROR: Self = vk::Result::ERROR_UNKNOWN;
+}
+impl CommandResult for u32 {
+    const ERROR: Self = 0;
+}
+impl CommandResult for u64 {
+    const ERROR: Self = 0;
+}
+
+/// Function pointer type of a command called on a dispatchable handle.
+pub trait Signature: Copy + 'static {
+    type First: DispatchableHandle;
+    /// Parameters as a tuple.
+    type Args: Copy;
+    type Output: CommandResult;
+
+    fn dispatchable(args: &Self::Args) -> Self::First;
+
+    unsafe fn from_void(pfn: unsafe extern "system" fn()) -> Self;
+
+    unsafe fn call(self, args: Self::Args) -> Self::Output;
+
+    /// Entry point running the [`Interceptor`]s of command `C` of layer `L`.
+    fn trampoline<L: Layer, C: TypedCommand<Pfn = Self>>() -> Self;
+}
+
+macro_rules! impl_signature {
+    ($first:ident $(, $arg:ident)*) => {
+        impl<$first, $($arg,)* R> Signature for unsafe extern "system" fn($first, $($arg),*) -> R
+        where
+            $first: DispatchableHandle + 'static,
+            $($arg: Copy + 'static,)*
+            R: CommandResult + 'static,
+        {
+            type First = $first;
+            type Args = ($first, $($arg,)*);
+            type Output = R;
+
+            #[inline]
+            fn dispatchable(args: &Self::Args) -> $first {
+                args.0
+            }
+
+            #[inline]
+            unsafe fn from_void(pfn: unsafe extern "system" fn()) -> Self {
+                mem::transmute::<unsafe extern "system" fn(), Self>(pfn)
+            }
+
+            #[inline]
+            #[allow(non_snake_case)]
+            unsafe fn call(self, ($first, $($arg,)*): Self::Args) -> R {
+                self($first, $($arg),*)
+            }
+
+            fn trampoline<L: Layer, C: TypedCommand<Pfn = Self>>() -> Self {
+                #[allow(non_snake_case)]
+                unsafe extern "system" fn trampoline<L, C, $first, $($arg,)* R>(
+                    $first: $first,
+                    $($arg: $arg,)*
+                ) -> R
+                where
+                    L: Layer,
+                    C: TypedCommand<Pfn = unsafe extern "system" fn($first, $($arg),*) -> R>,
+                    $first: DispatchableHandle + 'static,
+                    $($arg: Copy + 'static,)*
+                    R: CommandResult + 'static,
+                {
+                    intercept::<L, C>(($first, $($arg,)*))
+                }
+                trampoline::<L, C, $first, $($arg,)* R>
+            }
+        }
+    };
+}
+
+impl_signature!(A0);
+impl_signature!(A0, A1);
+impl_signature!(A0, A1, A2);
+impl_signature!(A0, A1, A2, A3);
+impl_signature!(A0, A1, A2, A3, A4);
+impl_signature!(A0, A1, A2, A3, A4, A5);
+impl_signature!(A0, A1, A2, A3, A4, A5, A6);
+impl_signature!(A0, A1, A2, A3, A4, A5, A6, A7);
+impl_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8);
+impl_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9);
+impl_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+impl_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+impl_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
+impl_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13);
+impl_signature!(A0, A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14);
+
+/// Parameters of command `C` as a tuple.
+pub type Args<C> = <<C as TypedCommand>::Pfn as Signature>::Args;
+pub type Output<C> = <<C as TypedCommand>::Pfn as Signature>::Output;
+/// Instance or device dispatch command `C` is called on.
+pub type DispatchOf<C> =
+    <<<C as TypedCommand>::Pfn as Signature>::First as DispatchableHandle>::Dispatch;
+
+/// Called before calling down, may modify the arguments passed down or return
+/// [`ControlFlow::Break`] to skip calling down with a result.
+pub type PreCall<C> = unsafe fn(&DispatchOf<C>, &mut Args<C>) -> ControlFlow<Output<C>>;
+/// Called after calling down with the arguments passed down, may modify the result.
+pub type PostCall<C> = unsafe fn(&DispatchOf<C>, &Args<C>, &mut Output<C>);
+
+/// Callbacks of a [`Module`] of layer `L` around a command.
+pub struct Interceptor<L: Layer> {
+    name: &'static str,
+    level: CommandLevel,
+    calls: Calls,
+    trampoline: fn() -> unsafe extern "system" fn(),
+    layer: PhantomData<fn() -> L>,
+}
+
+// not derived, which would require them of `L`
+impl<L: Layer> Clone for Interceptor<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<L: Layer> Copy for Interceptor<L> {}
+#[cfg(feature = "debug")]
+impl<L: Layer> core::fmt::Debug for Interceptor<L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Interceptor")
+            .field("name", &self.name)
+            .field("level", &self.level)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<L: Layer> Interceptor<L> {
+    /// Fails the build for the commands the layer implements through the methods of [`Layer`],
+    /// e.g. `vkCreateDevice`, which would never be intercepted.
+    ///
+    /// ```compile_fail
+    /// use ash_layer::registry::commands::vkCreateDevice;
+    /// use ash_layer::*;
+    ///
+    /// struct ExampleLayer;
+    /// impl Layer for ExampleLayer {}
+    ///
+    /// const MODULE: Module<ExampleLayer> =
+    ///     Module::new("create", &[Interceptor::new::<vkCreateDevice>(None, None)]);
+    /// ```
+    pub const fn new<C: TypedCommand>(
+        pre_call: Option<PreCall<C>>,
+        post_call: Option<PostCall<C>>,
+    ) -> Self {
+        let mut i = 0;
+        while i < BUILTIN_COMMANDS.len() {
+            if str_eq(BUILTIN_COMMANDS[i], C::NAME) {
+                panic!("command implemented through the methods of Layer");
+            }
+            i += 1;
+        }
+        let calls = Calls {
+            pre_call: match pre_call {
+                Some(v) => Some(unsafe { mem::transmute::<PreCall<C>, unsafe fn()>(v) }),
+                None => None,
+            },
+            post_call: match post_call {
+                Some(v) => Some(unsafe { mem::transmute::<PostCall<C>, unsafe fn()>(v) }),
+                None => None,
+            },
+        };
+        Self {
+            name: C::NAME,
+            level: C::LEVEL,
+            calls,
+            trampoline: trampoline::<L, C>,
+            layer: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[inline]
+    pub fn level(&self) -> CommandLevel {
+        self.level
+    }
+}
+
+fn trampoline<L: Layer, C: TypedCommand>() -> unsafe extern "system" fn() {
+    let pfn = <C::Pfn as Signature>::trampoline::<L, C>();
+    // only called back as `C::Pfn`
+    unsafe { mem::transmute_copy::<C::Pfn, unsafe extern "system" fn()>(&pfn) }
+}
+
+/// Runs hook `f` of `command` written with [`hook!`](crate::hook) under [`catch_panic`], calling
+/// down instead with [`PanicPolicy::PassThrough`].
+#[doc(hidden)]
+pub unsafe fn call_hook<L: Layer, F: Signature>(
+    command: &'static str,
+    args: F::Args,
+    f: impl FnOnce(F::Args) -> F::Output,
+) -> F::Output {
+    match catch_panic::<L, _>(command, || f(args)) {
+        Ok(v) => v,
+        Err(PanicPolicy::PassThrough) => {
+            let name = CString::new(command).unwrap_or_default();
+            let next = dispatch_of(F::dispatchable(&args)).and_then(|v| v.next_proc_addr(&name));
+            match next {
+                Some(next) => F::from_void(next).call(args),
+                None => CommandResult::ERROR,
+            }
+        }
+        Err(_) => CommandResult::ERROR,
+    }
+}
+
+/// Independent set of [`Interceptor`]s of layer `L`, see [`Layer::MODULES`].
+///
+/// A pre call modifies a copy of the arguments, which is discarded if it panics with
+/// [`PanicPolicy::PassThrough`]. Writes through pointer arguments are kept.
+pub struct Module<L: Layer> {
+    pub name: &'static str,
+    pub interceptors: &'static [Interceptor<L>],
+}
+
+impl<L: Layer> Clone for Module<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<L: Layer> Copy for Module<L> {}
+#[cfg(feature = "debug")]
+impl<L: Layer> core::fmt::Debug for Module<L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Module")
+            .field("name", &self.name)
+            .field("interceptors", &self.interceptors)
+            .finish()
+    }
+}
+
+impl<L: Layer> Module<L> {
+    #[inline]
+    pub const fn new(name: &'static str, interceptors: &'static [Interceptor<L>]) -> Self {
+        Self { name, interceptors }
+    }
+}
+
+/// Entry point running the interceptors of `name` in `modules`, if any is at one of `levels`.
+pub(crate) fn interceptor_pfn<L: Layer>(
+    modules: &[Module<L>],
+    name: &CStr,
+    levels: &[CommandLevel],
+) -> Option<unsafe extern "system" fn()> {
+    let name = name.to_bytes();
+    modules
+        .iter()
+        .flat_map(|v| v.interceptors)
+        .find(|v| v.name.as_bytes() == name && levels.contains(&v.level))
+        .map(|v| (v.trampoline)())
+}
+
+/// Pre and post calls of an [`Interceptor`], typed by its command.
+#[derive(Clone, Copy)]
+struct Calls {
+    pre_call: Option<unsafe fn()>,
+    post_call: Option<unsafe fn()>,
+}
+
+struct Entry {
+    name: &'static str,
+    next: vk::PFN_vkVoidFunction,
+    /// In the order of modules.
+    interceptors: Vec<Calls>,
+}
+
+/// Interceptors of the modules of a layer at the level of a dispatch, along with the next
+/// layer's commands they call down.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct Interception {
+    entries: Vec<Entry>,
+}
+
+impl Interception {
+    pub(crate) fn new<L: Layer>(
+        levels: &[CommandLevel],
+        mut next: impl FnMut(&CStr, CommandLevel) -> vk::PFN_vkVoidFunction,
+    ) -> Self {
+        let mut entries: Vec<Entry> = Vec::new();
+        for interceptor in L::MODULES.iter().flat_map(|v| v.interceptors) {
+            if !levels.contains(&interceptor.level) {
+                continue;
+            }
+            match entries.binary_search_by(|v| v.name.cmp(interceptor.name)) {
+                Ok(i) => entries[i].interceptors.push(interceptor.calls),
+                Err(i) => {
+                    let name = CString::new(interceptor.name).unwrap_or_default();
+                    let entry = Entry {
+                        name: interceptor.name,
+                        next: next(&name, interceptor.level),
+                        interceptors: vec![interceptor.calls],
+                    };
+                    entries.insert(i, entry);
+                }
+            }
+        }
+        Self { entries }
+    }
+
+    fn entry(&self, name: &str) -> Option<&Entry> {
+        let i = self.entries.binary_search_by(|v| v.name.cmp(name)).ok()?;
+        Some(&self.entries[i])
+    }
+
+    /// Whether intercepted command `name` can be called down, true if not intercepted at the
+    /// level of the dispatch.
+    pub(crate) fn is_supported_below(&self, name: &CStr) -> bool {
+        let name = name.to_str().unwrap_or_default();
+        self.entry(name).map_or(true, |v| v.next.is_some())
+    }
+}
+
+/// Runs the pre calls of the interceptors of `C` in the order of modules until one skips
+/// calling down, then the post calls of those before it in reverse order.
+unsafe fn intercept<L: Layer, C: TypedCommand>(mut args: Args<C>) -> Output<C> {
+    let handle = <C::Pfn as Signature>::dispatchable(&args);
+    let dispatch = match dispatch_of(handle) {
+        Some(v) => v,
+        None => {
+            fail::<L>(C::NAME, "called on an unknown handle");
+            return CommandResult::ERROR;
+        }
+    };
+    let interception = dispatch.interception();
+    let entry = match interception.entry(C::NAME) {
+        Some(v) => v,
+        None => {
+            fail::<L>(C::NAME, "not intercepted at the level of the handle");
+            return CommandResult::ERROR;
+        }
+    };
+
+    let mut output = None;
+    let mut entered = 0;
+    // modules whose pre call panicked, as if they did not intercept the command, with their
+    // changes to the arguments dropped
+    let mut passed_through = Vec::new();
+    for (i, interceptor) in entry.interceptors.iter().enumerate() {
+        if let Some(pre_call) = interceptor.pre_call {
+            let pre_call = mem::transmute::<unsafe fn(), PreCall<C>>(pre_call);
+            let mut flow = ControlFlow::Continue(());
+            let mut modified = args;
+            match catch_panic::<L, _>(C::NAME, || flow = pre_call(&dispatch, &mut modified)) {
+                Ok(()) => args = modified,
+                Err(PanicPolicy::PassThrough) => passed_through.push(i),
+                Err(_) => return CommandResult::ERROR,
+            }
+            if let ControlFlow::Break(v) = flow {
+                output = Some(v);
+                break;
+            }
+        }
+        entered += 1;
+    }
+
+    let mut output = match (output, entry.next) {
+        (Some(v), _) => v,
+        (None, Some(next)) => <C::Pfn as Signature>::from_void(next).call(args),
+        (None, None) => {
+            fail::<L>(C::NAME, "not supported by the layers below");
+            return CommandResult::ERROR;
+        }
+    };
+
+    for (i, interceptor) in entry.interceptors[..entered].iter().enumerate().rev() {
+        if passed_through.contains(&i) {
+            continue;
+        }
+        if let Some(post_call) = interceptor.post_call {
+            let post_call = mem::transmute::<unsafe fn(), PostCall<C>>(post_call);
+            match catch_panic::<L, _>(C::NAME, || post_call(&dispatch, &args, &mut output)) {
+                Ok(()) | Err(PanicPolicy::PassThrough) => {}
+                Err(_) => return CommandResult::ERROR,
+            }
+        }
+    }
+    output
+}
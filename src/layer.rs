@@ -0,0 +1,872 @@
+use crate::*;
+
+use core::any::TypeId;
+use core::ffi::{c_char, CStr};
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr;
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use ash::vk;
+
+/// Hooks of a Vulkan layer, wired to the loader by [`declare_layer!`].
+///
+/// Every hook has a pass-through default so a layer only implements what it needs.
+pub trait Layer: Sized + 'static {
+    /// Name as in the manifest, reported by the legacy `vkEnumerate*` exports.
+    const NAME: &'static str = "";
+    const DESCRIPTION: &'static str = "";
+    const SPEC_VERSION: u32 = vk::HEADER_VERSION_COMPLETE;
+    const IMPLEMENTATION_VERSION: u32 = 1;
+    /// Oldest loader-layer interface version accepted in negotiation.
+    const MIN_INTERFACE_VERSION: u32 = MIN_SUPPORTED_LOADER_LAYER_INTERFACE_VERSION;
+
+    /// Commands intercepted by this layer, usually built by [`hook_table!`].
+    ///
+    /// Looked up before the `*_hook` methods, each `vkGet*ProcAddr` returning the hooks of the
+    /// levels it serves.
+    const HOOKS: HookTable = HookTable::EMPTY;
+
+    /// Modules intercepting commands with pre and post calls, looked up after [`Layer::HOOKS`].
+    ///
+    /// Pre calls of a command run in the order of modules, post calls in reverse order. Creating
+    /// and destroying instances and devices is hooked through the methods above instead.
+    const MODULES: &'static [Module<Self>] = &[];
+
+    /// What to do when another method panics, or an intercepted command has nothing to call
+    /// down, may be decided at runtime, e.g. from the environment.
+    fn panic_policy() -> PanicPolicy {
+        PanicPolicy::Abort
+    }
+
+    /// Called after the next layer created the instance, an error destroys the instance again.
+    ///
+    /// `instance` is registered already, so that its child objects can be looked up.
+    unsafe fn create_instance(
+        _create_info: &vk::InstanceCreateInfo,
+        _instance: &InstanceDispatch,
+    ) -> vk::Result {
+        vk::Result::SUCCESS
+    }
+
+    /// Called before the instance is destroyed by the next layer.
+    unsafe fn destroy_instance(_instance: &InstanceDispatch) {}
+
+    /// Called after the next layer created the device, an error destroys the device again.
+    ///
+    /// `device` is registered already, so that ash extension loaders can resolve its commands.
+    unsafe fn create_device(
+        _create_info: &vk::DeviceCreateInfo,
+        _instance: &InstanceDispatch,
+        _device: &DeviceDispatch,
+    ) -> vk::Result {
+        vk::Result::SUCCESS
+    }
+
+    /// Called before the device is destroyed by the next layer.
+    unsafe fn destroy_device(_device: &DeviceDispatch) {}
+
+    /// Instance-level command intercepted by this layer, for lookups [`Layer::HOOKS`] can't
+    /// express.
+    fn instance_hook(_name: &CStr) -> vk::PFN_vkVoidFunction {
+        None
+    }
+
+    /// Physical-device-level command intercepted by this layer.
+    ///
+    /// Hooks of commands unknown to the loader are only exposed through
+    /// `vk_layerGetPhysicalDeviceProcAddr` if a layer below or the driver supports the command,
+    /// unless [`Layer::implements_command`] says otherwise. Such hooks call down through
+    /// [`InstanceDispatch::get_physical_device_proc_addr`].
+    fn physical_device_hook(_name: &CStr) -> vk::PFN_vkVoidFunction {
+        None
+    }
+
+    /// Device-level command intercepted by this layer.
+    fn device_hook(_name: &CStr) -> vk::PFN_vkVoidFunction {
+        None
+    }
+
+    /// Whether the hook of `name` implements the command itself instead of wrapping the one of
+    /// the next layer.
+    fn implements_command(_name: &CStr) -> bool {
+        false
+    }
+
+    /// Adjusts the instance extensions reported below this layer, e.g. to add its own ones.
+    ///
+    /// Only called if the manifest lists the [`PRE_INSTANCE_FUNCTIONS`].
+    fn enumerate_instance_extensions(_extensions: &mut Vec<vk::ExtensionProperties>) {}
+
+    /// Adjusts the instance layers reported below this layer.
+    fn enumerate_instance_layers(_layers: &mut Vec<vk::LayerProperties>) {}
+
+    /// Adjusts the instance version reported below this layer, e.g. to cap it.
+    fn enumerate_instance_version(api_version: u32) -> u32 {
+        api_version
+    }
+
+    /// Instance extensions implemented by this layer, reported by the legacy exports.
+    fn instance_extension_properties() -> Vec<vk::ExtensionProperties> {
+        Vec::new()
+    }
+
+    /// Device extensions implemented by this layer, reported by the legacy exports.
+    fn device_extension_properties() -> Vec<vk::ExtensionProperties> {
+        Vec::new()
+    }
+}
+
+/// Manifest `pre_instance_functions` entries, mapping commands to the symbols exported by
+/// [`declare_layer!`].
+pub const PRE_INSTANCE_FUNCTIONS: [(&str, &str); 3] = [
+    (
+        "vkEnumerateInstanceExtensionProperties",
+        "ash_layer_vkEnumerateInstanceExtensionProperties",
+    ),
+    (
+        "vkEnumerateInstanceLayerProperties",
+        "ash_layer_vkEnumerateInstanceLayerProperties",
+    ),
+    (
+        "vkEnumerateInstanceVersion",
+        "ash_layer_vkEnumerateInstanceVersion",
+    ),
+];
+
+/// Exports `vkNegotiateLoaderLayerInterfaceVersion` and the [`PRE_INSTANCE_FUNCTIONS`] for a
+/// type implementing [`Layer`].
+///
+/// The entry points looked up by name by loaders predating interface version 1 are exported as
+/// well, so the layer must not link to the Vulkan loader itself.
+#[macro_export]
+macro_rules! declare_layer {
+    ($layer:ty) => {
+        #[no_mangle]
+        pub unsafe extern "system" fn vkNegotiateLoaderLayerInterfaceVersion(
+            p_version_struct: *mut $crate::NegotiateLayerInterface,
+        ) -> $crate::ash::vk::Result {
+            $crate::layer_negotiate_loader_layer_interface_version::<$layer>(p_version_struct)
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn vkGetInstanceProcAddr(
+            instance: $crate::ash::vk::Instance,
+            p_name: *const ::core::ffi::c_char,
+        ) -> $crate::ash::vk::PFN_vkVoidFunction {
+            $crate::layer_get_instance_proc_addr::<$layer>(instance, p_name)
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn vkGetDeviceProcAddr(
+            device: $crate::ash::vk::Device,
+            p_name: *const ::core::ffi::c_char,
+        ) -> $crate::ash::vk::PFN_vkVoidFunction {
+            $crate::layer_get_device_proc_addr::<$layer>(device, p_name)
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn vkEnumerateInstanceLayerProperties(
+            p_property_count: *mut u32,
+            p_properties: *mut $crate::ash::vk::LayerProperties,
+        ) -> $crate::ash::vk::Result {
+            $crate::layer_legacy_enumerate_instance_layer_properties::<$layer>(
+                p_property_count,
+                p_properties,
+            )
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn vkEnumerateInstanceExtensionProperties(
+            p_layer_name: *const ::core::ffi::c_char,
+            p_property_count: *mut u32,
+            p_properties: *mut $crate::ash::vk::ExtensionProperties,
+        ) -> $crate::ash::vk::Result {
+            $crate::layer_legacy_enumerate_instance_extension_properties::<$layer>(
+                p_layer_name,
+                p_property_count,
+                p_properties,
+            )
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn vkEnumerateDeviceLayerProperties(
+            physical_device: $crate::ash::vk::PhysicalDevice,
+            p_property_count: *mut u32,
+            p_properties: *mut $crate::ash::vk::LayerProperties,
+        ) -> $crate::ash::vk::Result {
+            $crate::layer_legacy_enumerate_device_layer_properties::<$layer>(
+                physical_device,
+                p_property_count,
+                p_properties,
+            )
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn vkEnumerateDeviceExtensionProperties(
+            physical_device: $crate::ash::vk::PhysicalDevice,
+            p_layer_name: *const ::core::ffi::c_char,
+            p_property_count: *mut u32,
+            p_properties: *mut $crate::ash::vk::ExtensionProperties,
+        ) -> $crate::ash::vk::Result {
+            $crate::layer_legacy_enumerate_device_extension_properties::<$layer>(
+                physical_device,
+                p_layer_name,
+                p_property_count,
+                p_properties,
+            )
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn ash_layer_vkEnumerateInstanceExtensionProperties(
+            p_chain: *const $crate::EnumerateInstanceExtensionPropertiesChain,
+            p_layer_name: *const ::core::ffi::c_char,
+            p_property_count: *mut u32,
+            p_properties: *mut $crate::ash::vk::ExtensionProperties,
+        ) -> $crate::ash::vk::Result {
+            $crate::layer_enumerate_instance_extension_properties::<$layer>(
+                p_chain,
+                p_layer_name,
+                p_property_count,
+                p_properties,
+            )
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn ash_layer_vkEnumerateInstanceLayerProperties(
+            p_chain: *const $crate::EnumerateInstanceLayerPropertiesChain,
+            p_property_count: *mut u32,
+            p_properties: *mut $crate::ash::vk::LayerProperties,
+        ) -> $crate::ash::vk::Result {
+            $crate::layer_enumerate_instance_layer_properties::<$layer>(
+                p_chain,
+                p_property_count,
+                p_properties,
+            )
+        }
+
+        #[no_mangle]
+        pub unsafe extern "system" fn ash_layer_vkEnumerateInstanceVersion(
+            p_chain: *const $crate::EnumerateInstanceVersionChain,
+            p_api_version: *mut u32,
+        ) -> $crate::ash::vk::Result {
+            $crate::layer_enumerate_instance_version::<$layer>(p_chain, p_api_version)
+        }
+    };
+}
+
+/// `vkGetInstanceProcAddr` handed to the loader by each layer type, by which the loader tells the
+/// calling layer in `vkLayerCreateDevice`.
+static NEGOTIATED_GET_INSTANCE_PROC_ADDR: RwLock<BTreeMap<TypeId, vk::PFN_vkGetInstanceProcAddr>> =
+    RwLock::new(BTreeMap::new());
+
+/// `vkGetInstanceProcAddr` of `L` as known to the loader.
+fn own_get_instance_proc_addr<L: Layer>() -> vk::PFN_vkGetInstanceProcAddr {
+    let negotiated = NEGOTIATED_GET_INSTANCE_PROC_ADDR.read().unwrap();
+    match negotiated.get(&TypeId::of::<L>()) {
+        Some(v) => *v,
+        // looked up from the exports by loaders not negotiating
+        None => layer_get_instance_proc_addr::<L>,
+    }
+}
+
+/// Agrees on the older of the loader's and [`CURRENT_LOADER_LAYER_INTERFACE_VERSION`], failing
+/// if that is older than [`Layer::MIN_INTERFACE_VERSION`].
+pub unsafe extern "system" fn layer_negotiate_loader_layer_interface_version<L: Layer>(
+    p_version_struct: *mut NegotiateLayerInterface,
+) -> vk::Result {
+    let version_struct = match p_version_struct.as_mut() {
+        Some(v) if v.s_type == NegotiateLayerStructType::INTERFACE_STRUCT => v,
+        _ => return vk::Result::ERROR_INITIALIZATION_FAILED,
+    };
+    let version = version_struct
+        .loader_layer_interface_version
+        .min(CURRENT_LOADER_LAYER_INTERFACE_VERSION);
+    if version < L::MIN_INTERFACE_VERSION.max(MIN_SUPPORTED_LOADER_LAYER_INTERFACE_VERSION) {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    version_struct.loader_layer_interface_version = version;
+    // kept so that later calls pass the very same pointer, the address of a generic function
+    // may differ between codegen units
+    let get_instance_proc_addr = *NEGOTIATED_GET_INSTANCE_PROC_ADDR
+        .write()
+        .unwrap()
+        .entry(TypeId::of::<L>())
+        .or_insert(layer_get_instance_proc_addr::<L>);
+    version_struct.pfn_get_instance_proc_addr = Some(get_instance_proc_addr);
+    version_struct.pfn_get_device_proc_addr = Some(layer_get_device_proc_addr::<L>);
+    // the field is only read by loaders of interface version 2 and later
+    if version >= 2 {
+        version_struct.pfn_get_physical_device_proc_addr =
+            Some(layer_get_physical_device_proc_addr::<L>);
+    }
+    vk::Result::SUCCESS
+}
+const _: PFN_vkNegotiateLoaderLayerInterfaceVersion =
+    layer_negotiate_loader_layer_interface_version::<NoLayer>;
+
+macro_rules! builtin_hook {
+    ($level:ident, $name:literal, $pfn:expr, $ty:ty) => {
+        Hook::new(CommandLevel::$level, $name, unsafe {
+            mem::transmute::<$ty, unsafe extern "system" fn()>($pfn)
+        })
+    };
+}
+
+/// Commands of [`BuiltinHooks`], implemented through the methods of [`Layer`] rather than
+/// [`Layer::HOOKS`] or [`Layer::MODULES`].
+pub(crate) const BUILTIN_COMMANDS: &[&str] = &[
+    "vkGetInstanceProcAddr",
+    "vkCreateInstance",
+    "vkDestroyInstance",
+    "vk_layerGetPhysicalDeviceProcAddr",
+    "vkCreateDevice",
+    "vkGetDeviceProcAddr",
+    "vkDestroyDevice",
+];
+
+/// Entry points of the layer itself.
+struct BuiltinHooks<L>(PhantomData<L>);
+
+impl<L: Layer> BuiltinHooks<L> {
+    const HOOKS: &'static [Hook] = &[
+        builtin_hook!(
+            Global,
+            "vkGetInstanceProcAddr",
+            layer_get_instance_proc_addr::<L>,
+            vk::PFN_vkGetInstanceProcAddr
+        ),
+        builtin_hook!(
+            Global,
+            "vkCreateInstance",
+            layer_create_instance::<L>,
+            vk::PFN_vkCreateInstance
+        ),
+        builtin_hook!(
+            Instance,
+            "vkDestroyInstance",
+            layer_destroy_instance::<L>,
+            vk::PFN_vkDestroyInstance
+        ),
+        builtin_hook!(
+            Instance,
+            "vk_layerGetPhysicalDeviceProcAddr",
+            layer_get_physical_device_proc_addr::<L>,
+            PFN_vk_layerGetPhysicalDeviceProcAddr
+        ),
+        builtin_hook!(
+            PhysicalDevice,
+            "vkCreateDevice",
+            layer_create_device::<L>,
+            vk::PFN_vkCreateDevice
+        ),
+        builtin_hook!(
+            Device,
+            "vkGetDeviceProcAddr",
+            layer_get_device_proc_addr::<L>,
+            vk::PFN_vkGetDeviceProcAddr
+        ),
+        builtin_hook!(
+            Device,
+            "vkDestroyDevice",
+            layer_destroy_device::<L>,
+            vk::PFN_vkDestroyDevice
+        ),
+    ];
+    const INDEX: ([u32; 7], [u16; 14]) = HookTable::index(Self::HOOKS);
+    const SEEDS: &'static [u32] = &Self::INDEX.0;
+    const SLOTS: &'static [u16] = &Self::INDEX.1;
+    const TABLE: HookTable = HookTable::new(Self::HOOKS, Self::SEEDS, Self::SLOTS);
+}
+
+const ALL_LEVELS: &[CommandLevel] = &[
+    CommandLevel::Global,
+    CommandLevel::Instance,
+    CommandLevel::PhysicalDevice,
+    CommandLevel::Device,
+];
+
+pub unsafe extern "system" fn layer_get_instance_proc_addr<L: Layer>(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    if p_name.is_null() {
+        return None;
+    }
+    let name = CStr::from_ptr(p_name);
+    // only global commands are resolved without an instance, but for vkCreateDevice which the
+    // layers above look up that way as in the loader documentation
+    let levels = if instance == vk::Instance::null() {
+        if name.to_bytes() == b"vkCreateDevice" {
+            return BuiltinHooks::<L>::TABLE.pfn(name, &[CommandLevel::PhysicalDevice]);
+        }
+        &[CommandLevel::Global]
+    } else {
+        ALL_LEVELS
+    };
+    if let Some(hook) = BuiltinHooks::<L>::TABLE.pfn(name, levels) {
+        return Some(hook);
+    }
+    let dispatch = InstanceDispatch::from_handle(instance);
+    // commands the application did not enable must be null, hooked or not
+    if matches!(&dispatch, Some(v) if !v.is_command_enabled(name)) {
+        return None;
+    }
+    if let Some(hook) = L::HOOKS.pfn(name, levels) {
+        return Some(hook);
+    }
+    if let Some(hook) = interceptor_pfn(L::MODULES, name, levels) {
+        // interceptors have nothing to call down if the layers below lack the command
+        return match &dispatch {
+            Some(v) if !v.interception().is_supported_below(name) => None,
+            _ => Some(hook),
+        };
+    }
+    if instance == vk::Instance::null() {
+        return None;
+    }
+    let hook = catch_panic::<L, _>("vkGetInstanceProcAddr", || {
+        L::instance_hook(name)
+            .or_else(|| L::physical_device_hook(name))
+            .or_else(|| L::device_hook(name))
+    });
+    match hook {
+        Ok(Some(v)) => return Some(v),
+        Ok(None) | Err(PanicPolicy::PassThrough) => {}
+        Err(_) => return None,
+    }
+    let dispatch = dispatch?;
+    (dispatch.next_get_instance_proc_addr())(instance, p_name)
+}
+const _: vk::PFN_vkGetInstanceProcAddr = layer_get_instance_proc_addr::<NoLayer>;
+
+pub unsafe extern "system" fn layer_get_physical_device_proc_addr<L: Layer>(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    if p_name.is_null() {
+        return None;
+    }
+    let name = CStr::from_ptr(p_name);
+    let levels = &[CommandLevel::PhysicalDevice];
+    if let Some(hook) = BuiltinHooks::<L>::TABLE.pfn(name, levels) {
+        return Some(hook);
+    }
+    let dispatch = InstanceDispatch::from_handle(instance)?;
+    if !dispatch.is_command_enabled(name) {
+        return None;
+    }
+    let hook = catch_panic::<L, _>("vk_layerGetPhysicalDeviceProcAddr", || {
+        let hook = L::HOOKS
+            .pfn(name, levels)
+            .or_else(|| interceptor_pfn(L::MODULES, name, levels))
+            .or_else(|| L::physical_device_hook(name));
+        (hook, hook.is_some() && L::implements_command(name))
+    });
+    let hook = match hook {
+        Ok((hook, true)) => return hook,
+        Ok((hook, false)) => hook,
+        Err(PanicPolicy::PassThrough) => None,
+        Err(_) => return None,
+    };
+    let next = match dispatch.next_get_physical_device_proc_addr() {
+        Some(v) => v(instance, p_name),
+        // the next layer predates interface version 2
+        None => (dispatch.next_get_instance_proc_addr())(instance, p_name),
+    };
+    // null tells the loader nothing below supports the command, wrapping it is moot
+    next.and(hook).or(next)
+}
+const _: PFN_vk_layerGetPhysicalDeviceProcAddr = layer_get_physical_device_proc_addr::<NoLayer>;
+
+pub unsafe extern "system" fn layer_get_device_proc_addr<L: Layer>(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    if p_name.is_null() {
+        return None;
+    }
+    let name = CStr::from_ptr(p_name);
+    let levels = &[CommandLevel::Device];
+    if let Some(hook) = BuiltinHooks::<L>::TABLE.pfn(name, levels) {
+        return Some(hook);
+    }
+    let dispatch = DeviceDispatch::from_handle(device);
+    // commands the application did not enable must be null, hooked or not
+    if matches!(&dispatch, Some(v) if !v.is_command_enabled(name)) {
+        return None;
+    }
+    if let Some(hook) = L::HOOKS.pfn(name, levels) {
+        return Some(hook);
+    }
+    if let Some(hook) = interceptor_pfn(L::MODULES, name, levels) {
+        return match &dispatch {
+            Some(v) if !v.interception().is_supported_below(name) => None,
+            _ => Some(hook),
+        };
+    }
+    match catch_panic::<L, _>("vkGetDeviceProcAddr", || L::device_hook(name)) {
+        Ok(Some(v)) => return Some(v),
+        Ok(None) | Err(PanicPolicy::PassThrough) => {}
+        Err(_) => return None,
+    }
+    let dispatch = dispatch?;
+    (dispatch.next_get_device_proc_addr())(device, p_name)
+}
+const _: vk::PFN_vkGetDeviceProcAddr = layer_get_device_proc_addr::<NoLayer>;
+
+/// Pre-instance `vkEnumerateInstanceExtensionProperties`, filtering the extensions of all
+/// layers below through [`Layer::enumerate_instance_extensions`].
+pub unsafe extern "system" fn layer_enumerate_instance_extension_properties<L: Layer>(
+    p_chain: *const EnumerateInstanceExtensionPropertiesChain,
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let chain = &*p_chain;
+    // queries of a specific layer are not ours to adjust
+    if !p_layer_name.is_null() {
+        return chain
+            .call_down(p_layer_name, p_property_count, p_properties)
+            .unwrap_or_else(Into::into);
+    }
+    let mut extensions = match read_array(|count, items| {
+        chain
+            .call_down(ptr::null(), count, items)
+            .unwrap_or_else(Into::into)
+    }) {
+        Ok(v) => v,
+        Err(err) => return err,
+    };
+    let mut adjusted = extensions.clone();
+    match catch_panic::<L, _>("vkEnumerateInstanceExtensionProperties", || {
+        L::enumerate_instance_extensions(&mut adjusted)
+    }) {
+        Ok(()) => extensions = adjusted,
+        Err(PanicPolicy::PassThrough) => {}
+        Err(_) => return vk::Result::ERROR_UNKNOWN,
+    }
+    write_array(&extensions, p_property_count, p_properties)
+}
+const _: PFN_layer_vkEnumerateInstanceExtensionProperties =
+    layer_enumerate_instance_extension_properties::<NoLayer>;
+
+/// Pre-instance `vkEnumerateInstanceLayerProperties`, filtering through
+/// [`Layer::enumerate_instance_layers`].
+pub unsafe extern "system" fn layer_enumerate_instance_layer_properties<L: Layer>(
+    p_chain: *const EnumerateInstanceLayerPropertiesChain,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    let chain = &*p_chain;
+    let mut layers =
+        match read_array(|count, items| chain.call_down(count, items).unwrap_or_else(Into::into)) {
+            Ok(v) => v,
+            Err(err) => return err,
+        };
+    let mut adjusted = layers.clone();
+    match catch_panic::<L, _>("vkEnumerateInstanceLayerProperties", || {
+        L::enumerate_instance_layers(&mut adjusted)
+    }) {
+        Ok(()) => layers = adjusted,
+        Err(PanicPolicy::PassThrough) => {}
+        Err(_) => return vk::Result::ERROR_UNKNOWN,
+    }
+    write_array(&layers, p_property_count, p_properties)
+}
+const _: PFN_layer_vkEnumerateInstanceLayerProperties =
+    layer_enumerate_instance_layer_properties::<NoLayer>;
+
+/// Pre-instance `vkEnumerateInstanceVersion`, adjusted by [`Layer::enumerate_instance_version`].
+pub unsafe extern "system" fn layer_enumerate_instance_version<L: Layer>(
+    p_chain: *const EnumerateInstanceVersionChain,
+    p_api_version: *mut u32,
+) -> vk::Result {
+    let mut api_version = 0;
+    let res = match (*p_chain).call_down(&mut api_version) {
+        Ok(v) => v,
+        Err(err) => return err.into(),
+    };
+    if res != vk::Result::SUCCESS {
+        return res;
+    }
+    *p_api_version = match catch_panic::<L, _>("vkEnumerateInstanceVersion", || {
+        L::enumerate_instance_version(api_version)
+    }) {
+        Ok(v) => v,
+        Err(PanicPolicy::PassThrough) => api_version,
+        Err(_) => return vk::Result::ERROR_UNKNOWN,
+    };
+    vk::Result::SUCCESS
+}
+const _: PFN_layer_vkEnumerateInstanceVersion = layer_enumerate_instance_version::<NoLayer>;
+
+unsafe fn is_own_layer_name<L: Layer>(p_layer_name: *const c_char) -> bool {
+    !p_layer_name.is_null()
+        && !L::NAME.is_empty()
+        && CStr::from_ptr(p_layer_name).to_bytes() == L::NAME.as_bytes()
+}
+
+unsafe fn own_layer_properties<L: Layer>(
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    let properties = layer_properties(
+        L::NAME,
+        L::SPEC_VERSION,
+        L::IMPLEMENTATION_VERSION,
+        L::DESCRIPTION,
+    );
+    let layers = if L::NAME.is_empty() {
+        &[][..]
+    } else {
+        core::slice::from_ref(&properties)
+    };
+    write_array(layers, p_property_count, p_properties)
+}
+
+/// Legacy `vkEnumerateInstanceLayerProperties` export reporting this layer.
+pub unsafe extern "system" fn layer_legacy_enumerate_instance_layer_properties<L: Layer>(
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    own_layer_properties::<L>(p_property_count, p_properties)
+}
+const _: vk::PFN_vkEnumerateInstanceLayerProperties =
+    layer_legacy_enumerate_instance_layer_properties::<NoLayer>;
+
+/// Legacy `vkEnumerateInstanceExtensionProperties` export reporting
+/// [`Layer::instance_extension_properties`].
+pub unsafe extern "system" fn layer_legacy_enumerate_instance_extension_properties<L: Layer>(
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    if !is_own_layer_name::<L>(p_layer_name) {
+        return vk::Result::ERROR_LAYER_NOT_PRESENT;
+    }
+    let extensions = match catch_panic::<L, _>(
+        "vkEnumerateInstanceExtensionProperties",
+        L::instance_extension_properties,
+    ) {
+        Ok(v) => v,
+        Err(PanicPolicy::PassThrough) => Vec::new(),
+        Err(_) => return vk::Result::ERROR_UNKNOWN,
+    };
+    write_array(&extensions, p_property_count, p_properties)
+}
+const _: vk::PFN_vkEnumerateInstanceExtensionProperties =
+    layer_legacy_enumerate_instance_extension_properties::<NoLayer>;
+
+/// Legacy `vkEnumerateDeviceLayerProperties` export reporting this layer.
+pub unsafe extern "system" fn layer_legacy_enumerate_device_layer_properties<L: Layer>(
+    _physical_device: vk::PhysicalDevice,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    own_layer_properties::<L>(p_property_count, p_properties)
+}
+const _: vk::PFN_vkEnumerateDeviceLayerProperties =
+    layer_legacy_enumerate_device_layer_properties::<NoLayer>;
+
+/// Legacy `vkEnumerateDeviceExtensionProperties` export reporting
+/// [`Layer::device_extension_properties`], other queries are passed down the chain.
+pub unsafe extern "system" fn layer_legacy_enumerate_device_extension_properties<L: Layer>(
+    physical_device: vk::PhysicalDevice,
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    if is_own_layer_name::<L>(p_layer_name) {
+        let extensions = match catch_panic::<L, _>(
+            "vkEnumerateDeviceExtensionProperties",
+            L::device_extension_properties,
+        ) {
+            Ok(v) => v,
+            Err(PanicPolicy::PassThrough) => Vec::new(),
+            Err(_) => return vk::Result::ERROR_UNKNOWN,
+        };
+        return write_array(&extensions, p_property_count, p_properties);
+    }
+    match InstanceDispatch::from_handle(physical_device) {
+        Some(dispatch) => (dispatch
+            .table()
+            .fp_v1_0()
+            .enumerate_device_extension_properties)(
+            physical_device,
+            p_layer_name,
+            p_property_count,
+            p_properties,
+        ),
+        None => vk::Result::ERROR_LAYER_NOT_PRESENT,
+    }
+}
+const _: vk::PFN_vkEnumerateDeviceExtensionProperties =
+    layer_legacy_enumerate_device_extension_properties::<NoLayer>;
+
+unsafe extern "system" fn layer_create_instance<L: Layer>(
+    p_create_info: *const vk::InstanceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    let create_info = &*p_create_info;
+    let next = match advance_instance_link(create_info) {
+        Ok(v) => v,
+        Err(err) => return err.into(),
+    };
+    let next_gipa = next.get_instance_proc_addr;
+
+    let name = CStr::from_bytes_with_nul_unchecked(b"vkCreateInstance\0");
+    let create_instance = match next_gipa(vk::Instance::null(), name.as_ptr()) {
+        Some(v) => mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateInstance>(v),
+        None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+    };
+
+    let res = create_instance(p_create_info, p_allocator, p_instance);
+    if res != vk::Result::SUCCESS {
+        return res;
+    }
+    let instance = *p_instance;
+
+    let mut dispatch =
+        match InstanceDispatch::new(instance, next_gipa, next.get_physical_device_proc_addr) {
+            Some(v) => v,
+            None => {
+                // created below already, so not to leak it
+                let name = b"vkDestroyInstance\0".as_ptr().cast();
+                if let Some(v) = next_gipa(instance, name) {
+                    let destroy_instance =
+                        mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyInstance>(v);
+                    destroy_instance(instance, p_allocator);
+                }
+                return vk::Result::ERROR_INITIALIZATION_FAILED;
+            }
+        };
+    dispatch.capture_create_info(create_info);
+    dispatch.set_layer_get_instance_proc_addr(own_get_instance_proc_addr::<L>());
+    dispatch.intercept::<L>();
+    // registered first so that the hook can resolve commands through vkGetInstanceProcAddr
+    let dispatch = dispatch.register();
+
+    let res = match catch_panic::<L, _>("vkCreateInstance", || {
+        L::create_instance(create_info, &dispatch)
+    }) {
+        Ok(v) => v,
+        Err(PanicPolicy::PassThrough) => vk::Result::SUCCESS,
+        Err(_) => vk::Result::ERROR_INITIALIZATION_FAILED,
+    };
+    if res != vk::Result::SUCCESS {
+        InstanceDispatch::unregister(dispatch.key());
+        (dispatch.table().fp_v1_0().destroy_instance)(instance, p_allocator);
+        return res;
+    }
+    vk::Result::SUCCESS
+}
+const _: vk::PFN_vkCreateInstance = layer_create_instance::<NoLayer>;
+
+unsafe extern "system" fn layer_destroy_instance<L: Layer>(
+    instance: vk::Instance,
+    p_allocator: *const vk::AllocationCallbacks,
+) {
+    let dispatch = match instance
+        .dispatch_key()
+        .and_then(InstanceDispatch::unregister)
+    {
+        Some(v) => v,
+        None => return,
+    };
+
+    // destroyed below whatever the panic policy
+    let _ = catch_panic::<L, _>("vkDestroyInstance", || L::destroy_instance(&dispatch));
+    (dispatch.table().fp_v1_0().destroy_instance)(instance, p_allocator);
+}
+const _: vk::PFN_vkDestroyInstance = layer_destroy_instance::<NoLayer>;
+
+unsafe extern "system" fn layer_create_device<L: Layer>(
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    let instance_dispatch = match InstanceDispatch::from_handle(physical_device) {
+        Some(v) => v,
+        None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+    };
+
+    let create_info = &*p_create_info;
+    let next = match advance_device_link(create_info) {
+        Ok(v) => v,
+        Err(err) => return err.into(),
+    };
+
+    // resolved through the link, which differs from the instance chain for layer devices
+    let name = CStr::from_bytes_with_nul_unchecked(b"vkCreateDevice\0");
+    let create_device =
+        match (next.get_instance_proc_addr)(instance_dispatch.handle(), name.as_ptr()) {
+            Some(v) => mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateDevice>(v),
+            None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+        };
+    let res = create_device(physical_device, p_create_info, p_allocator, p_device);
+    if res != vk::Result::SUCCESS {
+        return res;
+    }
+    let device = *p_device;
+
+    let mut dispatch = match DeviceDispatch::new(
+        &instance_dispatch,
+        physical_device,
+        device,
+        next.get_device_proc_addr,
+    ) {
+        Some(v) => v,
+        None => {
+            // created below already, so not to leak it
+            let name = b"vkDestroyDevice\0".as_ptr().cast();
+            if let Some(v) = (next.get_device_proc_addr)(device, name) {
+                let destroy_device =
+                    mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkDestroyDevice>(v);
+                destroy_device(device, p_allocator);
+            }
+            return vk::Result::ERROR_INITIALIZATION_FAILED;
+        }
+    };
+    dispatch.capture_create_info(&instance_dispatch, create_info);
+    dispatch.intercept::<L>();
+    // registered first so that the hook can resolve commands through vkGetDeviceProcAddr,
+    // e.g. to load extensions
+    let dispatch = dispatch.register();
+
+    let res = match catch_panic::<L, _>("vkCreateDevice", || {
+        L::create_device(create_info, &instance_dispatch, &dispatch)
+    }) {
+        Ok(v) => v,
+        Err(PanicPolicy::PassThrough) => vk::Result::SUCCESS,
+        Err(_) => vk::Result::ERROR_INITIALIZATION_FAILED,
+    };
+    if res != vk::Result::SUCCESS {
+        DeviceDispatch::unregister(dispatch.key());
+        (dispatch.table().fp_v1_0().destroy_device)(device, p_allocator);
+        return res;
+    }
+    vk::Result::SUCCESS
+}
+const _: vk::PFN_vkCreateDevice = layer_create_device::<NoLayer>;
+
+unsafe extern "system" fn layer_destroy_device<L: Layer>(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks,
+) {
+    let dispatch = match device.dispatch_key().and_then(DeviceDispatch::unregister) {
+        Some(v) => v,
+        None => return,
+    };
+
+    // destroyed below whatever the panic policy
+    let _ = catch_panic::<L, _>("vkDestroyDevice", || L::destroy_device(&dispatch));
+    (dispatch.table().fp_v1_0().destroy_device)(device, p_allocator);
+}
+const _: vk::PFN_vkDestroyDevice = layer_destroy_device::<NoLayer>;
+
+enum NoLayer {}
+impl Layer for NoLayer {}
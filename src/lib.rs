@@ -1,5 +1,27 @@
+#![allow(clippy::missing_safety_doc)]
+
+mod dispatch;
+mod hook;
+mod intercept;
+mod layer;
+mod panic;
 mod sys;
 mod utils;
+mod view;
+
+pub use ash;
+
+#[cfg(feature = "manifest")]
+pub mod manifest;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod registry;
 
+pub use dispatch::*;
+pub use hook::*;
+pub use intercept::*;
+pub use layer::*;
+pub use panic::*;
 pub use sys::*;
 pub use utils::*;
+pub use view::*;
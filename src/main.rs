@@ -0,0 +1,466 @@
+//! Installs, lists and checks Vulkan layers.
+
+use ash_layer::manifest::*;
+use ash_layer::mock::*;
+use ash_layer::*;
+
+use std::env;
+use std::ffi::{c_void, CString};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::ptr;
+use std::sync::Arc;
+
+use ash::vk;
+
+const USAGE: &str = "\
+Usage: ash-layer <command>
+
+Commands:
+  install <manifest> [--implicit]
+      Copy a manifest into ~/.local/share/vulkan/explicit_layer.d, or
+      implicit_layer.d with --implicit, with its library path made absolute.
+  list
+      List the manifests found on the loader search paths.
+  check <manifest|library>
+      Load the layer library, negotiate the loader interface and print its
+      exports and the commands it intercepts, probed on a mock instance and
+      device for interface version 2 layers.";
+
+/// Functions a layer library may export for the loader.
+const EXPORTED_FUNCTIONS: &[&str] = &[
+    VK_NEGOTIATE_LOADER_LAYER_INTERFACE_VERSION_SYM,
+    "vkGetInstanceProcAddr",
+    "vkGetDeviceProcAddr",
+    "vkEnumerateInstanceLayerProperties",
+    "vkEnumerateInstanceExtensionProperties",
+    "vkEnumerateDeviceLayerProperties",
+    "vkEnumerateDeviceExtensionProperties",
+];
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let res = match args.as_slice() {
+        ["install", manifest] => install(Path::new(manifest), false),
+        ["install", manifest, "--implicit"] => install(Path::new(manifest), true),
+        ["list"] => list(),
+        ["check", path] => check(Path::new(path)),
+        ["help" | "--help" | "-h"] => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    match res {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+type Error = Box<dyn std::fmt::Display>;
+
+fn boxed(err: impl std::fmt::Display + 'static) -> Error {
+    Box::new(err)
+}
+
+fn read_manifest(path: &Path) -> Result<ManifestFile, Error> {
+    let json = fs::read_to_string(path)
+        .map_err(|err| boxed(format!("reading {}: {}", path.display(), err)))?;
+    ManifestFile::from_json(&json).map_err(boxed)
+}
+
+/// Resolves `library_path` like the loader, relative to the manifest if it has a separator.
+fn library_path(manifest_path: &Path, layer: &LayerManifest) -> Option<PathBuf> {
+    let path = layer.library_path.as_ref()?;
+    if path.components().count() > 1 && path.is_relative() {
+        let dir = manifest_path.parent().unwrap_or_else(|| Path::new("."));
+        Some(dir.join(path))
+    } else {
+        Some(path.clone())
+    }
+}
+
+fn install(path: &Path, implicit: bool) -> Result<(), Error> {
+    let mut manifest = read_manifest(path)?;
+    manifest.validate().map_err(boxed)?;
+    if implicit {
+        // the loader ignores implicit layers that can not be disabled
+        if let Some(layer) = manifest
+            .iter_layers()
+            .find(|layer| layer.disable_environment.is_empty())
+        {
+            return Err(boxed(format!(
+                "implicit layer {} has no disable_environment",
+                layer.name
+            )));
+        }
+    }
+
+    for layer in manifest.layer.iter_mut().chain(&mut manifest.layers) {
+        let resolved = match library_path(path, layer) {
+            Some(v) if v.components().count() > 1 => v,
+            // meta layers and libraries looked up in the system library paths
+            _ => continue,
+        };
+        let absolute = resolved
+            .canonicalize()
+            .map_err(|err| boxed(format!("resolving {}: {}", resolved.display(), err)))?;
+        layer.library_path = Some(absolute);
+    }
+
+    let dir = data_home()
+        .ok_or_else(|| boxed("neither XDG_DATA_HOME nor HOME is set"))?
+        .join("vulkan")
+        .join(if implicit {
+            "implicit_layer.d"
+        } else {
+            "explicit_layer.d"
+        });
+    fs::create_dir_all(&dir).map_err(boxed)?;
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| boxed(format!("{} is not a file", path.display())))?;
+    let dest = dir.join(file_name);
+    fs::write(&dest, manifest.to_json().map_err(boxed)? + "\n").map_err(boxed)?;
+    println!("installed {}", dest.display());
+    Ok(())
+}
+
+fn list() -> Result<(), Error> {
+    let mut found = false;
+    for (dir, implicit) in search_dirs() {
+        let mut entries: Vec<PathBuf> = match fs::read_dir(&dir) {
+            Ok(v) => v.filter_map(|v| Some(v.ok()?.path())).collect(),
+            Err(_) => continue,
+        };
+        entries.retain(|v| v.extension().map_or(false, |ext| ext == "json"));
+        entries.sort();
+        for path in entries {
+            found = true;
+            let kind = if implicit { "implicit" } else { "explicit" };
+            println!("{} ({})", path.display(), kind);
+            let manifest = match read_manifest(&path) {
+                Ok(v) => v,
+                Err(err) => {
+                    println!("  ! {}", err);
+                    continue;
+                }
+            };
+            for layer in manifest.iter_layers() {
+                match library_path(&path, layer) {
+                    Some(library) => println!(
+                        "  {} {} -> {}",
+                        layer.name,
+                        layer.api_version,
+                        library.display()
+                    ),
+                    None => println!(
+                        "  {} {} meta layer of {}",
+                        layer.name,
+                        layer.api_version,
+                        layer.component_layers.join(", ")
+                    ),
+                }
+            }
+            if let Err(err) = manifest.validate() {
+                println!("  ! {}", err);
+            }
+        }
+    }
+    if !found {
+        println!("no layer manifests found");
+    }
+    Ok(())
+}
+
+fn check(path: &Path) -> Result<(), Error> {
+    if path.extension().map_or(false, |ext| ext == "json") {
+        let manifest = read_manifest(path)?;
+        let mut res = manifest.validate().map_err(boxed);
+        for layer in manifest.iter_layers() {
+            println!("{}", layer.name);
+            let library = match library_path(path, layer) {
+                Some(v) => v,
+                None => {
+                    println!("  meta layer of {}", layer.component_layers.join(", "));
+                    continue;
+                }
+            };
+            if let Err(err) = check_library(&library, Some(layer)) {
+                println!("  ! {}", err);
+                res = Err(err);
+            }
+        }
+        res
+    } else {
+        check_library(path, None)
+    }
+}
+
+fn check_library(path: &Path, layer: Option<&LayerManifest>) -> Result<(), Error> {
+    println!("  library: {}", path.display());
+    let library = unsafe { libloading::Library::new(path) }
+        .map_err(|err| boxed(format!("loading {}: {}", path.display(), err)))?;
+    let has_symbol = |name: &str| unsafe { library.get::<*const c_void>(name.as_bytes()).is_ok() };
+    let exported_name =
+        |function: &'static str| layer.map_or(function, |layer| layer.exported_name(function));
+    println!("  exports:");
+    let mut exports: Vec<&str> = EXPORTED_FUNCTIONS
+        .iter()
+        .map(|v| exported_name(v))
+        .collect();
+    exports.extend(
+        layer
+            .iter()
+            .flat_map(|v| v.pre_instance_functions.values().map(String::as_str)),
+    );
+    exports.sort_unstable();
+    exports.dedup();
+    for name in exports.into_iter().filter(|v| has_symbol(v)) {
+        println!("    {}", name);
+    }
+    if let Some(layer) = layer {
+        layer.validate_exports(has_symbol).map_err(boxed)?;
+    }
+
+    let negotiate_name = exported_name(VK_NEGOTIATE_LOADER_LAYER_INTERFACE_VERSION_SYM);
+    let (get_instance_proc_addr, negotiate) = match unsafe {
+        library.get::<PFN_vkNegotiateLoaderLayerInterfaceVersion>(negotiate_name.as_bytes())
+    } {
+        Ok(negotiate) => {
+            let mut version_struct = NegotiateLayerInterface {
+                s_type: NegotiateLayerStructType::INTERFACE_STRUCT,
+                p_next: ptr::null_mut(),
+                loader_layer_interface_version: CURRENT_LOADER_LAYER_INTERFACE_VERSION,
+                pfn_get_instance_proc_addr: None,
+                pfn_get_device_proc_addr: None,
+                pfn_get_physical_device_proc_addr: None,
+            };
+            let res = unsafe { negotiate(&mut version_struct) };
+            if res != vk::Result::SUCCESS {
+                return Err(boxed(format!("{} returned {}", negotiate_name, res)));
+            }
+            let version = version_struct.loader_layer_interface_version;
+            println!("  negotiated interface version {}", version);
+            let found = |v: bool| if v { "found" } else { "missing" };
+            println!(
+                "  vkGetInstanceProcAddr {}, vkGetDeviceProcAddr {}, \
+                 vk_layerGetPhysicalDeviceProcAddr {}",
+                found(version_struct.pfn_get_instance_proc_addr.is_some()),
+                found(version_struct.pfn_get_device_proc_addr.is_some()),
+                found(version_struct.pfn_get_physical_device_proc_addr.is_some()),
+            );
+            if !(1..=2).contains(&version) {
+                return Err(boxed(format!("unsupported interface version {}", version)));
+            }
+            let get_instance_proc_addr = match version_struct.pfn_get_instance_proc_addr {
+                Some(v) => v,
+                None => return Err(boxed("negotiation returned no vkGetInstanceProcAddr")),
+            };
+            // older interfaces export entry points by name, the mock loader can't chain them
+            (
+                get_instance_proc_addr,
+                Some(*negotiate).filter(|_| version >= 2),
+            )
+        }
+        Err(_) => {
+            println!("  {} not exported, legacy layer", negotiate_name);
+            let name = exported_name("vkGetInstanceProcAddr");
+            let get_instance_proc_addr =
+                *unsafe { library.get::<vk::PFN_vkGetInstanceProcAddr>(name.as_bytes()) }.map_err(
+                    |_| boxed(format!("neither {} nor {} exported", negotiate_name, name)),
+                )?;
+            (get_instance_proc_addr, None)
+        }
+    };
+
+    // only global commands may be queried without an instance, layers look instance state up
+    // from the dispatch of the handle
+    println!("  global commands:");
+    for name in command_names(CommandLevel::Global) {
+        if unsafe { get_instance_proc_addr(vk::Instance::null(), name.as_ptr()) }.is_some() {
+            println!("    {}", name.to_string_lossy());
+        }
+    }
+    match negotiate {
+        Some(v) => unsafe { check_hooks(v) },
+        None => {
+            println!("  other commands not probed, interface version 2 required");
+            Ok(())
+        }
+    }
+}
+
+/// Prints the instance, physical device and device commands the layer resolves differently from
+/// the mock driver below it, probed with handles created through the layer.
+unsafe fn check_hooks(negotiate: PFN_vkNegotiateLoaderLayerInterfaceVersion) -> Result<(), Error> {
+    // layers may hide hooks of commands not supported below
+    let driver = Arc::new(MockDriver::default());
+    driver.set_stub_commands(true);
+    let bare_loader = MockLoader::with_driver(driver.clone());
+    let mut loader = MockLoader::with_driver(driver);
+    loader
+        .add_layer(negotiate)
+        .map_err(|err| boxed(format!("stacking the layer on a mock loader: {}", err)))?;
+
+    // enabling everything, as commands of disabled extensions and versions are null
+    let (instance_extensions, device_extensions): (Vec<_>, Vec<_>) = extension_names()
+        .into_iter()
+        .partition(|v| registry::is_instance_extension(v.to_str().unwrap()));
+    let instance_extensions: Vec<_> = instance_extensions.iter().map(|v| v.as_ptr()).collect();
+    let device_extensions: Vec<_> = device_extensions.iter().map(|v| v.as_ptr()).collect();
+    let application_info = vk::ApplicationInfo {
+        api_version: vk::API_VERSION_1_3,
+        ..Default::default()
+    };
+    let instance_info = vk::InstanceCreateInfo {
+        p_application_info: &application_info,
+        enabled_extension_count: instance_extensions.len() as u32,
+        pp_enabled_extension_names: instance_extensions.as_ptr(),
+        ..Default::default()
+    };
+    let queue_priority = 1.0;
+    let queue_info = vk::DeviceQueueCreateInfo {
+        queue_count: 1,
+        p_queue_priorities: &queue_priority,
+        ..Default::default()
+    };
+    let device_info = vk::DeviceCreateInfo {
+        queue_create_info_count: 1,
+        p_queue_create_infos: &queue_info,
+        enabled_extension_count: device_extensions.len() as u32,
+        pp_enabled_extension_names: device_extensions.as_ptr(),
+        ..Default::default()
+    };
+
+    let bare_instance = bare_loader.create_instance(&instance_info).map_err(boxed)?;
+    let bare_physical_device = bare_instance
+        .table()
+        .enumerate_physical_devices()
+        .map_err(boxed)?[0];
+    let bare_device = bare_instance
+        .create_device(bare_physical_device, &device_info)
+        .map_err(boxed)?;
+    let instance = loader
+        .create_instance(&instance_info)
+        .map_err(|err| boxed(format!("creating an instance through the layer: {}", err)))?;
+    let physical_device = instance
+        .table()
+        .enumerate_physical_devices()
+        .map_err(boxed)?[0];
+    let device = instance
+        .create_device(physical_device, &device_info)
+        .map_err(|err| boxed(format!("creating a device through the layer: {}", err)))?;
+
+    for (title, level) in [
+        ("instance commands", CommandLevel::Instance),
+        ("physical device commands", CommandLevel::PhysicalDevice),
+        ("device commands", CommandLevel::Device),
+    ] {
+        println!("  {}:", title);
+        for name in command_names(level) {
+            let (pfn, next) = match level {
+                CommandLevel::Device => (
+                    device.get_device_proc_addr(&name),
+                    bare_device.get_device_proc_addr(&name),
+                ),
+                _ => (
+                    instance.get_instance_proc_addr(&name),
+                    bare_instance.get_instance_proc_addr(&name),
+                ),
+            };
+            // commands passed through resolve to the driver's
+            if pfn.is_some() && pfn.map(|v| v as usize) != next.map(|v| v as usize) {
+                println!("    {}", name.to_string_lossy());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Names of the commands of the Vulkan registry at `level`.
+fn command_names(level: CommandLevel) -> Vec<CString> {
+    registry::all_commands()
+        .iter()
+        .filter(|v| v.level == level)
+        .map(|v| CString::new(v.name).unwrap())
+        .collect()
+}
+
+/// Names of the extensions providing commands of the Vulkan registry.
+fn extension_names() -> Vec<CString> {
+    let mut names: Vec<&str> = registry::all_commands()
+        .iter()
+        .flat_map(|v| v.providers)
+        .filter_map(|v| match v {
+            registry::Provider::Extension(v) => Some(*v),
+            registry::Provider::Core(_) => None,
+        })
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+        .into_iter()
+        .map(|v| CString::new(v).unwrap())
+        .collect()
+}
+
+fn home() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+fn data_home() -> Option<PathBuf> {
+    match env::var_os("XDG_DATA_HOME").filter(|v| !v.is_empty()) {
+        Some(v) => Some(PathBuf::from(v)),
+        None => Some(home()?.join(".local/share")),
+    }
+}
+
+fn env_dirs(key: &str, default: &str) -> Vec<PathBuf> {
+    let value = env::var(key).ok().filter(|v| !v.is_empty());
+    let value = value.as_deref().unwrap_or(default);
+    env::split_paths(value).collect()
+}
+
+/// Manifest directories in the order the Linux loader searches them, with whether they hold
+/// implicit layers.
+fn search_dirs() -> Vec<(PathBuf, bool)> {
+    let mut dirs = Vec::new();
+    // VK_LAYER_PATH replaces the explicit layer search paths, VK_ADD_LAYER_PATH prepends to them
+    let layer_path = env::var_os("VK_LAYER_PATH").filter(|v| !v.is_empty());
+    if let Some(v) = &layer_path {
+        dirs.extend(env::split_paths(v).map(|v| (v, false)));
+    }
+    if let Some(v) = env::var_os("VK_ADD_LAYER_PATH").filter(|v| !v.is_empty()) {
+        dirs.extend(env::split_paths(&v).map(|v| (v, false)));
+    }
+
+    let mut bases = Vec::new();
+    match env::var_os("XDG_CONFIG_HOME").filter(|v| !v.is_empty()) {
+        Some(v) => bases.push(PathBuf::from(v)),
+        None => bases.extend(home().map(|v| v.join(".config"))),
+    }
+    bases.extend(env_dirs("XDG_CONFIG_DIRS", "/etc/xdg"));
+    bases.push(PathBuf::from("/etc"));
+    bases.extend(data_home());
+    bases.extend(env_dirs("XDG_DATA_DIRS", "/usr/local/share:/usr/share"));
+
+    for base in bases {
+        let base = base.join("vulkan");
+        if layer_path.is_none() {
+            dirs.push((base.join("explicit_layer.d"), false));
+        }
+        dirs.push((base.join("implicit_layer.d"), true));
+    }
+    dirs
+}
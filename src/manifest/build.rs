@@ -0,0 +1,191 @@
+use super::*;
+
+use std::env::{self, consts};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Writes the manifest of a layer crate from its build script, next to the cdylib cargo builds
+/// for the current target and profile.
+///
+/// ```no_run
+/// // build.rs
+/// use ash_layer::manifest::ManifestBuilder;
+///
+/// ManifestBuilder::new("VK_LAYER_example", 1)
+///     .implicit("DISABLE_EXAMPLE_LAYER")
+///     .write()
+///     .unwrap();
+/// ```
+pub struct ManifestBuilder {
+    layer: LayerManifest,
+    library_name: String,
+    library_path: Option<PathBuf>,
+    implicit: bool,
+}
+
+impl ManifestBuilder {
+    /// Starts the manifest of an explicit layer with the description of the package being built.
+    ///
+    /// `implementation_version` must be the [`Layer::IMPLEMENTATION_VERSION`] of the layer, which
+    /// the loader expects to match.
+    ///
+    /// [`Layer::IMPLEMENTATION_VERSION`]: crate::Layer::IMPLEMENTATION_VERSION
+    pub fn new(name: impl Into<String>, implementation_version: u32) -> Self {
+        let mut layer = LayerManifest::new(name, PathBuf::new());
+        layer.implementation_version = implementation_version.to_string();
+        layer.description = env_var("CARGO_PKG_DESCRIPTION");
+        Self {
+            layer,
+            library_name: env_var("CARGO_PKG_NAME").replace('-', "_"),
+            library_path: None,
+            implicit: false,
+        }
+    }
+
+    /// Name of the cdylib target, the package name by default.
+    pub fn library_name(mut self, name: impl Into<String>) -> Self {
+        self.library_name = name.into();
+        self
+    }
+
+    /// Path of the library relative to the manifest, or absolute, the cdylib being built by
+    /// default.
+    pub fn library_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.library_path = Some(path.into());
+        self
+    }
+
+    pub fn layer_type(mut self, ty: LayerType) -> Self {
+        self.layer.ty = ty;
+        self
+    }
+
+    /// Makes the layer implicit, enabled unless `disable_environment` is set.
+    pub fn implicit(mut self, disable_environment: impl Into<String>) -> Self {
+        self.implicit = true;
+        self.layer
+            .disable_environment
+            .insert(disable_environment.into(), "1".to_owned());
+        self
+    }
+
+    /// Only enables an implicit layer if `name` is set to `value`.
+    pub fn enable_environment(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.layer
+            .enable_environment
+            .insert(name.into(), value.into());
+        self
+    }
+
+    pub fn api_version(mut self, api_version: u32) -> Self {
+        self.layer.api_version = api_version_string(api_version);
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.layer.description = description.into();
+        self
+    }
+
+    pub fn instance_extension(mut self, name: impl Into<String>, spec_version: u32) -> Self {
+        self.layer.instance_extensions.push(ExtensionManifest {
+            name: name.into(),
+            spec_version: spec_version.to_string(),
+            entrypoints: Vec::new(),
+        });
+        self
+    }
+
+    pub fn device_extension(
+        mut self,
+        name: impl Into<String>,
+        spec_version: u32,
+        entrypoints: &[&str],
+    ) -> Self {
+        self.layer.device_extensions.push(ExtensionManifest {
+            name: name.into(),
+            spec_version: spec_version.to_string(),
+            entrypoints: entrypoints.iter().map(|v| v.to_string()).collect(),
+        });
+        self
+    }
+
+    /// The manifest being built, for fields without a dedicated setter.
+    pub fn layer_mut(&mut self) -> &mut LayerManifest {
+        &mut self.layer
+    }
+
+    /// File name of the cdylib, for the target being built when run from a build script and for
+    /// the host otherwise.
+    pub fn library_file(&self) -> String {
+        let (prefix, suffix) = match env::var("CARGO_CFG_TARGET_OS") {
+            Ok(os) => match os.as_str() {
+                "windows" => ("", ".dll"),
+                "macos" | "ios" => ("lib", ".dylib"),
+                _ => ("lib", ".so"),
+            },
+            Err(_) => (consts::DLL_PREFIX, consts::DLL_SUFFIX),
+        };
+        format!("{}{}{}", prefix, self.library_name, suffix)
+    }
+
+    /// Directory cargo puts the artifacts of the package being built in, i.e.
+    /// `<target dir>[/<target triple>]/<profile dir>`, when run from a build script.
+    pub fn artifact_dir() -> Option<PathBuf> {
+        let out_dir = PathBuf::from(env::var_os("OUT_DIR")?);
+        // <artifact dir>/build/<package>-<hash>/out
+        out_dir.ancestors().nth(3).map(Path::to_path_buf)
+    }
+
+    /// Validates the manifest and writes it as `<layer name>.json` into [`Self::artifact_dir`],
+    /// returning its path.
+    pub fn write(self) -> Result<PathBuf, ManifestError> {
+        let dir = Self::artifact_dir().ok_or_else(|| {
+            ManifestError::Io(io::Error::new(
+                io::ErrorKind::NotFound,
+                "OUT_DIR not set, not running in a build script",
+            ))
+        })?;
+        self.write_to(dir)
+    }
+
+    /// Like [`Self::write`] but into `dir`, the library path defaulting to the cdylib in
+    /// [`Self::artifact_dir`] if that is another directory.
+    pub fn write_to(mut self, dir: impl AsRef<Path>) -> Result<PathBuf, ManifestError> {
+        let dir = dir.as_ref();
+        let library_path = match self.library_path.take() {
+            Some(v) => v,
+            None => match Self::artifact_dir() {
+                Some(v) if !same_dir(&v, dir) => v.join(self.library_file()),
+                // relative to the manifest, a bare file name would be searched in the library
+                // paths
+                _ => Path::new(".").join(self.library_file()),
+            },
+        };
+        self.layer.library_path = Some(library_path);
+        if self.implicit && self.layer.disable_environment.is_empty() {
+            return Err(ManifestError::InvalidLayer {
+                layer: self.layer.name,
+                reason: "implicit layer without disable_environment",
+            });
+        }
+
+        let path = dir.join(format!("{}.json", self.layer.name));
+        let manifest = ManifestFile::new(self.layer);
+        manifest.validate()?;
+        fs::write(&path, manifest.to_json()? + "\n").map_err(ManifestError::Io)?;
+        Ok(path)
+    }
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn env_var(key: &str) -> String {
+    env::var(key).unwrap_or_default()
+}
@@ -0,0 +1,7 @@
+//! Layer manifest JSON read by the Vulkan loader to discover layers.
+
+mod build;
+mod model;
+
+pub use build::*;
+pub use model::*;
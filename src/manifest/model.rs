@@ -0,0 +1,373 @@
+use crate::*;
+
+use core::fmt;
+use core::str::FromStr;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use ash::vk;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Version of the manifest file format, `"major.minor.patch"` in JSON.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileFormatVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl FileFormatVersion {
+    /// Single `layer` object.
+    pub const V1_0_0: Self = Self::new(1, 0, 0);
+    /// `layers` array.
+    pub const V1_0_1: Self = Self::new(1, 0, 1);
+    /// Meta layers with `component_layers`.
+    pub const V1_1_1: Self = Self::new(1, 1, 1);
+    /// `pre_instance_functions`.
+    pub const V1_1_2: Self = Self::new(1, 1, 2);
+    /// `library_arch`.
+    pub const V1_2_1: Self = Self::new(1, 2, 1);
+    /// Newest version understood, later 1.2 patches are accepted as well.
+    pub const LATEST: Self = Self::V1_2_1;
+
+    #[inline]
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Whether this crate can read a manifest of this version.
+    #[inline]
+    pub fn is_supported(self) -> bool {
+        self.major == 1 && self.minor <= 2
+    }
+}
+
+impl fmt::Display for FileFormatVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for FileFormatVersion {
+    type Err = ManifestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (major, minor, patch) =
+            parse_triple(s).ok_or_else(|| ManifestError::InvalidVersion(s.to_owned()))?;
+        Ok(Self::new(major, minor, patch))
+    }
+}
+
+impl Serialize for FileFormatVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileFormatVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+fn parse_triple(s: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = s.split('.').map(|v| v.parse::<u32>().ok());
+    let triple = (parts.next()??, parts.next()??, parts.next()??);
+    match parts.next() {
+        None => Some(triple),
+        Some(_) => None,
+    }
+}
+
+/// Formats a Vulkan API version the way manifests spell `api_version`.
+pub fn api_version_string(api_version: u32) -> String {
+    format!(
+        "{}.{}.{}",
+        vk::api_version_major(api_version),
+        vk::api_version_minor(api_version),
+        vk::api_version_patch(api_version)
+    )
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum LayerType {
+    Global,
+    /// Deprecated, treated as global by the loader.
+    Instance,
+}
+
+/// Extension provided by a layer, `entrypoints` only apply to device extensions.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtensionManifest {
+    pub name: String,
+    pub spec_version: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entrypoints: Vec<String>,
+}
+
+/// Description of a single layer.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerManifest {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: LayerType,
+    /// Absent for meta layers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub library_path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub library_arch: Option<String>,
+    pub api_version: String,
+    pub implementation_version: String,
+    pub description: String,
+    /// Overrides of the exported entry point names.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub functions: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instance_extensions: Vec<ExtensionManifest>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub device_extensions: Vec<ExtensionManifest>,
+    /// Makes an implicit layer opt-in.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub enable_environment: BTreeMap<String, String>,
+    /// Mandatory for implicit layers.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub disable_environment: BTreeMap<String, String>,
+    /// Only honored for implicit layers.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pre_instance_functions: BTreeMap<String, String>,
+    /// Layers enabled by a meta layer.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub component_layers: Vec<String>,
+}
+
+impl LayerManifest {
+    /// Manifest of a layer built with [`declare_layer!`], exporting the
+    /// [`PRE_INSTANCE_FUNCTIONS`].
+    pub fn new(name: impl Into<String>, library_path: impl Into<PathBuf>) -> Self {
+        Self {
+            name: name.into(),
+            ty: LayerType::Global,
+            library_path: Some(library_path.into()),
+            library_arch: None,
+            api_version: api_version_string(vk::HEADER_VERSION_COMPLETE),
+            implementation_version: "1".to_owned(),
+            description: String::new(),
+            functions: BTreeMap::new(),
+            instance_extensions: Vec::new(),
+            device_extensions: Vec::new(),
+            enable_environment: BTreeMap::new(),
+            disable_environment: BTreeMap::new(),
+            pre_instance_functions: PRE_INSTANCE_FUNCTIONS
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            component_layers: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn is_meta_layer(&self) -> bool {
+        !self.component_layers.is_empty()
+    }
+
+    /// Oldest file format version able to describe this layer.
+    pub fn required_file_format_version(&self) -> FileFormatVersion {
+        if self.library_arch.is_some() {
+            FileFormatVersion::V1_2_1
+        } else if !self.pre_instance_functions.is_empty() {
+            FileFormatVersion::V1_1_2
+        } else if self.is_meta_layer() {
+            FileFormatVersion::V1_1_1
+        } else {
+            FileFormatVersion::V1_0_0
+        }
+    }
+
+    /// Name of the exported symbol the loader looks up for `function`.
+    pub fn exported_name<'a>(&'a self, function: &'a str) -> &'a str {
+        self.functions
+            .get(function)
+            .map_or(function, String::as_str)
+    }
+
+    /// Checks the fields the loader relies on.
+    pub fn validate(&self) -> Result<(), ManifestError> {
+        let invalid = |reason| ManifestError::InvalidLayer {
+            layer: self.name.clone(),
+            reason,
+        };
+        if self.name.is_empty() {
+            return Err(invalid("empty name"));
+        }
+        if parse_triple(&self.api_version).is_none() {
+            return Err(invalid("api_version is not major.minor.patch"));
+        }
+        match (&self.library_path, self.is_meta_layer()) {
+            (Some(_), true) => return Err(invalid("meta layer with library_path")),
+            (None, false) => return Err(invalid("missing library_path")),
+            _ => (),
+        }
+        if !self.enable_environment.is_empty() && self.disable_environment.is_empty() {
+            return Err(invalid("enable_environment without disable_environment"));
+        }
+        if matches!(self.library_arch.as_deref(), Some(v) if v != "32" && v != "64") {
+            return Err(invalid("library_arch is neither 32 nor 64"));
+        }
+        Ok(())
+    }
+
+    /// Checks the library exports the entry points named by the manifest, `has_symbol` tells
+    /// whether the library exports a symbol.
+    pub fn validate_exports(
+        &self,
+        mut has_symbol: impl FnMut(&str) -> bool,
+    ) -> Result<(), ManifestError> {
+        if self.is_meta_layer() {
+            return Ok(());
+        }
+        let missing = |symbol: &str| ManifestError::MissingExport {
+            layer: self.name.clone(),
+            symbol: symbol.to_owned(),
+        };
+        let negotiate = self.exported_name(VK_NEGOTIATE_LOADER_LAYER_INTERFACE_VERSION_SYM);
+        // layers predating negotiation export vkGetInstanceProcAddr directly
+        if !has_symbol(negotiate) && !has_symbol(self.exported_name("vkGetInstanceProcAddr")) {
+            return Err(missing(negotiate));
+        }
+        for symbol in self.pre_instance_functions.values() {
+            if !has_symbol(symbol) {
+                return Err(missing(symbol));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Contents of a layer manifest file.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestFile {
+    pub file_format_version: FileFormatVersion,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layer: Option<LayerManifest>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layers: Vec<LayerManifest>,
+}
+
+impl ManifestFile {
+    /// Manifest of a single layer with the oldest file format version describing it.
+    pub fn new(layer: LayerManifest) -> Self {
+        Self {
+            file_format_version: layer.required_file_format_version(),
+            layer: Some(layer),
+            layers: Vec::new(),
+        }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ManifestError> {
+        serde_json::from_str(json).map_err(ManifestError::Json)
+    }
+
+    pub fn to_json(&self) -> Result<String, ManifestError> {
+        serde_json::to_string_pretty(self).map_err(ManifestError::Json)
+    }
+
+    /// All layers of the manifest, `layer` and `layers` combined.
+    pub fn iter_layers(&self) -> impl Iterator<Item = &LayerManifest> {
+        self.layer.iter().chain(&self.layers)
+    }
+
+    /// Checks the file format version and every layer.
+    pub fn validate(&self) -> Result<(), ManifestError> {
+        if !self.file_format_version.is_supported() {
+            return Err(ManifestError::UnsupportedFileFormatVersion(
+                self.file_format_version,
+            ));
+        }
+        if self.iter_layers().next().is_none() {
+            return Err(ManifestError::NoLayer);
+        }
+        let mut required = FileFormatVersion::V1_0_0;
+        if !self.layers.is_empty() {
+            required = FileFormatVersion::V1_0_1;
+        }
+        for layer in self.iter_layers() {
+            layer.validate()?;
+            required = required.max(layer.required_file_format_version());
+        }
+        if self.file_format_version < required {
+            return Err(ManifestError::FileFormatVersionTooOld {
+                version: self.file_format_version,
+                required,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Error of reading or validating a manifest.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum ManifestError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    InvalidVersion(String),
+    UnsupportedFileFormatVersion(FileFormatVersion),
+    /// The manifest uses fields introduced after its file format version.
+    FileFormatVersionTooOld {
+        version: FileFormatVersion,
+        required: FileFormatVersion,
+    },
+    NoLayer,
+    InvalidLayer {
+        layer: String,
+        reason: &'static str,
+    },
+    MissingExport {
+        layer: String,
+        symbol: String,
+    },
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "manifest I/O failed: {}", err),
+            Self::Json(err) => write!(f, "malformed manifest: {}", err),
+            Self::InvalidVersion(v) => write!(f, "invalid version {:?}", v),
+            Self::UnsupportedFileFormatVersion(v) => {
+                write!(f, "unsupported file format version {}", v)
+            }
+            Self::FileFormatVersionTooOld { version, required } => write!(
+                f,
+                "file format version {} is older than the {} required",
+                version, required
+            ),
+            Self::NoLayer => f.write_str("no layer in manifest"),
+            Self::InvalidLayer { layer, reason } => write!(f, "layer {}: {}", layer, reason),
+            Self::MissingExport { layer, symbol } => {
+                write!(f, "layer {}: library does not export {}", layer, symbol)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "debug")]
+impl std::error::Error for ManifestError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,771 @@
+use super::MockLayer;
+use crate::*;
+
+use core::ffi::{c_char, c_void, CStr};
+use core::mem;
+use core::ptr;
+use std::collections::BTreeMap;
+use std::process;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use ash::vk;
+use ash::vk::Handle;
+
+/// Fake physical device answered by [`MockDriver`].
+#[derive(Clone)]
+pub struct MockPhysicalDevice {
+    pub properties: vk::PhysicalDeviceProperties,
+    pub features: vk::PhysicalDeviceFeatures,
+    pub queue_families: Vec<vk::QueueFamilyProperties>,
+    pub memory_properties: vk::PhysicalDeviceMemoryProperties,
+    pub extensions: Vec<vk::ExtensionProperties>,
+}
+
+impl Default for MockPhysicalDevice {
+    fn default() -> Self {
+        let mut properties = vk::PhysicalDeviceProperties {
+            api_version: vk::API_VERSION_1_3,
+            device_type: vk::PhysicalDeviceType::CPU,
+            ..Default::default()
+        };
+        for (dst, src) in properties.device_name.iter_mut().zip(b"Mock Device") {
+            *dst = *src as c_char;
+        }
+
+        let mut memory_properties = vk::PhysicalDeviceMemoryProperties {
+            memory_type_count: 1,
+            memory_heap_count: 1,
+            ..Default::default()
+        };
+        memory_properties.memory_types[0] = vk::MemoryType {
+            property_flags: vk::MemoryPropertyFlags::DEVICE_LOCAL
+                | vk::MemoryPropertyFlags::HOST_VISIBLE
+                | vk::MemoryPropertyFlags::HOST_COHERENT,
+            heap_index: 0,
+        };
+        memory_properties.memory_heaps[0] = vk::MemoryHeap {
+            size: 256 << 20,
+            flags: vk::MemoryHeapFlags::DEVICE_LOCAL,
+        };
+
+        Self {
+            properties,
+            features: Default::default(),
+            queue_families: vec![vk::QueueFamilyProperties {
+                queue_flags: vk::QueueFlags::GRAPHICS
+                    | vk::QueueFlags::COMPUTE
+                    | vk::QueueFlags::TRANSFER,
+                queue_count: 1,
+                ..Default::default()
+            }],
+            memory_properties,
+            extensions: Vec::new(),
+        }
+    }
+}
+
+/// Command received by [`MockDriver`] with its dispatchable handle argument.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MockCall {
+    pub command: &'static str,
+    pub handle: u64,
+}
+
+/// Scriptable bottom of the chain for [`MockLoader`](super::MockLoader), recording every
+/// command it receives.
+pub struct MockDriver {
+    physical_devices: Vec<MockPhysicalDevice>,
+    results: Mutex<BTreeMap<&'static str, vk::Result>>,
+    calls: Mutex<Vec<MockCall>>,
+    stub_commands: AtomicBool,
+}
+
+impl Default for MockDriver {
+    fn default() -> Self {
+        Self::new(vec![MockPhysicalDevice::default()])
+    }
+}
+
+impl MockDriver {
+    pub fn new(physical_devices: Vec<MockPhysicalDevice>) -> Self {
+        Self {
+            physical_devices,
+            results: Mutex::new(BTreeMap::new()),
+            calls: Mutex::new(Vec::new()),
+            stub_commands: AtomicBool::new(false),
+        }
+    }
+
+    pub fn physical_devices(&self) -> &[MockPhysicalDevice] {
+        &self.physical_devices
+    }
+
+    /// Makes `command` fail with `result` without doing anything until cleared.
+    pub fn inject_result(&self, command: &'static str, result: vk::Result) {
+        self.results.lock().unwrap().insert(command, result);
+    }
+
+    pub fn clear_result(&self, command: &str) {
+        self.results.lock().unwrap().remove(command);
+    }
+
+    /// Resolves the registry commands the driver does not implement to a stub aborting the
+    /// process when called, so that layers find every command supported below.
+    pub fn set_stub_commands(&self, enabled: bool) {
+        self.stub_commands.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Commands received so far, in order.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    pub fn take_calls(&self) -> Vec<MockCall> {
+        mem::take(&mut *self.calls.lock().unwrap())
+    }
+
+    /// Number of times `command` was received.
+    pub fn call_count(&self, command: &str) -> usize {
+        let calls = self.calls.lock().unwrap();
+        calls.iter().filter(|call| call.command == command).count()
+    }
+
+    fn record(&self, command: &'static str, handle: u64) {
+        let call = MockCall { command, handle };
+        self.calls.lock().unwrap().push(call);
+    }
+
+    fn injected(&self, command: &str) -> Option<vk::Result> {
+        self.results.lock().unwrap().get(command).copied()
+    }
+
+    /// Stub of `name` if it is a registry command of `level` and stubs are enabled.
+    fn stub(&self, name: &CStr, level: CommandLevel) -> vk::PFN_vkVoidFunction {
+        if !self.stub_commands.load(Ordering::Relaxed) {
+            return None;
+        }
+        match registry::command(name) {
+            Some(command) if command.level == level => Some(terminator_stub),
+            _ => None,
+        }
+    }
+}
+
+// Dispatchable objects start with the loader dispatch pointer, which doubles as dispatch key.
+// The mock sets it to the address of the owning instance or device object.
+
+#[repr(C)]
+pub(crate) struct InstanceObject {
+    pub(crate) loader_data: *const c_void,
+    pub(crate) driver: Arc<MockDriver>,
+    pub(crate) physical_devices: Vec<PhysicalDeviceObject>,
+    // layers to create layer private devices through
+    pub(crate) layers: Vec<MockLayer>,
+    pub(crate) created: bool,
+}
+
+#[repr(C)]
+pub(crate) struct PhysicalDeviceObject {
+    pub(crate) loader_data: *const c_void,
+    pub(crate) instance: *const InstanceObject,
+    pub(crate) index: usize,
+}
+
+#[repr(C)]
+pub(crate) struct DeviceObject {
+    pub(crate) loader_data: *const c_void,
+    pub(crate) driver: Arc<MockDriver>,
+    // boxed to keep queue handles stable while the list grows
+    #[allow(clippy::vec_box)]
+    pub(crate) queues: Mutex<Vec<Box<QueueObject>>>,
+    pub(crate) command_buffers: Mutex<BTreeMap<u64, vk::CommandPool>>,
+    pub(crate) memory: Mutex<BTreeMap<u64, Vec<u8>>>,
+    pub(crate) next_handle: AtomicU64,
+}
+
+#[repr(C)]
+pub(crate) struct QueueObject {
+    pub(crate) loader_data: *const c_void,
+    pub(crate) family_index: u32,
+    pub(crate) index: u32,
+}
+
+#[repr(C)]
+pub(crate) struct CommandBufferObject {
+    pub(crate) loader_data: *const c_void,
+}
+
+impl InstanceObject {
+    pub(crate) fn new(driver: Arc<MockDriver>) -> Box<Self> {
+        let mut this = Box::new(Self {
+            loader_data: ptr::null(),
+            driver,
+            physical_devices: Vec::new(),
+            layers: Vec::new(),
+            created: false,
+        });
+        let key = &*this as *const Self as *const c_void;
+        this.loader_data = key;
+        this.physical_devices = (0..this.driver.physical_devices.len())
+            .map(|index| PhysicalDeviceObject {
+                loader_data: key,
+                instance: key.cast(),
+                index,
+            })
+            .collect();
+        this
+    }
+
+    pub(crate) fn handle(&self) -> vk::Instance {
+        vk::Instance::from_raw(self as *const Self as u64)
+    }
+}
+
+impl PhysicalDeviceObject {
+    unsafe fn from_handle<'a>(physical_device: vk::PhysicalDevice) -> &'a Self {
+        &*(physical_device.as_raw() as *const Self)
+    }
+
+    unsafe fn driver(&self) -> &MockDriver {
+        &(*self.instance).driver
+    }
+
+    unsafe fn config(&self) -> &MockPhysicalDevice {
+        &self.driver().physical_devices[self.index]
+    }
+}
+
+impl DeviceObject {
+    unsafe fn from_handle<'a>(device: vk::Device) -> &'a Self {
+        &*(device.as_raw() as *const Self)
+    }
+
+    fn new_handle(&self) -> u64 {
+        self.next_handle.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+impl Drop for DeviceObject {
+    fn drop(&mut self) {
+        for command_buffer in self.command_buffers.get_mut().unwrap().keys() {
+            unsafe { drop(Box::from_raw(*command_buffer as *mut CommandBufferObject)) }
+        }
+    }
+}
+
+macro_rules! pfn {
+    ($f:expr, $ty:ty) => {{
+        let f: $ty = $f;
+        Some(mem::transmute::<$ty, unsafe extern "system" fn()>(f))
+    }};
+}
+
+macro_rules! try_injected {
+    ($driver:expr, $command:literal, $handle:expr) => {{
+        let driver = $driver;
+        driver.record($command, $handle.as_raw());
+        if let Some(res) = driver.injected($command) {
+            return res;
+        }
+    }};
+}
+
+pub(crate) unsafe extern "system" fn terminator_get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    match CStr::from_ptr(p_name).to_bytes() {
+        b"vkGetInstanceProcAddr" => pfn!(
+            terminator_get_instance_proc_addr,
+            vk::PFN_vkGetInstanceProcAddr
+        ),
+        b"vkCreateInstance" => pfn!(terminator_create_instance, vk::PFN_vkCreateInstance),
+        b"vkDestroyInstance" => pfn!(terminator_destroy_instance, vk::PFN_vkDestroyInstance),
+        b"vkEnumeratePhysicalDevices" => pfn!(
+            terminator_enumerate_physical_devices,
+            vk::PFN_vkEnumeratePhysicalDevices
+        ),
+        b"vkGetPhysicalDeviceProperties" => pfn!(
+            terminator_get_physical_device_properties,
+            vk::PFN_vkGetPhysicalDeviceProperties
+        ),
+        b"vkGetPhysicalDeviceFeatures" => pfn!(
+            terminator_get_physical_device_features,
+            vk::PFN_vkGetPhysicalDeviceFeatures
+        ),
+        b"vkGetPhysicalDeviceQueueFamilyProperties" => pfn!(
+            terminator_get_physical_device_queue_family_properties,
+            vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties
+        ),
+        b"vkGetPhysicalDeviceMemoryProperties" => pfn!(
+            terminator_get_physical_device_memory_properties,
+            vk::PFN_vkGetPhysicalDeviceMemoryProperties
+        ),
+        b"vkEnumerateDeviceExtensionProperties" => pfn!(
+            terminator_enumerate_device_extension_properties,
+            vk::PFN_vkEnumerateDeviceExtensionProperties
+        ),
+        b"vkCreateDevice" => pfn!(terminator_create_device, vk::PFN_vkCreateDevice),
+        _ => {
+            let pfn = terminator_get_device_proc_addr(vk::Device::null(), p_name);
+            let object = instance.as_raw() as *const InstanceObject;
+            if pfn.is_some() || object.is_null() {
+                return pfn;
+            }
+            let name = CStr::from_ptr(p_name);
+            let driver = &(*object).driver;
+            [
+                CommandLevel::Instance,
+                CommandLevel::PhysicalDevice,
+                CommandLevel::Device,
+            ]
+            .into_iter()
+            .find_map(|level| driver.stub(name, level))
+        }
+    }
+}
+
+pub(crate) unsafe extern "system" fn terminator_get_physical_device_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    let object = instance.as_raw() as *const InstanceObject;
+    if object.is_null() {
+        return None;
+    }
+    let name = CStr::from_ptr(p_name);
+    (*object).driver.stub(name, CommandLevel::PhysicalDevice)
+}
+const _: PFN_vk_layerGetPhysicalDeviceProcAddr = terminator_get_physical_device_proc_addr;
+
+pub(crate) unsafe extern "system" fn terminator_get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    match CStr::from_ptr(p_name).to_bytes() {
+        b"vkGetDeviceProcAddr" => {
+            pfn!(terminator_get_device_proc_addr, vk::PFN_vkGetDeviceProcAddr)
+        }
+        b"vkDestroyDevice" => pfn!(terminator_destroy_device, vk::PFN_vkDestroyDevice),
+        b"vkDeviceWaitIdle" => pfn!(terminator_device_wait_idle, vk::PFN_vkDeviceWaitIdle),
+        b"vkGetDeviceQueue" => pfn!(terminator_get_device_queue, vk::PFN_vkGetDeviceQueue),
+        b"vkQueueSubmit" => pfn!(terminator_queue_submit, vk::PFN_vkQueueSubmit),
+        b"vkQueueWaitIdle" => pfn!(terminator_queue_wait_idle, vk::PFN_vkQueueWaitIdle),
+        b"vkCreateCommandPool" => {
+            pfn!(terminator_create_command_pool, vk::PFN_vkCreateCommandPool)
+        }
+        b"vkDestroyCommandPool" => {
+            pfn!(
+                terminator_destroy_command_pool,
+                vk::PFN_vkDestroyCommandPool
+            )
+        }
+        b"vkAllocateCommandBuffers" => pfn!(
+            terminator_allocate_command_buffers,
+            vk::PFN_vkAllocateCommandBuffers
+        ),
+        b"vkFreeCommandBuffers" => {
+            pfn!(
+                terminator_free_command_buffers,
+                vk::PFN_vkFreeCommandBuffers
+            )
+        }
+        b"vkBeginCommandBuffer" => {
+            pfn!(
+                terminator_begin_command_buffer,
+                vk::PFN_vkBeginCommandBuffer
+            )
+        }
+        b"vkEndCommandBuffer" => pfn!(terminator_end_command_buffer, vk::PFN_vkEndCommandBuffer),
+        b"vkAllocateMemory" => pfn!(terminator_allocate_memory, vk::PFN_vkAllocateMemory),
+        b"vkFreeMemory" => pfn!(terminator_free_memory, vk::PFN_vkFreeMemory),
+        b"vkMapMemory" => pfn!(terminator_map_memory, vk::PFN_vkMapMemory),
+        b"vkUnmapMemory" => pfn!(terminator_unmap_memory, vk::PFN_vkUnmapMemory),
+        _ => {
+            let object = device.as_raw() as *const DeviceObject;
+            if object.is_null() {
+                return None;
+            }
+            let name = CStr::from_ptr(p_name);
+            (*object).driver.stub(name, CommandLevel::Device)
+        }
+    }
+}
+
+unsafe extern "system" fn terminator_stub() {
+    eprintln!("called a command the mock driver only stubs");
+    process::abort();
+}
+
+pub(crate) unsafe extern "system" fn terminator_set_instance_loader_data(
+    instance: vk::Instance,
+    p_object: *mut c_void,
+) -> vk::Result {
+    let instance = instance.as_raw() as *const InstanceObject;
+    if instance.is_null() || p_object.is_null() {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    *p_object.cast::<*const c_void>() = (*instance).loader_data;
+    vk::Result::SUCCESS
+}
+const _: PFN_vkSetInstanceLoaderData = terminator_set_instance_loader_data;
+
+pub(crate) unsafe extern "system" fn terminator_set_device_loader_data(
+    device: vk::Device,
+    p_object: *mut c_void,
+) -> vk::Result {
+    let device = device.as_raw() as *const DeviceObject;
+    if device.is_null() || p_object.is_null() {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    *p_object.cast::<*const c_void>() = (*device).loader_data;
+    vk::Result::SUCCESS
+}
+const _: PFN_vkSetDeviceLoaderData = terminator_set_device_loader_data;
+
+// The mock loader allocates the instance object and passes it in `*p_instance` down the chain,
+// like the real loader does.
+unsafe extern "system" fn terminator_create_instance(
+    _p_create_info: *const vk::InstanceCreateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    let instance = (*p_instance).as_raw() as *mut InstanceObject;
+    if instance.is_null() {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    try_injected!(&(*instance).driver, "vkCreateInstance", *p_instance);
+    (*instance).created = true;
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_destroy_instance(
+    instance: vk::Instance,
+    _p_allocator: *const vk::AllocationCallbacks,
+) {
+    let object = instance.as_raw() as *mut InstanceObject;
+    if !object.is_null() {
+        (*object)
+            .driver
+            .record("vkDestroyInstance", instance.as_raw());
+        (*object).created = false;
+    }
+}
+
+unsafe extern "system" fn terminator_enumerate_physical_devices(
+    instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    let object = &*(instance.as_raw() as *const InstanceObject);
+    try_injected!(&object.driver, "vkEnumeratePhysicalDevices", instance);
+    let physical_devices: Vec<_> = object
+        .physical_devices
+        .iter()
+        .map(|v| vk::PhysicalDevice::from_raw(v as *const _ as u64))
+        .collect();
+    write_array(
+        &physical_devices,
+        p_physical_device_count,
+        p_physical_devices,
+    )
+}
+
+unsafe extern "system" fn terminator_get_physical_device_properties(
+    physical_device: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties,
+) {
+    let object = PhysicalDeviceObject::from_handle(physical_device);
+    let driver = object.driver();
+    driver.record("vkGetPhysicalDeviceProperties", physical_device.as_raw());
+    *p_properties = object.config().properties;
+}
+
+unsafe extern "system" fn terminator_get_physical_device_features(
+    physical_device: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures,
+) {
+    let object = PhysicalDeviceObject::from_handle(physical_device);
+    let driver = object.driver();
+    driver.record("vkGetPhysicalDeviceFeatures", physical_device.as_raw());
+    *p_features = object.config().features;
+}
+
+unsafe extern "system" fn terminator_get_physical_device_queue_family_properties(
+    physical_device: vk::PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut vk::QueueFamilyProperties,
+) {
+    let object = PhysicalDeviceObject::from_handle(physical_device);
+    let driver = object.driver();
+    driver.record(
+        "vkGetPhysicalDeviceQueueFamilyProperties",
+        physical_device.as_raw(),
+    );
+    // void command, the count already reports truncation
+    let _ = write_array(
+        &object.config().queue_families,
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    );
+}
+
+unsafe extern "system" fn terminator_get_physical_device_memory_properties(
+    physical_device: vk::PhysicalDevice,
+    p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties,
+) {
+    let object = PhysicalDeviceObject::from_handle(physical_device);
+    let driver = object.driver();
+    driver.record(
+        "vkGetPhysicalDeviceMemoryProperties",
+        physical_device.as_raw(),
+    );
+    *p_memory_properties = object.config().memory_properties;
+}
+
+unsafe extern "system" fn terminator_enumerate_device_extension_properties(
+    physical_device: vk::PhysicalDevice,
+    p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let object = PhysicalDeviceObject::from_handle(physical_device);
+    try_injected!(
+        object.driver(),
+        "vkEnumerateDeviceExtensionProperties",
+        physical_device
+    );
+    // layers are not known to the driver
+    if !p_layer_name.is_null() {
+        return vk::Result::ERROR_LAYER_NOT_PRESENT;
+    }
+    write_array(&object.config().extensions, p_property_count, p_properties)
+}
+
+unsafe extern "system" fn terminator_create_device(
+    physical_device: vk::PhysicalDevice,
+    _p_create_info: *const vk::DeviceCreateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    let object = PhysicalDeviceObject::from_handle(physical_device);
+    try_injected!(object.driver(), "vkCreateDevice", physical_device);
+    let mut device = Box::new(DeviceObject {
+        loader_data: ptr::null(),
+        driver: (*object.instance).driver.clone(),
+        queues: Mutex::new(Vec::new()),
+        command_buffers: Mutex::new(BTreeMap::new()),
+        memory: Mutex::new(BTreeMap::new()),
+        next_handle: AtomicU64::new(1),
+    });
+    device.loader_data = &*device as *const DeviceObject as *const c_void;
+    *p_device = vk::Device::from_raw(Box::into_raw(device) as u64);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_destroy_device(
+    device: vk::Device,
+    _p_allocator: *const vk::AllocationCallbacks,
+) {
+    let object = device.as_raw() as *mut DeviceObject;
+    if !object.is_null() {
+        (*object).driver.record("vkDestroyDevice", device.as_raw());
+        drop(Box::from_raw(object));
+    }
+}
+
+unsafe extern "system" fn terminator_device_wait_idle(device: vk::Device) -> vk::Result {
+    let object = DeviceObject::from_handle(device);
+    try_injected!(&object.driver, "vkDeviceWaitIdle", device);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_get_device_queue(
+    device: vk::Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut vk::Queue,
+) {
+    let object = DeviceObject::from_handle(device);
+    object.driver.record("vkGetDeviceQueue", device.as_raw());
+    let mut queues = object.queues.lock().unwrap();
+    let queue = match queues
+        .iter()
+        .find(|q| q.family_index == queue_family_index && q.index == queue_index)
+    {
+        Some(v) => &**v as *const QueueObject,
+        None => {
+            queues.push(Box::new(QueueObject {
+                loader_data: object.loader_data,
+                family_index: queue_family_index,
+                index: queue_index,
+            }));
+            &**queues.last().unwrap() as *const QueueObject
+        }
+    };
+    *p_queue = vk::Queue::from_raw(queue as u64);
+}
+
+unsafe fn driver_of<'a, H: DispatchableHandle>(handle: H) -> &'a MockDriver {
+    // queues and command buffers share the dispatch key, i.e. address, of their device
+    let device = handle.dispatch_key().unwrap().as_raw() as *const DeviceObject;
+    &(*device).driver
+}
+
+unsafe extern "system" fn terminator_queue_submit(
+    queue: vk::Queue,
+    _submit_count: u32,
+    _p_submits: *const vk::SubmitInfo,
+    _fence: vk::Fence,
+) -> vk::Result {
+    try_injected!(driver_of(queue), "vkQueueSubmit", queue);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_queue_wait_idle(queue: vk::Queue) -> vk::Result {
+    try_injected!(driver_of(queue), "vkQueueWaitIdle", queue);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_create_command_pool(
+    device: vk::Device,
+    _p_create_info: *const vk::CommandPoolCreateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_command_pool: *mut vk::CommandPool,
+) -> vk::Result {
+    let object = DeviceObject::from_handle(device);
+    try_injected!(&object.driver, "vkCreateCommandPool", device);
+    *p_command_pool = vk::CommandPool::from_raw(object.new_handle());
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_destroy_command_pool(
+    device: vk::Device,
+    command_pool: vk::CommandPool,
+    _p_allocator: *const vk::AllocationCallbacks,
+) {
+    let object = DeviceObject::from_handle(device);
+    object
+        .driver
+        .record("vkDestroyCommandPool", device.as_raw());
+    object.command_buffers.lock().unwrap().retain(|cb, pool| {
+        if *pool == command_pool {
+            drop(Box::from_raw(*cb as *mut CommandBufferObject));
+            false
+        } else {
+            true
+        }
+    });
+}
+
+unsafe extern "system" fn terminator_allocate_command_buffers(
+    device: vk::Device,
+    p_allocate_info: *const vk::CommandBufferAllocateInfo,
+    p_command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    let object = DeviceObject::from_handle(device);
+    try_injected!(&object.driver, "vkAllocateCommandBuffers", device);
+    let allocate_info = &*p_allocate_info;
+    let mut command_buffers = object.command_buffers.lock().unwrap();
+    for i in 0..allocate_info.command_buffer_count as usize {
+        let command_buffer = Box::into_raw(Box::new(CommandBufferObject {
+            loader_data: object.loader_data,
+        })) as u64;
+        command_buffers.insert(command_buffer, allocate_info.command_pool);
+        *p_command_buffers.add(i) = vk::CommandBuffer::from_raw(command_buffer);
+    }
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_free_command_buffers(
+    device: vk::Device,
+    _command_pool: vk::CommandPool,
+    command_buffer_count: u32,
+    p_command_buffers: *const vk::CommandBuffer,
+) {
+    let object = DeviceObject::from_handle(device);
+    object
+        .driver
+        .record("vkFreeCommandBuffers", device.as_raw());
+    let mut command_buffers = object.command_buffers.lock().unwrap();
+    for i in 0..command_buffer_count as usize {
+        let command_buffer = (*p_command_buffers.add(i)).as_raw();
+        if command_buffers.remove(&command_buffer).is_some() {
+            drop(Box::from_raw(command_buffer as *mut CommandBufferObject));
+        }
+    }
+}
+
+unsafe extern "system" fn terminator_begin_command_buffer(
+    command_buffer: vk::CommandBuffer,
+    _p_begin_info: *const vk::CommandBufferBeginInfo,
+) -> vk::Result {
+    try_injected!(
+        driver_of(command_buffer),
+        "vkBeginCommandBuffer",
+        command_buffer
+    );
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_end_command_buffer(
+    command_buffer: vk::CommandBuffer,
+) -> vk::Result {
+    try_injected!(
+        driver_of(command_buffer),
+        "vkEndCommandBuffer",
+        command_buffer
+    );
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_allocate_memory(
+    device: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    let object = DeviceObject::from_handle(device);
+    try_injected!(&object.driver, "vkAllocateMemory", device);
+    let handle = object.new_handle();
+    let size = (*p_allocate_info).allocation_size as usize;
+    object.memory.lock().unwrap().insert(handle, vec![0; size]);
+    *p_memory = vk::DeviceMemory::from_raw(handle);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_free_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    _p_allocator: *const vk::AllocationCallbacks,
+) {
+    let object = DeviceObject::from_handle(device);
+    object.driver.record("vkFreeMemory", device.as_raw());
+    object.memory.lock().unwrap().remove(&memory.as_raw());
+}
+
+unsafe extern "system" fn terminator_map_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    _size: vk::DeviceSize,
+    _flags: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    let object = DeviceObject::from_handle(device);
+    try_injected!(&object.driver, "vkMapMemory", device);
+    let mut memory_map = object.memory.lock().unwrap();
+    let data = match memory_map.get_mut(&memory.as_raw()) {
+        Some(v) if (offset as usize) <= v.len() => v,
+        _ => return vk::Result::ERROR_MEMORY_MAP_FAILED,
+    };
+    *pp_data = data.as_mut_ptr().add(offset as usize).cast();
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn terminator_unmap_memory(device: vk::Device, _memory: vk::DeviceMemory) {
+    let object = DeviceObject::from_handle(device);
+    object.driver.record("vkUnmapMemory", device.as_raw());
+}
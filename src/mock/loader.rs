@@ -0,0 +1,392 @@
+use super::driver::*;
+use crate::*;
+
+use core::ffi::{c_void, CStr};
+use core::mem;
+use core::ptr;
+use std::sync::Arc;
+
+use ash::prelude::VkResult;
+use ash::vk;
+use ash::vk::Handle;
+
+/// Entry points of a layer negotiated by [`MockLoader`].
+#[derive(Clone, Copy)]
+pub struct MockLayer {
+    pub interface_version: u32,
+    pub get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    pub get_device_proc_addr: Option<vk::PFN_vkGetDeviceProcAddr>,
+    pub get_physical_device_proc_addr: Option<PFN_vk_layerGetPhysicalDeviceProcAddr>,
+}
+
+/// In-process stand-in for the Vulkan loader, chaining layers on top of a [`MockDriver`].
+#[derive(Clone, Default)]
+pub struct MockLoader {
+    driver: Arc<MockDriver>,
+    layers: Vec<MockLayer>,
+    features: LoaderFeatureFlagBits,
+}
+
+impl MockLoader {
+    /// Creates a loader on top of a default [`MockDriver`] with one physical device.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_driver(driver: Arc<MockDriver>) -> Self {
+        Self {
+            driver,
+            layers: Vec::new(),
+            features: LoaderFeatureFlagBits::empty(),
+        }
+    }
+
+    pub fn driver(&self) -> &Arc<MockDriver> {
+        &self.driver
+    }
+
+    /// Features reported to the layers in the instance chain.
+    pub fn set_loader_features(&mut self, features: LoaderFeatureFlagBits) {
+        self.features = features;
+    }
+
+    /// Negotiates with a layer and stacks it below the layers added before.
+    pub unsafe fn add_layer(
+        &mut self,
+        negotiate: PFN_vkNegotiateLoaderLayerInterfaceVersion,
+    ) -> VkResult<MockLayer> {
+        let mut version_struct = NegotiateLayerInterface {
+            s_type: NegotiateLayerStructType::INTERFACE_STRUCT,
+            p_next: ptr::null_mut(),
+            loader_layer_interface_version: CURRENT_LOADER_LAYER_INTERFACE_VERSION,
+            pfn_get_instance_proc_addr: None,
+            pfn_get_device_proc_addr: None,
+            pfn_get_physical_device_proc_addr: None,
+        };
+        negotiate(&mut version_struct).result()?;
+        // older interfaces export entry points by name, which can't be looked up in-process
+        if version_struct.loader_layer_interface_version < 2 {
+            return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+        }
+        let layer = MockLayer {
+            interface_version: version_struct.loader_layer_interface_version,
+            get_instance_proc_addr: version_struct
+                .pfn_get_instance_proc_addr
+                .ok_or(vk::Result::ERROR_INITIALIZATION_FAILED)?,
+            get_device_proc_addr: version_struct.pfn_get_device_proc_addr,
+            get_physical_device_proc_addr: version_struct.pfn_get_physical_device_proc_addr,
+        };
+        self.layers.push(layer);
+        Ok(layer)
+    }
+
+    /// Layers from the top, closest to the application, to the bottom of the chain.
+    pub fn layers(&self) -> &[MockLayer] {
+        &self.layers
+    }
+
+    fn get_instance_proc_addr(&self) -> vk::PFN_vkGetInstanceProcAddr {
+        match self.layers.first() {
+            Some(layer) => layer.get_instance_proc_addr,
+            None => terminator_get_instance_proc_addr,
+        }
+    }
+
+    /// Creates an instance through all layers, inserting the loader chain entries into
+    /// `create_info`.
+    pub unsafe fn create_instance(
+        &self,
+        create_info: &vk::InstanceCreateInfo,
+    ) -> VkResult<MockInstance<'_>> {
+        let mut links: Vec<LayerInstanceLink> = (0..self.layers.len())
+            .map(|i| match self.layers.get(i + 1) {
+                Some(next) => LayerInstanceLink {
+                    p_next: ptr::null_mut(),
+                    pfn_next_get_instance_proc_addr: Some(next.get_instance_proc_addr),
+                    pfn_next_get_physical_device_proc_addr: next.get_physical_device_proc_addr,
+                },
+                None => LayerInstanceLink {
+                    p_next: ptr::null_mut(),
+                    pfn_next_get_instance_proc_addr: Some(terminator_get_instance_proc_addr),
+                    pfn_next_get_physical_device_proc_addr: Some(
+                        terminator_get_physical_device_proc_addr,
+                    ),
+                },
+            })
+            .collect();
+        for i in 1..links.len() {
+            links[i - 1].p_next = &mut links[i];
+        }
+
+        let features_info = LayerInstanceCreateInfo {
+            p_next: create_info.p_next,
+            ..LayerInstanceCreateInfo::new(InstanceChainKind::LoaderFeatures(self.features))
+        };
+        let layer_device_info = LayerInstanceCreateInfo {
+            p_next: &features_info as *const _ as *const c_void,
+            ..LayerInstanceCreateInfo::new(InstanceChainKind::CreateDeviceCallback(
+                LayerInstanceCreateInfoLayerDevice {
+                    pfn_layer_create_device: Some(mock_layer_create_device),
+                    pfn_layer_destroy_device: Some(mock_layer_destroy_device),
+                },
+            ))
+        };
+        let loader_data_info = LayerInstanceCreateInfo {
+            p_next: &layer_device_info as *const _ as *const c_void,
+            ..LayerInstanceCreateInfo::new(InstanceChainKind::LoaderDataCallback(Some(
+                terminator_set_instance_loader_data,
+            )))
+        };
+        let link_info = LayerInstanceCreateInfo {
+            p_next: &loader_data_info as *const _ as *const c_void,
+            ..LayerInstanceCreateInfo::new(InstanceChainKind::LinkInfo(
+                links.first_mut().map_or(ptr::null_mut(), |v| v as *mut _),
+            ))
+        };
+        let create_info = vk::InstanceCreateInfo {
+            p_next: &link_info as *const _ as *const c_void,
+            ..*create_info
+        };
+
+        let name = CStr::from_bytes_with_nul_unchecked(b"vkCreateInstance\0");
+        let create_instance =
+            match (self.get_instance_proc_addr())(vk::Instance::null(), name.as_ptr()) {
+                Some(v) => {
+                    mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateInstance>(v)
+                }
+                None => return Err(vk::Result::ERROR_INITIALIZATION_FAILED),
+            };
+
+        let mut object = InstanceObject::new(self.driver.clone());
+        object.layers = self.layers.clone();
+        let mut instance = object.handle();
+        create_instance(&create_info, ptr::null(), &mut instance).result()?;
+        if !object.created || instance != object.handle() {
+            return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+        }
+
+        let table = ash::Instance::load(
+            &vk::StaticFn {
+                get_instance_proc_addr: self.get_instance_proc_addr(),
+            },
+            instance,
+        );
+        Ok(MockInstance {
+            loader: self,
+            object,
+            table,
+        })
+    }
+}
+
+/// Instance created by [`MockLoader`], destroyed through the layers on drop.
+pub struct MockInstance<'a> {
+    loader: &'a MockLoader,
+    object: Box<InstanceObject>,
+    table: ash::Instance,
+}
+
+impl<'a> MockInstance<'a> {
+    #[inline]
+    pub fn handle(&self) -> vk::Instance {
+        self.object.handle()
+    }
+
+    /// Function table resolved through the top layer's `vkGetInstanceProcAddr`.
+    #[inline]
+    pub fn table(&self) -> &ash::Instance {
+        &self.table
+    }
+
+    pub unsafe fn get_instance_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction {
+        (self.loader.get_instance_proc_addr())(self.handle(), name.as_ptr())
+    }
+
+    /// Creates a device through all layers, inserting the loader chain entries into
+    /// `create_info`.
+    pub unsafe fn create_device(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        create_info: &vk::DeviceCreateInfo,
+    ) -> VkResult<MockDevice<'_>> {
+        let mut device = vk::Device::null();
+        let get_device_proc_addr = create_device_chain(
+            self.handle(),
+            &self.loader.layers,
+            physical_device,
+            create_info,
+            ptr::null(),
+            &mut device,
+        )?;
+
+        let mut instance_fn = self.table.fp_v1_0().clone();
+        instance_fn.get_device_proc_addr = get_device_proc_addr;
+        Ok(MockDevice {
+            _instance: self,
+            get_device_proc_addr,
+            table: ash::Device::load(&instance_fn, device),
+        })
+    }
+}
+
+/// Creates a device through `layers`, returning the top layer's `vkGetDeviceProcAddr`.
+unsafe fn create_device_chain(
+    instance: vk::Instance,
+    layers: &[MockLayer],
+    physical_device: vk::PhysicalDevice,
+    create_info: &vk::DeviceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> VkResult<vk::PFN_vkGetDeviceProcAddr> {
+    let get_device_proc_addr = |layer: &MockLayer| -> Option<vk::PFN_vkGetDeviceProcAddr> {
+        if let Some(v) = layer.get_device_proc_addr {
+            return Some(v);
+        }
+        let name = CStr::from_bytes_with_nul_unchecked(b"vkGetDeviceProcAddr\0");
+        let pfn = (layer.get_instance_proc_addr)(instance, name.as_ptr())?;
+        Some(mem::transmute::<
+            unsafe extern "system" fn(),
+            vk::PFN_vkGetDeviceProcAddr,
+        >(pfn))
+    };
+
+    let mut links = Vec::with_capacity(layers.len());
+    for i in 0..layers.len() {
+        links.push(match layers.get(i + 1) {
+            Some(next) => LayerDeviceLink {
+                p_next: ptr::null_mut(),
+                pfn_next_get_instance_proc_addr: Some(next.get_instance_proc_addr),
+                pfn_next_get_device_proc_addr: Some(
+                    get_device_proc_addr(next).ok_or(vk::Result::ERROR_INITIALIZATION_FAILED)?,
+                ),
+            },
+            None => LayerDeviceLink {
+                p_next: ptr::null_mut(),
+                pfn_next_get_instance_proc_addr: Some(terminator_get_instance_proc_addr),
+                pfn_next_get_device_proc_addr: Some(terminator_get_device_proc_addr),
+            },
+        });
+    }
+    for i in 1..links.len() {
+        links[i - 1].p_next = &mut links[i];
+    }
+    let (top_get_instance_proc_addr, top_get_device_proc_addr) = match layers.first() {
+        Some(layer) => (
+            layer.get_instance_proc_addr,
+            get_device_proc_addr(layer).ok_or(vk::Result::ERROR_INITIALIZATION_FAILED)?,
+        ),
+        None => (
+            terminator_get_instance_proc_addr as vk::PFN_vkGetInstanceProcAddr,
+            terminator_get_device_proc_addr as vk::PFN_vkGetDeviceProcAddr,
+        ),
+    };
+
+    let loader_data_info = LayerDeviceCreateInfo {
+        p_next: create_info.p_next,
+        ..LayerDeviceCreateInfo::new(DeviceChainKind::LoaderDataCallback(Some(
+            terminator_set_device_loader_data,
+        )))
+    };
+    let link_info = LayerDeviceCreateInfo {
+        p_next: &loader_data_info as *const _ as *const c_void,
+        ..LayerDeviceCreateInfo::new(DeviceChainKind::LinkInfo(
+            links.first_mut().map_or(ptr::null_mut(), |v| v as *mut _),
+        ))
+    };
+    let create_info = vk::DeviceCreateInfo {
+        p_next: &link_info as *const _ as *const c_void,
+        ..*create_info
+    };
+
+    let name = CStr::from_bytes_with_nul_unchecked(b"vkCreateDevice\0");
+    let create_device = match top_get_instance_proc_addr(instance, name.as_ptr()) {
+        Some(v) => mem::transmute::<unsafe extern "system" fn(), vk::PFN_vkCreateDevice>(v),
+        None => return Err(vk::Result::ERROR_INITIALIZATION_FAILED),
+    };
+    create_device(physical_device, &create_info, p_allocator, p_device).result()?;
+    Ok(top_get_device_proc_addr)
+}
+
+unsafe extern "system" fn mock_layer_create_device(
+    instance: vk::Instance,
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+    layer_get_instance_proc_addr: Option<vk::PFN_vkGetInstanceProcAddr>,
+    p_next_get_device_proc_addr: *mut Option<vk::PFN_vkGetDeviceProcAddr>,
+) -> vk::Result {
+    let object = &*(instance.as_raw() as *const InstanceObject);
+    // the chain starts below the calling layer
+    let below = match layer_get_instance_proc_addr.and_then(|caller| {
+        let caller = caller as usize;
+        let layers = &object.layers;
+        let index = layers
+            .iter()
+            .position(|v| v.get_instance_proc_addr as usize == caller)?;
+        Some(&layers[index + 1..])
+    }) {
+        Some(v) => v,
+        None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+    };
+    match create_device_chain(
+        instance,
+        below,
+        physical_device,
+        &*p_create_info,
+        p_allocator,
+        p_device,
+    ) {
+        Ok(v) => {
+            *p_next_get_device_proc_addr = Some(v);
+            vk::Result::SUCCESS
+        }
+        Err(err) => err,
+    }
+}
+const _: PFN_vkLayerCreateDevice = mock_layer_create_device;
+
+unsafe extern "system" fn mock_layer_destroy_device(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks,
+    destroy_device: vk::PFN_vkDestroyDevice,
+) {
+    destroy_device(device, p_allocator)
+}
+const _: PFN_vkLayerDestroyDevice = mock_layer_destroy_device;
+
+impl<'a> Drop for MockInstance<'a> {
+    fn drop(&mut self) {
+        unsafe { self.table.destroy_instance(None) }
+    }
+}
+
+/// Device created by [`MockInstance`], destroyed through the layers on drop.
+pub struct MockDevice<'a> {
+    _instance: &'a MockInstance<'a>,
+    get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+    table: ash::Device,
+}
+
+impl<'a> MockDevice<'a> {
+    #[inline]
+    pub fn handle(&self) -> vk::Device {
+        self.table.handle()
+    }
+
+    /// Function table resolved through the top layer's `vkGetDeviceProcAddr`.
+    #[inline]
+    pub fn table(&self) -> &ash::Device {
+        &self.table
+    }
+
+    pub unsafe fn get_device_proc_addr(&self, name: &CStr) -> vk::PFN_vkVoidFunction {
+        (self.get_device_proc_addr)(self.handle(), name.as_ptr())
+    }
+}
+
+impl<'a> Drop for MockDevice<'a> {
+    fn drop(&mut self) {
+        unsafe { self.table.destroy_device(None) }
+    }
+}
@@ -0,0 +1,7 @@
+//! In-process stand-ins for the Vulkan loader and driver, to exercise layers without a GPU.
+
+mod driver;
+mod loader;
+
+pub use driver::{MockCall, MockDriver, MockPhysicalDevice};
+pub use loader::*;
@@ -0,0 +1,75 @@
+use crate::*;
+
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+
+/// What the entry points of a layer do when a [`Layer`] method panics, as unwinding into the
+/// loader is not possible.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum PanicPolicy {
+    /// Aborts the process.
+    #[default]
+    Abort,
+    /// Fails the call, with `ERROR_INITIALIZATION_FAILED` for commands creating an instance or
+    /// device, `ERROR_UNKNOWN` for others and null for `vkGet*ProcAddr`.
+    ReturnError,
+    /// Passes the call through as if the method was not implemented by the layer.
+    ///
+    /// If a [`Module`]'s pre call panics, its changes to by-value arguments are dropped, but
+    /// writes it already made through pointer arguments are not undone.
+    PassThrough,
+}
+
+/// Runs `f` on behalf of `command`, applying [`Layer::panic_policy`] if it panics.
+///
+/// Aborts or returns the policy to apply, after reporting the panic on stderr in addition to
+/// the panic hook. Hooks written with [`hook!`](crate::hook) run under it.
+pub fn catch_panic<L: Layer, R>(command: &str, f: impl FnOnce() -> R) -> Result<R, PanicPolicy> {
+    let err = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(v) => return Ok(v),
+        Err(err) => err,
+    };
+    let message = err
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| err.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>");
+    Err(report::<L>("panicked in", command, message))
+}
+
+/// Applies [`Layer::panic_policy`] to `command` failing other than by a panic, e.g. when nothing
+/// below supports it, rather than returning an error that may look like success.
+pub(crate) fn fail<L: Layer>(command: &str, message: &str) -> PanicPolicy {
+    report::<L>("failed", command, message)
+}
+
+/// Reports on stderr what happened to `command` and aborts or returns the policy to apply.
+fn report<L: Layer>(what: &str, command: &str, message: &str) -> PanicPolicy {
+    let policy = panic::catch_unwind(L::panic_policy).unwrap_or_default();
+    let name = if L::NAME.is_empty() {
+        "ash-layer"
+    } else {
+        L::NAME
+    };
+    let action = match policy {
+        PanicPolicy::Abort => "aborting",
+        PanicPolicy::ReturnError => "returning an error",
+        PanicPolicy::PassThrough => "passing the call through",
+    };
+    // unlike eprintln! this can't panic again
+    let _ = writeln!(
+        io::stderr(),
+        "[{}] {} {}: {}, {}",
+        name,
+        what,
+        command,
+        message,
+        action
+    );
+    if policy == PanicPolicy::Abort {
+        process::abort();
+    }
+    policy
+}
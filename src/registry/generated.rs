@@ -0,0 +1,1972 @@
+// Generated by the generator crate from vk.xml 1.3.251, do not edit.
+
+use super::*;
+
+use Dispatchable::*;
+use Provider::*;
+
+pub(super) const COMMANDS: &[Command] = &[
+    Command::new("vkAcquireDrmDisplayEXT", Some(PhysicalDevice), &[Extension("VK_EXT_acquire_drm_display")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkAcquireFullScreenExclusiveModeEXT", Some(Device), &[Extension("VK_EXT_full_screen_exclusive")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkAcquireNextImage2KHR", Some(Device), &[Extension("VK_KHR_swapchain"), Extension("VK_KHR_device_group")], &[vk::Result::SUCCESS, vk::Result::TIMEOUT, vk::Result::NOT_READY, vk::Result::SUBOPTIMAL_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_OUT_OF_DATE_KHR, vk::Result::ERROR_SURFACE_LOST_KHR, vk::Result::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT]),
+    Command::new("vkAcquireNextImageKHR", Some(Device), &[Extension("VK_KHR_swapchain")], &[vk::Result::SUCCESS, vk::Result::TIMEOUT, vk::Result::NOT_READY, vk::Result::SUBOPTIMAL_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_OUT_OF_DATE_KHR, vk::Result::ERROR_SURFACE_LOST_KHR, vk::Result::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT]),
+    Command::new("vkAcquirePerformanceConfigurationINTEL", Some(Device), &[Extension("VK_INTEL_performance_query")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkAcquireProfilingLockKHR", Some(Device), &[Extension("VK_KHR_performance_query")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::TIMEOUT]),
+    Command::new("vkAcquireWinrtDisplayNV", Some(PhysicalDevice), &[Extension("VK_NV_acquire_winrt_display")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkAcquireXlibDisplayEXT", Some(PhysicalDevice), &[Extension("VK_EXT_acquire_xlib_display")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkAllocateCommandBuffers", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkAllocateDescriptorSets", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_FRAGMENTED_POOL, vk::Result::ERROR_OUT_OF_POOL_MEMORY]),
+    Command::new("vkAllocateMemory", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkBeginCommandBuffer", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkBindAccelerationStructureMemoryNV", Some(Device), &[Extension("VK_NV_ray_tracing")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkBindBufferMemory", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkBindBufferMemory2", Some(Device), &[Core(vk::API_VERSION_1_1)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkBindBufferMemory2KHR", Some(Device), &[Extension("VK_KHR_bind_memory2")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkBindImageMemory", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkBindImageMemory2", Some(Device), &[Core(vk::API_VERSION_1_1)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkBindImageMemory2KHR", Some(Device), &[Extension("VK_KHR_bind_memory2")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkBindOpticalFlowSessionImageNV", Some(Device), &[Extension("VK_NV_optical_flow")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkBindVideoSessionMemoryKHR", Some(Device), &[Extension("VK_KHR_video_queue")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkBuildAccelerationStructuresKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[vk::Result::SUCCESS, vk::Result::OPERATION_DEFERRED_KHR, vk::Result::OPERATION_NOT_DEFERRED_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkBuildMicromapsEXT", Some(Device), &[Extension("VK_EXT_opacity_micromap")], &[vk::Result::SUCCESS, vk::Result::OPERATION_DEFERRED_KHR, vk::Result::OPERATION_NOT_DEFERRED_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCmdBeginConditionalRenderingEXT", Some(CommandBuffer), &[Extension("VK_EXT_conditional_rendering")], &[], &[]),
+    Command::new("vkCmdBeginDebugUtilsLabelEXT", Some(CommandBuffer), &[Extension("VK_EXT_debug_utils")], &[], &[]),
+    Command::new("vkCmdBeginQuery", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdBeginQueryIndexedEXT", Some(CommandBuffer), &[Extension("VK_EXT_transform_feedback")], &[], &[]),
+    Command::new("vkCmdBeginRenderPass", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdBeginRenderPass2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_2)], &[], &[]),
+    Command::new("vkCmdBeginRenderPass2KHR", Some(CommandBuffer), &[Extension("VK_KHR_create_renderpass2")], &[], &[]),
+    Command::new("vkCmdBeginRendering", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdBeginRenderingKHR", Some(CommandBuffer), &[Extension("VK_KHR_dynamic_rendering")], &[], &[]),
+    Command::new("vkCmdBeginTransformFeedbackEXT", Some(CommandBuffer), &[Extension("VK_EXT_transform_feedback")], &[], &[]),
+    Command::new("vkCmdBeginVideoCodingKHR", Some(CommandBuffer), &[Extension("VK_KHR_video_queue")], &[], &[]),
+    Command::new("vkCmdBindDescriptorBufferEmbeddedSamplersEXT", Some(CommandBuffer), &[Extension("VK_EXT_descriptor_buffer")], &[], &[]),
+    Command::new("vkCmdBindDescriptorBuffersEXT", Some(CommandBuffer), &[Extension("VK_EXT_descriptor_buffer")], &[], &[]),
+    Command::new("vkCmdBindDescriptorSets", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdBindIndexBuffer", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdBindInvocationMaskHUAWEI", Some(CommandBuffer), &[Extension("VK_HUAWEI_invocation_mask")], &[], &[]),
+    Command::new("vkCmdBindPipeline", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdBindPipelineShaderGroupNV", Some(CommandBuffer), &[Extension("VK_NV_device_generated_commands")], &[], &[]),
+    Command::new("vkCmdBindShadersEXT", Some(CommandBuffer), &[Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdBindShadingRateImageNV", Some(CommandBuffer), &[Extension("VK_NV_shading_rate_image")], &[], &[]),
+    Command::new("vkCmdBindTransformFeedbackBuffersEXT", Some(CommandBuffer), &[Extension("VK_EXT_transform_feedback")], &[], &[]),
+    Command::new("vkCmdBindVertexBuffers", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdBindVertexBuffers2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdBindVertexBuffers2EXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdBlitImage", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdBlitImage2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdBlitImage2KHR", Some(CommandBuffer), &[Extension("VK_KHR_copy_commands2")], &[], &[]),
+    Command::new("vkCmdBuildAccelerationStructureNV", Some(CommandBuffer), &[Extension("VK_NV_ray_tracing")], &[], &[]),
+    Command::new("vkCmdBuildAccelerationStructuresIndirectKHR", Some(CommandBuffer), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkCmdBuildAccelerationStructuresKHR", Some(CommandBuffer), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkCmdBuildMicromapsEXT", Some(CommandBuffer), &[Extension("VK_EXT_opacity_micromap")], &[], &[]),
+    Command::new("vkCmdClearAttachments", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdClearColorImage", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdClearDepthStencilImage", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdControlVideoCodingKHR", Some(CommandBuffer), &[Extension("VK_KHR_video_queue")], &[], &[]),
+    Command::new("vkCmdCopyAccelerationStructureKHR", Some(CommandBuffer), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkCmdCopyAccelerationStructureNV", Some(CommandBuffer), &[Extension("VK_NV_ray_tracing")], &[], &[]),
+    Command::new("vkCmdCopyAccelerationStructureToMemoryKHR", Some(CommandBuffer), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkCmdCopyBuffer", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdCopyBuffer2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdCopyBuffer2KHR", Some(CommandBuffer), &[Extension("VK_KHR_copy_commands2")], &[], &[]),
+    Command::new("vkCmdCopyBufferToImage", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdCopyBufferToImage2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdCopyBufferToImage2KHR", Some(CommandBuffer), &[Extension("VK_KHR_copy_commands2")], &[], &[]),
+    Command::new("vkCmdCopyImage", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdCopyImage2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdCopyImage2KHR", Some(CommandBuffer), &[Extension("VK_KHR_copy_commands2")], &[], &[]),
+    Command::new("vkCmdCopyImageToBuffer", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdCopyImageToBuffer2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdCopyImageToBuffer2KHR", Some(CommandBuffer), &[Extension("VK_KHR_copy_commands2")], &[], &[]),
+    Command::new("vkCmdCopyMemoryIndirectNV", Some(CommandBuffer), &[Extension("VK_NV_copy_memory_indirect")], &[], &[]),
+    Command::new("vkCmdCopyMemoryToAccelerationStructureKHR", Some(CommandBuffer), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkCmdCopyMemoryToImageIndirectNV", Some(CommandBuffer), &[Extension("VK_NV_copy_memory_indirect")], &[], &[]),
+    Command::new("vkCmdCopyMemoryToMicromapEXT", Some(CommandBuffer), &[Extension("VK_EXT_opacity_micromap")], &[], &[]),
+    Command::new("vkCmdCopyMicromapEXT", Some(CommandBuffer), &[Extension("VK_EXT_opacity_micromap")], &[], &[]),
+    Command::new("vkCmdCopyMicromapToMemoryEXT", Some(CommandBuffer), &[Extension("VK_EXT_opacity_micromap")], &[], &[]),
+    Command::new("vkCmdCopyQueryPoolResults", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdCuLaunchKernelNVX", Some(CommandBuffer), &[Extension("VK_NVX_binary_import")], &[], &[]),
+    Command::new("vkCmdDebugMarkerBeginEXT", Some(CommandBuffer), &[Extension("VK_EXT_debug_marker")], &[], &[]),
+    Command::new("vkCmdDebugMarkerEndEXT", Some(CommandBuffer), &[Extension("VK_EXT_debug_marker")], &[], &[]),
+    Command::new("vkCmdDebugMarkerInsertEXT", Some(CommandBuffer), &[Extension("VK_EXT_debug_marker")], &[], &[]),
+    Command::new("vkCmdDecodeVideoKHR", Some(CommandBuffer), &[Extension("VK_KHR_video_decode_queue")], &[], &[]),
+    Command::new("vkCmdDecompressMemoryIndirectCountNV", Some(CommandBuffer), &[Extension("VK_NV_memory_decompression")], &[], &[]),
+    Command::new("vkCmdDecompressMemoryNV", Some(CommandBuffer), &[Extension("VK_NV_memory_decompression")], &[], &[]),
+    Command::new("vkCmdDispatch", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdDispatchBase", Some(CommandBuffer), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkCmdDispatchBaseKHR", Some(CommandBuffer), &[Extension("VK_KHR_device_group")], &[], &[]),
+    Command::new("vkCmdDispatchIndirect", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdDraw", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdDrawClusterHUAWEI", Some(CommandBuffer), &[Extension("VK_HUAWEI_cluster_culling_shader")], &[], &[]),
+    Command::new("vkCmdDrawClusterIndirectHUAWEI", Some(CommandBuffer), &[Extension("VK_HUAWEI_cluster_culling_shader")], &[], &[]),
+    Command::new("vkCmdDrawIndexed", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdDrawIndexedIndirect", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdDrawIndexedIndirectCount", Some(CommandBuffer), &[Core(vk::API_VERSION_1_2)], &[], &[]),
+    Command::new("vkCmdDrawIndexedIndirectCountAMD", Some(CommandBuffer), &[Extension("VK_AMD_draw_indirect_count")], &[], &[]),
+    Command::new("vkCmdDrawIndexedIndirectCountKHR", Some(CommandBuffer), &[Extension("VK_KHR_draw_indirect_count")], &[], &[]),
+    Command::new("vkCmdDrawIndirect", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdDrawIndirectByteCountEXT", Some(CommandBuffer), &[Extension("VK_EXT_transform_feedback")], &[], &[]),
+    Command::new("vkCmdDrawIndirectCount", Some(CommandBuffer), &[Core(vk::API_VERSION_1_2)], &[], &[]),
+    Command::new("vkCmdDrawIndirectCountAMD", Some(CommandBuffer), &[Extension("VK_AMD_draw_indirect_count")], &[], &[]),
+    Command::new("vkCmdDrawIndirectCountKHR", Some(CommandBuffer), &[Extension("VK_KHR_draw_indirect_count")], &[], &[]),
+    Command::new("vkCmdDrawMeshTasksEXT", Some(CommandBuffer), &[Extension("VK_EXT_mesh_shader")], &[], &[]),
+    Command::new("vkCmdDrawMeshTasksIndirectCountEXT", Some(CommandBuffer), &[Extension("VK_EXT_mesh_shader")], &[], &[]),
+    Command::new("vkCmdDrawMeshTasksIndirectCountNV", Some(CommandBuffer), &[Extension("VK_NV_mesh_shader")], &[], &[]),
+    Command::new("vkCmdDrawMeshTasksIndirectEXT", Some(CommandBuffer), &[Extension("VK_EXT_mesh_shader")], &[], &[]),
+    Command::new("vkCmdDrawMeshTasksIndirectNV", Some(CommandBuffer), &[Extension("VK_NV_mesh_shader")], &[], &[]),
+    Command::new("vkCmdDrawMeshTasksNV", Some(CommandBuffer), &[Extension("VK_NV_mesh_shader")], &[], &[]),
+    Command::new("vkCmdDrawMultiEXT", Some(CommandBuffer), &[Extension("VK_EXT_multi_draw")], &[], &[]),
+    Command::new("vkCmdDrawMultiIndexedEXT", Some(CommandBuffer), &[Extension("VK_EXT_multi_draw")], &[], &[]),
+    Command::new("vkCmdEncodeVideoKHR", Some(CommandBuffer), &[Extension("VK_KHR_video_encode_queue")], &[], &[]),
+    Command::new("vkCmdEndConditionalRenderingEXT", Some(CommandBuffer), &[Extension("VK_EXT_conditional_rendering")], &[], &[]),
+    Command::new("vkCmdEndDebugUtilsLabelEXT", Some(CommandBuffer), &[Extension("VK_EXT_debug_utils")], &[], &[]),
+    Command::new("vkCmdEndQuery", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdEndQueryIndexedEXT", Some(CommandBuffer), &[Extension("VK_EXT_transform_feedback")], &[], &[]),
+    Command::new("vkCmdEndRenderPass", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdEndRenderPass2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_2)], &[], &[]),
+    Command::new("vkCmdEndRenderPass2KHR", Some(CommandBuffer), &[Extension("VK_KHR_create_renderpass2")], &[], &[]),
+    Command::new("vkCmdEndRendering", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdEndRenderingKHR", Some(CommandBuffer), &[Extension("VK_KHR_dynamic_rendering")], &[], &[]),
+    Command::new("vkCmdEndTransformFeedbackEXT", Some(CommandBuffer), &[Extension("VK_EXT_transform_feedback")], &[], &[]),
+    Command::new("vkCmdEndVideoCodingKHR", Some(CommandBuffer), &[Extension("VK_KHR_video_queue")], &[], &[]),
+    Command::new("vkCmdExecuteCommands", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdExecuteGeneratedCommandsNV", Some(CommandBuffer), &[Extension("VK_NV_device_generated_commands")], &[], &[]),
+    Command::new("vkCmdFillBuffer", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdInsertDebugUtilsLabelEXT", Some(CommandBuffer), &[Extension("VK_EXT_debug_utils")], &[], &[]),
+    Command::new("vkCmdNextSubpass", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdNextSubpass2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_2)], &[], &[]),
+    Command::new("vkCmdNextSubpass2KHR", Some(CommandBuffer), &[Extension("VK_KHR_create_renderpass2")], &[], &[]),
+    Command::new("vkCmdOpticalFlowExecuteNV", Some(CommandBuffer), &[Extension("VK_NV_optical_flow")], &[], &[]),
+    Command::new("vkCmdPipelineBarrier", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdPipelineBarrier2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdPipelineBarrier2KHR", Some(CommandBuffer), &[Extension("VK_KHR_synchronization2")], &[], &[]),
+    Command::new("vkCmdPreprocessGeneratedCommandsNV", Some(CommandBuffer), &[Extension("VK_NV_device_generated_commands")], &[], &[]),
+    Command::new("vkCmdPushConstants", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdPushDescriptorSetKHR", Some(CommandBuffer), &[Extension("VK_KHR_push_descriptor")], &[], &[]),
+    Command::new("vkCmdPushDescriptorSetWithTemplateKHR", Some(CommandBuffer), &[Extension("VK_KHR_push_descriptor"), Extension("VK_KHR_descriptor_update_template")], &[], &[]),
+    Command::new("vkCmdResetEvent", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdResetEvent2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdResetEvent2KHR", Some(CommandBuffer), &[Extension("VK_KHR_synchronization2")], &[], &[]),
+    Command::new("vkCmdResetQueryPool", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdResolveImage", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdResolveImage2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdResolveImage2KHR", Some(CommandBuffer), &[Extension("VK_KHR_copy_commands2")], &[], &[]),
+    Command::new("vkCmdSetAlphaToCoverageEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetAlphaToOneEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetAttachmentFeedbackLoopEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_attachment_feedback_loop_dynamic_state")], &[], &[]),
+    Command::new("vkCmdSetBlendConstants", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetCheckpointNV", Some(CommandBuffer), &[Extension("VK_NV_device_diagnostic_checkpoints")], &[], &[]),
+    Command::new("vkCmdSetCoarseSampleOrderNV", Some(CommandBuffer), &[Extension("VK_NV_shading_rate_image")], &[], &[]),
+    Command::new("vkCmdSetColorBlendAdvancedEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetColorBlendEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetColorBlendEquationEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetColorWriteEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_color_write_enable")], &[], &[]),
+    Command::new("vkCmdSetColorWriteMaskEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetConservativeRasterizationModeEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetCoverageModulationModeNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetCoverageModulationTableEnableNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetCoverageModulationTableNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetCoverageReductionModeNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetCoverageToColorEnableNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetCoverageToColorLocationNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetCullMode", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetCullModeEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetDepthBias", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetDepthBiasEnable", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetDepthBiasEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state2"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetDepthBounds", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetDepthBoundsTestEnable", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetDepthBoundsTestEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetDepthClampEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetDepthClipEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetDepthClipNegativeOneToOneEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetDepthCompareOp", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetDepthCompareOpEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetDepthTestEnable", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetDepthTestEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetDepthWriteEnable", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetDepthWriteEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetDescriptorBufferOffsetsEXT", Some(CommandBuffer), &[Extension("VK_EXT_descriptor_buffer")], &[], &[]),
+    Command::new("vkCmdSetDeviceMask", Some(CommandBuffer), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkCmdSetDeviceMaskKHR", Some(CommandBuffer), &[Extension("VK_KHR_device_group")], &[], &[]),
+    Command::new("vkCmdSetDiscardRectangleEXT", Some(CommandBuffer), &[Extension("VK_EXT_discard_rectangles")], &[], &[]),
+    Command::new("vkCmdSetDiscardRectangleEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_discard_rectangles")], &[], &[]),
+    Command::new("vkCmdSetDiscardRectangleModeEXT", Some(CommandBuffer), &[Extension("VK_EXT_discard_rectangles")], &[], &[]),
+    Command::new("vkCmdSetEvent", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetEvent2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetEvent2KHR", Some(CommandBuffer), &[Extension("VK_KHR_synchronization2")], &[], &[]),
+    Command::new("vkCmdSetExclusiveScissorEnableNV", Some(CommandBuffer), &[Extension("VK_NV_scissor_exclusive")], &[], &[]),
+    Command::new("vkCmdSetExclusiveScissorNV", Some(CommandBuffer), &[Extension("VK_NV_scissor_exclusive")], &[], &[]),
+    Command::new("vkCmdSetExtraPrimitiveOverestimationSizeEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetFragmentShadingRateEnumNV", Some(CommandBuffer), &[Extension("VK_NV_fragment_shading_rate_enums")], &[], &[]),
+    Command::new("vkCmdSetFragmentShadingRateKHR", Some(CommandBuffer), &[Extension("VK_KHR_fragment_shading_rate")], &[], &[]),
+    Command::new("vkCmdSetFrontFace", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetFrontFaceEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetLineRasterizationModeEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetLineStippleEXT", Some(CommandBuffer), &[Extension("VK_EXT_line_rasterization")], &[], &[]),
+    Command::new("vkCmdSetLineStippleEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetLineWidth", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetLogicOpEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state2"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetLogicOpEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetPatchControlPointsEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state2"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetPerformanceMarkerINTEL", Some(CommandBuffer), &[Extension("VK_INTEL_performance_query")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCmdSetPerformanceOverrideINTEL", Some(CommandBuffer), &[Extension("VK_INTEL_performance_query")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCmdSetPerformanceStreamMarkerINTEL", Some(CommandBuffer), &[Extension("VK_INTEL_performance_query")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCmdSetPolygonModeEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetPrimitiveRestartEnable", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetPrimitiveRestartEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state2"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetPrimitiveTopology", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetPrimitiveTopologyEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetProvokingVertexModeEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetRasterizationSamplesEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetRasterizationStreamEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetRasterizerDiscardEnable", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetRasterizerDiscardEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state2"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetRayTracingPipelineStackSizeKHR", Some(CommandBuffer), &[Extension("VK_KHR_ray_tracing_pipeline")], &[], &[]),
+    Command::new("vkCmdSetRepresentativeFragmentTestEnableNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetSampleLocationsEXT", Some(CommandBuffer), &[Extension("VK_EXT_sample_locations")], &[], &[]),
+    Command::new("vkCmdSetSampleLocationsEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetSampleMaskEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetScissor", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetScissorWithCount", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetScissorWithCountEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetShadingRateImageEnableNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetStencilCompareMask", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetStencilOp", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetStencilOpEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetStencilReference", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetStencilTestEnable", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetStencilTestEnableEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetStencilWriteMask", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetTessellationDomainOriginEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetVertexInputEXT", Some(CommandBuffer), &[Extension("VK_EXT_vertex_input_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetViewport", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdSetViewportShadingRatePaletteNV", Some(CommandBuffer), &[Extension("VK_NV_shading_rate_image")], &[], &[]),
+    Command::new("vkCmdSetViewportSwizzleNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetViewportWScalingEnableNV", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state3"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSetViewportWScalingNV", Some(CommandBuffer), &[Extension("VK_NV_clip_space_w_scaling")], &[], &[]),
+    Command::new("vkCmdSetViewportWithCount", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdSetViewportWithCountEXT", Some(CommandBuffer), &[Extension("VK_EXT_extended_dynamic_state"), Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkCmdSubpassShadingHUAWEI", Some(CommandBuffer), &[Extension("VK_HUAWEI_subpass_shading")], &[], &[]),
+    Command::new("vkCmdTraceRaysIndirect2KHR", Some(CommandBuffer), &[Extension("VK_KHR_ray_tracing_maintenance1")], &[], &[]),
+    Command::new("vkCmdTraceRaysIndirectKHR", Some(CommandBuffer), &[Extension("VK_KHR_ray_tracing_pipeline")], &[], &[]),
+    Command::new("vkCmdTraceRaysKHR", Some(CommandBuffer), &[Extension("VK_KHR_ray_tracing_pipeline")], &[], &[]),
+    Command::new("vkCmdTraceRaysNV", Some(CommandBuffer), &[Extension("VK_NV_ray_tracing")], &[], &[]),
+    Command::new("vkCmdUpdateBuffer", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdWaitEvents", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdWaitEvents2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdWaitEvents2KHR", Some(CommandBuffer), &[Extension("VK_KHR_synchronization2")], &[], &[]),
+    Command::new("vkCmdWriteAccelerationStructuresPropertiesKHR", Some(CommandBuffer), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkCmdWriteAccelerationStructuresPropertiesNV", Some(CommandBuffer), &[Extension("VK_NV_ray_tracing")], &[], &[]),
+    Command::new("vkCmdWriteBufferMarker2AMD", Some(CommandBuffer), &[Extension("VK_KHR_synchronization2")], &[], &[]),
+    Command::new("vkCmdWriteBufferMarkerAMD", Some(CommandBuffer), &[Extension("VK_AMD_buffer_marker")], &[], &[]),
+    Command::new("vkCmdWriteMicromapsPropertiesEXT", Some(CommandBuffer), &[Extension("VK_EXT_opacity_micromap")], &[], &[]),
+    Command::new("vkCmdWriteTimestamp", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkCmdWriteTimestamp2", Some(CommandBuffer), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkCmdWriteTimestamp2KHR", Some(CommandBuffer), &[Extension("VK_KHR_synchronization2")], &[], &[]),
+    Command::new("vkCompileDeferredNV", Some(Device), &[Extension("VK_NV_ray_tracing")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCopyAccelerationStructureKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[vk::Result::SUCCESS, vk::Result::OPERATION_DEFERRED_KHR, vk::Result::OPERATION_NOT_DEFERRED_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCopyAccelerationStructureToMemoryKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[vk::Result::SUCCESS, vk::Result::OPERATION_DEFERRED_KHR, vk::Result::OPERATION_NOT_DEFERRED_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCopyMemoryToAccelerationStructureKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[vk::Result::SUCCESS, vk::Result::OPERATION_DEFERRED_KHR, vk::Result::OPERATION_NOT_DEFERRED_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCopyMemoryToMicromapEXT", Some(Device), &[Extension("VK_EXT_opacity_micromap")], &[vk::Result::SUCCESS, vk::Result::OPERATION_DEFERRED_KHR, vk::Result::OPERATION_NOT_DEFERRED_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCopyMicromapEXT", Some(Device), &[Extension("VK_EXT_opacity_micromap")], &[vk::Result::SUCCESS, vk::Result::OPERATION_DEFERRED_KHR, vk::Result::OPERATION_NOT_DEFERRED_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCopyMicromapToMemoryEXT", Some(Device), &[Extension("VK_EXT_opacity_micromap")], &[vk::Result::SUCCESS, vk::Result::OPERATION_DEFERRED_KHR, vk::Result::OPERATION_NOT_DEFERRED_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateAccelerationStructureKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkCreateAccelerationStructureNV", Some(Device), &[Extension("VK_NV_ray_tracing")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCreateAndroidSurfaceKHR", Some(Instance), &[Extension("VK_KHR_android_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_NATIVE_WINDOW_IN_USE_KHR]),
+    Command::new("vkCreateBuffer", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkCreateBufferCollectionFUCHSIA", Some(Device), &[Extension("VK_FUCHSIA_buffer_collection")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkCreateBufferView", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateCommandPool", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateComputePipelines", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::PIPELINE_COMPILE_REQUIRED_EXT], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_SHADER_NV]),
+    Command::new("vkCreateCuFunctionNVX", Some(Device), &[Extension("VK_NVX_binary_import")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkCreateCuModuleNVX", Some(Device), &[Extension("VK_NVX_binary_import")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkCreateDebugReportCallbackEXT", Some(Instance), &[Extension("VK_EXT_debug_report")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCreateDebugUtilsMessengerEXT", Some(Instance), &[Extension("VK_EXT_debug_utils")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCreateDeferredOperationKHR", Some(Device), &[Extension("VK_KHR_deferred_host_operations")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCreateDescriptorPool", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_FRAGMENTATION_EXT]),
+    Command::new("vkCreateDescriptorSetLayout", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateDescriptorUpdateTemplate", Some(Device), &[Core(vk::API_VERSION_1_1)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateDescriptorUpdateTemplateKHR", Some(Device), &[Extension("VK_KHR_descriptor_update_template")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateDevice", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_EXTENSION_NOT_PRESENT, vk::Result::ERROR_FEATURE_NOT_PRESENT, vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkCreateDirectFBSurfaceEXT", Some(Instance), &[Extension("VK_EXT_directfb_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateDisplayModeKHR", Some(PhysicalDevice), &[Extension("VK_KHR_display")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkCreateDisplayPlaneSurfaceKHR", Some(Instance), &[Extension("VK_KHR_display")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateEvent", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateFence", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateFramebuffer", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateGraphicsPipelines", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::PIPELINE_COMPILE_REQUIRED_EXT], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_SHADER_NV]),
+    Command::new("vkCreateHeadlessSurfaceEXT", Some(Instance), &[Extension("VK_EXT_headless_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateIOSSurfaceMVK", Some(Instance), &[Extension("VK_MVK_ios_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_NATIVE_WINDOW_IN_USE_KHR]),
+    Command::new("vkCreateImage", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_COMPRESSION_EXHAUSTED_EXT, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkCreateImagePipeSurfaceFUCHSIA", Some(Instance), &[Extension("VK_FUCHSIA_imagepipe_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateImageView", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkCreateIndirectCommandsLayoutNV", Some(Device), &[Extension("VK_NV_device_generated_commands")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateInstance", None, &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_LAYER_NOT_PRESENT, vk::Result::ERROR_EXTENSION_NOT_PRESENT, vk::Result::ERROR_INCOMPATIBLE_DRIVER]),
+    Command::new("vkCreateMacOSSurfaceMVK", Some(Instance), &[Extension("VK_MVK_macos_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_NATIVE_WINDOW_IN_USE_KHR]),
+    Command::new("vkCreateMetalSurfaceEXT", Some(Instance), &[Extension("VK_EXT_metal_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_NATIVE_WINDOW_IN_USE_KHR]),
+    Command::new("vkCreateMicromapEXT", Some(Device), &[Extension("VK_EXT_opacity_micromap")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkCreateOpticalFlowSessionNV", Some(Device), &[Extension("VK_NV_optical_flow")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkCreatePipelineCache", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreatePipelineLayout", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreatePrivateDataSlot", Some(Device), &[Core(vk::API_VERSION_1_3)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCreatePrivateDataSlotEXT", Some(Device), &[Extension("VK_EXT_private_data")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCreateQueryPool", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateRayTracingPipelinesKHR", Some(Device), &[Extension("VK_KHR_ray_tracing_pipeline")], &[vk::Result::SUCCESS, vk::Result::OPERATION_DEFERRED_KHR, vk::Result::OPERATION_NOT_DEFERRED_KHR, vk::Result::PIPELINE_COMPILE_REQUIRED_EXT], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS]),
+    Command::new("vkCreateRayTracingPipelinesNV", Some(Device), &[Extension("VK_NV_ray_tracing")], &[vk::Result::SUCCESS, vk::Result::PIPELINE_COMPILE_REQUIRED_EXT], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_SHADER_NV]),
+    Command::new("vkCreateRenderPass", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateRenderPass2", Some(Device), &[Core(vk::API_VERSION_1_2)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateRenderPass2KHR", Some(Device), &[Extension("VK_KHR_create_renderpass2")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateSampler", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_OPAQUE_CAPTURE_ADDRESS_KHR]),
+    Command::new("vkCreateSamplerYcbcrConversion", Some(Device), &[Core(vk::API_VERSION_1_1)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateSamplerYcbcrConversionKHR", Some(Device), &[Extension("VK_KHR_sampler_ycbcr_conversion")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateScreenSurfaceQNX", Some(Instance), &[Extension("VK_QNX_screen_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateSemaphore", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateShaderModule", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_SHADER_NV]),
+    Command::new("vkCreateShadersEXT", Some(Device), &[Extension("VK_EXT_shader_object")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_INCOMPATIBLE_SHADER_BINARY_EXT]),
+    Command::new("vkCreateSharedSwapchainsKHR", Some(Device), &[Extension("VK_KHR_display_swapchain")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INCOMPATIBLE_DISPLAY_KHR, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkCreateStreamDescriptorSurfaceGGP", Some(Instance), &[Extension("VK_GGP_stream_descriptor_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_NATIVE_WINDOW_IN_USE_KHR]),
+    Command::new("vkCreateSwapchainKHR", Some(Device), &[Extension("VK_KHR_swapchain")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_SURFACE_LOST_KHR, vk::Result::ERROR_NATIVE_WINDOW_IN_USE_KHR, vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_COMPRESSION_EXHAUSTED_EXT]),
+    Command::new("vkCreateValidationCacheEXT", Some(Device), &[Extension("VK_EXT_validation_cache")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkCreateViSurfaceNN", Some(Instance), &[Extension("VK_NN_vi_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_NATIVE_WINDOW_IN_USE_KHR]),
+    Command::new("vkCreateVideoSessionKHR", Some(Device), &[Extension("VK_KHR_video_queue")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_VIDEO_STD_VERSION_NOT_SUPPORTED_KHR, vk::Result::ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR]),
+    Command::new("vkCreateVideoSessionParametersKHR", Some(Device), &[Extension("VK_KHR_video_queue")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR]),
+    Command::new("vkCreateWaylandSurfaceKHR", Some(Instance), &[Extension("VK_KHR_wayland_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateWin32SurfaceKHR", Some(Instance), &[Extension("VK_KHR_win32_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateXcbSurfaceKHR", Some(Instance), &[Extension("VK_KHR_xcb_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkCreateXlibSurfaceKHR", Some(Instance), &[Extension("VK_KHR_xlib_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkDebugMarkerSetObjectNameEXT", Some(Device), &[Extension("VK_EXT_debug_marker")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkDebugMarkerSetObjectTagEXT", Some(Device), &[Extension("VK_EXT_debug_marker")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkDebugReportMessageEXT", Some(Instance), &[Extension("VK_EXT_debug_report")], &[], &[]),
+    Command::new("vkDeferredOperationJoinKHR", Some(Device), &[Extension("VK_KHR_deferred_host_operations")], &[vk::Result::SUCCESS, vk::Result::THREAD_DONE_KHR, vk::Result::THREAD_IDLE_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkDestroyAccelerationStructureKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkDestroyAccelerationStructureNV", Some(Device), &[Extension("VK_NV_ray_tracing")], &[], &[]),
+    Command::new("vkDestroyBuffer", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyBufferCollectionFUCHSIA", Some(Device), &[Extension("VK_FUCHSIA_buffer_collection")], &[], &[]),
+    Command::new("vkDestroyBufferView", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyCommandPool", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyCuFunctionNVX", Some(Device), &[Extension("VK_NVX_binary_import")], &[], &[]),
+    Command::new("vkDestroyCuModuleNVX", Some(Device), &[Extension("VK_NVX_binary_import")], &[], &[]),
+    Command::new("vkDestroyDebugReportCallbackEXT", Some(Instance), &[Extension("VK_EXT_debug_report")], &[], &[]),
+    Command::new("vkDestroyDebugUtilsMessengerEXT", Some(Instance), &[Extension("VK_EXT_debug_utils")], &[], &[]),
+    Command::new("vkDestroyDeferredOperationKHR", Some(Device), &[Extension("VK_KHR_deferred_host_operations")], &[], &[]),
+    Command::new("vkDestroyDescriptorPool", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyDescriptorSetLayout", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyDescriptorUpdateTemplate", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkDestroyDescriptorUpdateTemplateKHR", Some(Device), &[Extension("VK_KHR_descriptor_update_template")], &[], &[]),
+    Command::new("vkDestroyDevice", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyEvent", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyFence", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyFramebuffer", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyImage", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyImageView", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyIndirectCommandsLayoutNV", Some(Device), &[Extension("VK_NV_device_generated_commands")], &[], &[]),
+    Command::new("vkDestroyInstance", Some(Instance), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyMicromapEXT", Some(Device), &[Extension("VK_EXT_opacity_micromap")], &[], &[]),
+    Command::new("vkDestroyOpticalFlowSessionNV", Some(Device), &[Extension("VK_NV_optical_flow")], &[], &[]),
+    Command::new("vkDestroyPipeline", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyPipelineCache", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyPipelineLayout", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyPrivateDataSlot", Some(Device), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkDestroyPrivateDataSlotEXT", Some(Device), &[Extension("VK_EXT_private_data")], &[], &[]),
+    Command::new("vkDestroyQueryPool", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyRenderPass", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroySampler", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroySamplerYcbcrConversion", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkDestroySamplerYcbcrConversionKHR", Some(Device), &[Extension("VK_KHR_sampler_ycbcr_conversion")], &[], &[]),
+    Command::new("vkDestroySemaphore", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroyShaderEXT", Some(Device), &[Extension("VK_EXT_shader_object")], &[], &[]),
+    Command::new("vkDestroyShaderModule", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkDestroySurfaceKHR", Some(Instance), &[Extension("VK_KHR_surface")], &[], &[]),
+    Command::new("vkDestroySwapchainKHR", Some(Device), &[Extension("VK_KHR_swapchain")], &[], &[]),
+    Command::new("vkDestroyValidationCacheEXT", Some(Device), &[Extension("VK_EXT_validation_cache")], &[], &[]),
+    Command::new("vkDestroyVideoSessionKHR", Some(Device), &[Extension("VK_KHR_video_queue")], &[], &[]),
+    Command::new("vkDestroyVideoSessionParametersKHR", Some(Device), &[Extension("VK_KHR_video_queue")], &[], &[]),
+    Command::new("vkDeviceWaitIdle", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkDisplayPowerControlEXT", Some(Device), &[Extension("VK_EXT_display_control")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkEndCommandBuffer", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR]),
+    Command::new("vkEnumerateDeviceExtensionProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_LAYER_NOT_PRESENT]),
+    Command::new("vkEnumerateDeviceLayerProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkEnumerateInstanceExtensionProperties", None, &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_LAYER_NOT_PRESENT]),
+    Command::new("vkEnumerateInstanceLayerProperties", None, &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkEnumerateInstanceVersion", None, &[Core(vk::API_VERSION_1_1)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkEnumeratePhysicalDeviceGroups", Some(Instance), &[Core(vk::API_VERSION_1_1)], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkEnumeratePhysicalDeviceGroupsKHR", Some(Instance), &[Extension("VK_KHR_device_group_creation")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR", Some(PhysicalDevice), &[Extension("VK_KHR_performance_query")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkEnumeratePhysicalDevices", Some(Instance), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkExportMetalObjectsEXT", Some(Device), &[Extension("VK_EXT_metal_objects")], &[], &[]),
+    Command::new("vkFlushMappedMemoryRanges", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkFreeCommandBuffers", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkFreeDescriptorSets", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[]),
+    Command::new("vkFreeMemory", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetAccelerationStructureBuildSizesKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkGetAccelerationStructureDeviceAddressKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkGetAccelerationStructureHandleNV", Some(Device), &[Extension("VK_NV_ray_tracing")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetAccelerationStructureMemoryRequirementsNV", Some(Device), &[Extension("VK_NV_ray_tracing")], &[], &[]),
+    Command::new("vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT", Some(Device), &[Extension("VK_EXT_descriptor_buffer")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetAndroidHardwareBufferPropertiesANDROID", Some(Device), &[Extension("VK_ANDROID_external_memory_android_hardware_buffer")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE_KHR]),
+    Command::new("vkGetBufferCollectionPropertiesFUCHSIA", Some(Device), &[Extension("VK_FUCHSIA_buffer_collection")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkGetBufferDeviceAddress", Some(Device), &[Core(vk::API_VERSION_1_2)], &[], &[]),
+    Command::new("vkGetBufferDeviceAddressEXT", Some(Device), &[Extension("VK_EXT_buffer_device_address")], &[], &[]),
+    Command::new("vkGetBufferDeviceAddressKHR", Some(Device), &[Extension("VK_KHR_buffer_device_address")], &[], &[]),
+    Command::new("vkGetBufferMemoryRequirements", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetBufferMemoryRequirements2", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetBufferMemoryRequirements2KHR", Some(Device), &[Extension("VK_KHR_get_memory_requirements2")], &[], &[]),
+    Command::new("vkGetBufferOpaqueCaptureAddress", Some(Device), &[Core(vk::API_VERSION_1_2)], &[], &[]),
+    Command::new("vkGetBufferOpaqueCaptureAddressKHR", Some(Device), &[Extension("VK_KHR_buffer_device_address")], &[], &[]),
+    Command::new("vkGetBufferOpaqueCaptureDescriptorDataEXT", Some(Device), &[Extension("VK_EXT_descriptor_buffer")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetCalibratedTimestampsEXT", Some(Device), &[Extension("VK_EXT_calibrated_timestamps")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetDeferredOperationMaxConcurrencyKHR", Some(Device), &[Extension("VK_KHR_deferred_host_operations")], &[], &[]),
+    Command::new("vkGetDeferredOperationResultKHR", Some(Device), &[Extension("VK_KHR_deferred_host_operations")], &[vk::Result::SUCCESS, vk::Result::NOT_READY], &[]),
+    Command::new("vkGetDescriptorEXT", Some(Device), &[Extension("VK_EXT_descriptor_buffer")], &[], &[]),
+    Command::new("vkGetDescriptorSetHostMappingVALVE", Some(Device), &[Extension("VK_VALVE_descriptor_set_host_mapping")], &[], &[]),
+    Command::new("vkGetDescriptorSetLayoutBindingOffsetEXT", Some(Device), &[Extension("VK_EXT_descriptor_buffer")], &[], &[]),
+    Command::new("vkGetDescriptorSetLayoutHostMappingInfoVALVE", Some(Device), &[Extension("VK_VALVE_descriptor_set_host_mapping")], &[], &[]),
+    Command::new("vkGetDescriptorSetLayoutSizeEXT", Some(Device), &[Extension("VK_EXT_descriptor_buffer")], &[], &[]),
+    Command::new("vkGetDescriptorSetLayoutSupport", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetDescriptorSetLayoutSupportKHR", Some(Device), &[Extension("VK_KHR_maintenance3")], &[], &[]),
+    Command::new("vkGetDeviceAccelerationStructureCompatibilityKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[], &[]),
+    Command::new("vkGetDeviceBufferMemoryRequirements", Some(Device), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkGetDeviceBufferMemoryRequirementsKHR", Some(Device), &[Extension("VK_KHR_maintenance4")], &[], &[]),
+    Command::new("vkGetDeviceFaultInfoEXT", Some(Device), &[Extension("VK_EXT_device_fault")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetDeviceGroupPeerMemoryFeatures", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetDeviceGroupPeerMemoryFeaturesKHR", Some(Device), &[Extension("VK_KHR_device_group")], &[], &[]),
+    Command::new("vkGetDeviceGroupPresentCapabilitiesKHR", Some(Device), &[Extension("VK_KHR_swapchain"), Extension("VK_KHR_device_group")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetDeviceGroupSurfacePresentModes2EXT", Some(Device), &[Extension("VK_EXT_full_screen_exclusive")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetDeviceGroupSurfacePresentModesKHR", Some(Device), &[Extension("VK_KHR_swapchain"), Extension("VK_KHR_device_group")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetDeviceImageMemoryRequirements", Some(Device), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkGetDeviceImageMemoryRequirementsKHR", Some(Device), &[Extension("VK_KHR_maintenance4")], &[], &[]),
+    Command::new("vkGetDeviceImageSparseMemoryRequirements", Some(Device), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkGetDeviceImageSparseMemoryRequirementsKHR", Some(Device), &[Extension("VK_KHR_maintenance4")], &[], &[]),
+    Command::new("vkGetDeviceMemoryCommitment", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetDeviceMemoryOpaqueCaptureAddress", Some(Device), &[Core(vk::API_VERSION_1_2)], &[], &[]),
+    Command::new("vkGetDeviceMemoryOpaqueCaptureAddressKHR", Some(Device), &[Extension("VK_KHR_buffer_device_address")], &[], &[]),
+    Command::new("vkGetDeviceMicromapCompatibilityEXT", Some(Device), &[Extension("VK_EXT_opacity_micromap")], &[], &[]),
+    Command::new("vkGetDeviceProcAddr", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetDeviceQueue", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetDeviceQueue2", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI", Some(Device), &[Extension("VK_HUAWEI_subpass_shading")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetDisplayModeProperties2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_display_properties2")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetDisplayModePropertiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_display")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetDisplayPlaneCapabilities2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_display_properties2")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetDisplayPlaneCapabilitiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_display")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetDisplayPlaneSupportedDisplaysKHR", Some(PhysicalDevice), &[Extension("VK_KHR_display")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetDrmDisplayEXT", Some(PhysicalDevice), &[Extension("VK_EXT_acquire_drm_display")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetDynamicRenderingTilePropertiesQCOM", Some(Device), &[Extension("VK_QCOM_tile_properties")], &[vk::Result::SUCCESS], &[]),
+    Command::new("vkGetEventStatus", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::EVENT_SET, vk::Result::EVENT_RESET], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkGetFenceFdKHR", Some(Device), &[Extension("VK_KHR_external_fence_fd")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetFenceStatus", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::NOT_READY], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkGetFenceWin32HandleKHR", Some(Device), &[Extension("VK_KHR_external_fence_win32")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetFramebufferTilePropertiesQCOM", Some(Device), &[Extension("VK_QCOM_tile_properties")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[]),
+    Command::new("vkGetGeneratedCommandsMemoryRequirementsNV", Some(Device), &[Extension("VK_NV_device_generated_commands")], &[], &[]),
+    Command::new("vkGetImageDrmFormatModifierPropertiesEXT", Some(Device), &[Extension("VK_EXT_image_drm_format_modifier")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetImageMemoryRequirements", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetImageMemoryRequirements2", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetImageMemoryRequirements2KHR", Some(Device), &[Extension("VK_KHR_get_memory_requirements2")], &[], &[]),
+    Command::new("vkGetImageOpaqueCaptureDescriptorDataEXT", Some(Device), &[Extension("VK_EXT_descriptor_buffer")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetImageSparseMemoryRequirements", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetImageSparseMemoryRequirements2", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetImageSparseMemoryRequirements2KHR", Some(Device), &[Extension("VK_KHR_get_memory_requirements2")], &[], &[]),
+    Command::new("vkGetImageSubresourceLayout", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetImageSubresourceLayout2EXT", Some(Device), &[Extension("VK_EXT_image_compression_control")], &[], &[]),
+    Command::new("vkGetImageViewAddressNVX", Some(Device), &[Extension("VK_NVX_image_view_handle")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_UNKNOWN]),
+    Command::new("vkGetImageViewHandleNVX", Some(Device), &[Extension("VK_NVX_image_view_handle")], &[], &[]),
+    Command::new("vkGetImageViewOpaqueCaptureDescriptorDataEXT", Some(Device), &[Extension("VK_EXT_descriptor_buffer")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetInstanceProcAddr", Some(Instance), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetMemoryAndroidHardwareBufferANDROID", Some(Device), &[Extension("VK_ANDROID_external_memory_android_hardware_buffer")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetMemoryFdKHR", Some(Device), &[Extension("VK_KHR_external_memory_fd")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetMemoryFdPropertiesKHR", Some(Device), &[Extension("VK_KHR_external_memory_fd")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkGetMemoryHostPointerPropertiesEXT", Some(Device), &[Extension("VK_EXT_external_memory_host")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkGetMemoryRemoteAddressNV", Some(Device), &[Extension("VK_NV_external_memory_rdma")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkGetMemoryWin32HandleKHR", Some(Device), &[Extension("VK_KHR_external_memory_win32")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetMemoryWin32HandleNV", Some(Device), &[Extension("VK_NV_external_memory_win32")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetMemoryWin32HandlePropertiesKHR", Some(Device), &[Extension("VK_KHR_external_memory_win32")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkGetMemoryZirconHandleFUCHSIA", Some(Device), &[Extension("VK_FUCHSIA_external_memory")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetMemoryZirconHandlePropertiesFUCHSIA", Some(Device), &[Extension("VK_FUCHSIA_external_memory")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkGetMicromapBuildSizesEXT", Some(Device), &[Extension("VK_EXT_opacity_micromap")], &[], &[]),
+    Command::new("vkGetPastPresentationTimingGOOGLE", Some(Device), &[Extension("VK_GOOGLE_display_timing")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_OUT_OF_DATE_KHR, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetPerformanceParameterINTEL", Some(Device), &[Extension("VK_INTEL_performance_query")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetPhysicalDeviceCalibrateableTimeDomainsEXT", Some(PhysicalDevice), &[Extension("VK_EXT_calibrated_timestamps")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPhysicalDeviceCooperativeMatrixPropertiesNV", Some(PhysicalDevice), &[Extension("VK_NV_cooperative_matrix")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPhysicalDeviceDirectFBPresentationSupportEXT", Some(PhysicalDevice), &[Extension("VK_EXT_directfb_surface")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceDisplayPlaneProperties2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_display_properties2")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPhysicalDeviceDisplayPlanePropertiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_display")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPhysicalDeviceDisplayProperties2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_display_properties2")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPhysicalDeviceDisplayPropertiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_display")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPhysicalDeviceExternalBufferProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceExternalBufferPropertiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_external_memory_capabilities")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceExternalFenceProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceExternalFencePropertiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_external_fence_capabilities")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceExternalImageFormatPropertiesNV", Some(PhysicalDevice), &[Extension("VK_NV_external_memory_capabilities")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_FORMAT_NOT_SUPPORTED]),
+    Command::new("vkGetPhysicalDeviceExternalSemaphoreProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceExternalSemaphorePropertiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_external_semaphore_capabilities")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceFeatures", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceFeatures2", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceFeatures2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_physical_device_properties2")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceFormatProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceFormatProperties2", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceFormatProperties2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_physical_device_properties2")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceFragmentShadingRatesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_fragment_shading_rate")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetPhysicalDeviceImageFormatProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_FORMAT_NOT_SUPPORTED]),
+    Command::new("vkGetPhysicalDeviceImageFormatProperties2", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_FORMAT_NOT_SUPPORTED, vk::Result::ERROR_IMAGE_USAGE_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR]),
+    Command::new("vkGetPhysicalDeviceImageFormatProperties2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_physical_device_properties2")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_FORMAT_NOT_SUPPORTED, vk::Result::ERROR_IMAGE_USAGE_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR]),
+    Command::new("vkGetPhysicalDeviceMemoryProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceMemoryProperties2", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceMemoryProperties2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_physical_device_properties2")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceMultisamplePropertiesEXT", Some(PhysicalDevice), &[Extension("VK_EXT_sample_locations")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceOpticalFlowImageFormatsNV", Some(PhysicalDevice), &[Extension("VK_NV_optical_flow")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_EXTENSION_NOT_PRESENT, vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_FORMAT_NOT_SUPPORTED]),
+    Command::new("vkGetPhysicalDevicePresentRectanglesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_swapchain"), Extension("VK_KHR_device_group")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPhysicalDeviceProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceProperties2", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceProperties2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_physical_device_properties2")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_performance_query")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceQueueFamilyProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceQueueFamilyProperties2", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceQueueFamilyProperties2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_physical_device_properties2")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceScreenPresentationSupportQNX", Some(PhysicalDevice), &[Extension("VK_QNX_screen_surface")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceSparseImageFormatProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceSparseImageFormatProperties2", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkGetPhysicalDeviceSparseImageFormatProperties2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_physical_device_properties2")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV", Some(PhysicalDevice), &[Extension("VK_NV_coverage_reduction_mode")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPhysicalDeviceSurfaceCapabilities2EXT", Some(PhysicalDevice), &[Extension("VK_EXT_display_surface_counter")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetPhysicalDeviceSurfaceCapabilities2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_surface_capabilities2")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetPhysicalDeviceSurfaceCapabilitiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetPhysicalDeviceSurfaceFormats2KHR", Some(PhysicalDevice), &[Extension("VK_KHR_get_surface_capabilities2")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetPhysicalDeviceSurfaceFormatsKHR", Some(PhysicalDevice), &[Extension("VK_KHR_surface")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetPhysicalDeviceSurfacePresentModes2EXT", Some(PhysicalDevice), &[Extension("VK_EXT_full_screen_exclusive")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetPhysicalDeviceSurfacePresentModesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_surface")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetPhysicalDeviceSurfaceSupportKHR", Some(PhysicalDevice), &[Extension("VK_KHR_surface")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetPhysicalDeviceToolProperties", Some(PhysicalDevice), &[Core(vk::API_VERSION_1_3)], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetPhysicalDeviceToolPropertiesEXT", Some(PhysicalDevice), &[Extension("VK_EXT_tooling_info")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetPhysicalDeviceVideoCapabilitiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_video_queue")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR]),
+    Command::new("vkGetPhysicalDeviceVideoFormatPropertiesKHR", Some(PhysicalDevice), &[Extension("VK_KHR_video_queue")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_IMAGE_USAGE_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_OPERATION_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_FORMAT_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PICTURE_LAYOUT_NOT_SUPPORTED_KHR, vk::Result::ERROR_VIDEO_PROFILE_CODEC_NOT_SUPPORTED_KHR]),
+    Command::new("vkGetPhysicalDeviceWaylandPresentationSupportKHR", Some(PhysicalDevice), &[Extension("VK_KHR_wayland_surface")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceWin32PresentationSupportKHR", Some(PhysicalDevice), &[Extension("VK_KHR_win32_surface")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceXcbPresentationSupportKHR", Some(PhysicalDevice), &[Extension("VK_KHR_xcb_surface")], &[], &[]),
+    Command::new("vkGetPhysicalDeviceXlibPresentationSupportKHR", Some(PhysicalDevice), &[Extension("VK_KHR_xlib_surface")], &[], &[]),
+    Command::new("vkGetPipelineCacheData", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPipelineExecutableInternalRepresentationsKHR", Some(Device), &[Extension("VK_KHR_pipeline_executable_properties")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPipelineExecutablePropertiesKHR", Some(Device), &[Extension("VK_KHR_pipeline_executable_properties")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPipelineExecutableStatisticsKHR", Some(Device), &[Extension("VK_KHR_pipeline_executable_properties")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetPipelinePropertiesEXT", Some(Device), &[Extension("VK_EXT_pipeline_properties")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetPrivateData", Some(Device), &[Core(vk::API_VERSION_1_3)], &[], &[]),
+    Command::new("vkGetPrivateDataEXT", Some(Device), &[Extension("VK_EXT_private_data")], &[], &[]),
+    Command::new("vkGetQueryPoolResults", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::NOT_READY], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkGetQueueCheckpointData2NV", Some(Queue), &[Extension("VK_KHR_synchronization2")], &[], &[]),
+    Command::new("vkGetQueueCheckpointDataNV", Some(Queue), &[Extension("VK_NV_device_diagnostic_checkpoints")], &[], &[]),
+    Command::new("vkGetRandROutputDisplayEXT", Some(PhysicalDevice), &[Extension("VK_EXT_acquire_xlib_display")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetRayTracingCaptureReplayShaderGroupHandlesKHR", Some(Device), &[Extension("VK_KHR_ray_tracing_pipeline")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetRayTracingShaderGroupHandlesKHR", Some(Device), &[Extension("VK_KHR_ray_tracing_pipeline")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetRayTracingShaderGroupHandlesNV", Some(Device), &[Extension("VK_NV_ray_tracing")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetRayTracingShaderGroupStackSizeKHR", Some(Device), &[Extension("VK_KHR_ray_tracing_pipeline")], &[], &[]),
+    Command::new("vkGetRefreshCycleDurationGOOGLE", Some(Device), &[Extension("VK_GOOGLE_display_timing")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkGetRenderAreaGranularity", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkGetSamplerOpaqueCaptureDescriptorDataEXT", Some(Device), &[Extension("VK_EXT_descriptor_buffer")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetSemaphoreCounterValue", Some(Device), &[Core(vk::API_VERSION_1_2)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkGetSemaphoreCounterValueKHR", Some(Device), &[Extension("VK_KHR_timeline_semaphore")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkGetSemaphoreFdKHR", Some(Device), &[Extension("VK_KHR_external_semaphore_fd")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetSemaphoreWin32HandleKHR", Some(Device), &[Extension("VK_KHR_external_semaphore_win32")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetSemaphoreZirconHandleFUCHSIA", Some(Device), &[Extension("VK_FUCHSIA_external_semaphore")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetShaderBinaryDataEXT", Some(Device), &[Extension("VK_EXT_shader_object")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetShaderInfoAMD", Some(Device), &[Extension("VK_AMD_shader_info")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_FEATURE_NOT_PRESENT, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkGetShaderModuleCreateInfoIdentifierEXT", Some(Device), &[Extension("VK_EXT_shader_module_identifier")], &[], &[]),
+    Command::new("vkGetShaderModuleIdentifierEXT", Some(Device), &[Extension("VK_EXT_shader_module_identifier")], &[], &[]),
+    Command::new("vkGetSwapchainCounterEXT", Some(Device), &[Extension("VK_EXT_display_control")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_OUT_OF_DATE_KHR]),
+    Command::new("vkGetSwapchainImagesKHR", Some(Device), &[Extension("VK_KHR_swapchain")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetSwapchainStatusKHR", Some(Device), &[Extension("VK_KHR_shared_presentable_image")], &[vk::Result::SUCCESS, vk::Result::SUBOPTIMAL_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_OUT_OF_DATE_KHR, vk::Result::ERROR_SURFACE_LOST_KHR, vk::Result::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT]),
+    Command::new("vkGetValidationCacheDataEXT", Some(Device), &[Extension("VK_EXT_validation_cache")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkGetVideoSessionMemoryRequirementsKHR", Some(Device), &[Extension("VK_KHR_video_queue")], &[vk::Result::SUCCESS, vk::Result::INCOMPLETE], &[]),
+    Command::new("vkGetWinrtDisplayNV", Some(PhysicalDevice), &[Extension("VK_NV_acquire_winrt_display")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_INITIALIZATION_FAILED]),
+    Command::new("vkImportFenceFdKHR", Some(Device), &[Extension("VK_KHR_external_fence_fd")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkImportFenceWin32HandleKHR", Some(Device), &[Extension("VK_KHR_external_fence_win32")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkImportSemaphoreFdKHR", Some(Device), &[Extension("VK_KHR_external_semaphore_fd")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkImportSemaphoreWin32HandleKHR", Some(Device), &[Extension("VK_KHR_external_semaphore_win32")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkImportSemaphoreZirconHandleFUCHSIA", Some(Device), &[Extension("VK_FUCHSIA_external_semaphore")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_INVALID_EXTERNAL_HANDLE]),
+    Command::new("vkInitializePerformanceApiINTEL", Some(Device), &[Extension("VK_INTEL_performance_query")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkInvalidateMappedMemoryRanges", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkMapMemory", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_MEMORY_MAP_FAILED]),
+    Command::new("vkMapMemory2KHR", Some(Device), &[Extension("VK_KHR_map_memory2")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_MEMORY_MAP_FAILED]),
+    Command::new("vkMergePipelineCaches", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkMergeValidationCachesEXT", Some(Device), &[Extension("VK_EXT_validation_cache")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkQueueBeginDebugUtilsLabelEXT", Some(Queue), &[Extension("VK_EXT_debug_utils")], &[], &[]),
+    Command::new("vkQueueBindSparse", Some(Queue), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkQueueEndDebugUtilsLabelEXT", Some(Queue), &[Extension("VK_EXT_debug_utils")], &[], &[]),
+    Command::new("vkQueueInsertDebugUtilsLabelEXT", Some(Queue), &[Extension("VK_EXT_debug_utils")], &[], &[]),
+    Command::new("vkQueuePresentKHR", Some(Queue), &[Extension("VK_KHR_swapchain")], &[vk::Result::SUCCESS, vk::Result::SUBOPTIMAL_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_OUT_OF_DATE_KHR, vk::Result::ERROR_SURFACE_LOST_KHR, vk::Result::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT]),
+    Command::new("vkQueueSetPerformanceConfigurationINTEL", Some(Queue), &[Extension("VK_INTEL_performance_query")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkQueueSubmit", Some(Queue), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkQueueSubmit2", Some(Queue), &[Core(vk::API_VERSION_1_3)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkQueueSubmit2KHR", Some(Queue), &[Extension("VK_KHR_synchronization2")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkQueueWaitIdle", Some(Queue), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkRegisterDeviceEventEXT", Some(Device), &[Extension("VK_EXT_display_control")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkRegisterDisplayEventEXT", Some(Device), &[Extension("VK_EXT_display_control")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkReleaseDisplayEXT", Some(PhysicalDevice), &[Extension("VK_EXT_direct_mode_display")], &[vk::Result::SUCCESS], &[]),
+    Command::new("vkReleaseFullScreenExclusiveModeEXT", Some(Device), &[Extension("VK_EXT_full_screen_exclusive")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkReleasePerformanceConfigurationINTEL", Some(Device), &[Extension("VK_INTEL_performance_query")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_TOO_MANY_OBJECTS, vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkReleaseProfilingLockKHR", Some(Device), &[Extension("VK_KHR_performance_query")], &[], &[]),
+    Command::new("vkReleaseSwapchainImagesEXT", Some(Device), &[Extension("VK_EXT_swapchain_maintenance1")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_SURFACE_LOST_KHR]),
+    Command::new("vkResetCommandBuffer", Some(CommandBuffer), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkResetCommandPool", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkResetDescriptorPool", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[]),
+    Command::new("vkResetEvent", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkResetFences", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkResetQueryPool", Some(Device), &[Core(vk::API_VERSION_1_2)], &[], &[]),
+    Command::new("vkResetQueryPoolEXT", Some(Device), &[Extension("VK_EXT_host_query_reset")], &[], &[]),
+    Command::new("vkSetBufferCollectionBufferConstraintsFUCHSIA", Some(Device), &[Extension("VK_FUCHSIA_buffer_collection")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_FORMAT_NOT_SUPPORTED]),
+    Command::new("vkSetBufferCollectionImageConstraintsFUCHSIA", Some(Device), &[Extension("VK_FUCHSIA_buffer_collection")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_INITIALIZATION_FAILED, vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_FORMAT_NOT_SUPPORTED]),
+    Command::new("vkSetDebugUtilsObjectNameEXT", Some(Device), &[Extension("VK_EXT_debug_utils")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkSetDebugUtilsObjectTagEXT", Some(Device), &[Extension("VK_EXT_debug_utils")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkSetDeviceMemoryPriorityEXT", Some(Device), &[Extension("VK_EXT_pageable_device_local_memory")], &[], &[]),
+    Command::new("vkSetEvent", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkSetHdrMetadataEXT", Some(Device), &[Extension("VK_EXT_hdr_metadata")], &[], &[]),
+    Command::new("vkSetLocalDimmingAMD", Some(Device), &[Extension("VK_AMD_display_native_hdr")], &[], &[]),
+    Command::new("vkSetPrivateData", Some(Device), &[Core(vk::API_VERSION_1_3)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkSetPrivateDataEXT", Some(Device), &[Extension("VK_EXT_private_data")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY]),
+    Command::new("vkSignalSemaphore", Some(Device), &[Core(vk::API_VERSION_1_2)], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkSignalSemaphoreKHR", Some(Device), &[Extension("VK_KHR_timeline_semaphore")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkSubmitDebugUtilsMessageEXT", Some(Instance), &[Extension("VK_EXT_debug_utils")], &[], &[]),
+    Command::new("vkTrimCommandPool", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkTrimCommandPoolKHR", Some(Device), &[Extension("VK_KHR_maintenance1")], &[], &[]),
+    Command::new("vkUninitializePerformanceApiINTEL", Some(Device), &[Extension("VK_INTEL_performance_query")], &[], &[]),
+    Command::new("vkUnmapMemory", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkUnmapMemory2KHR", Some(Device), &[Extension("VK_KHR_map_memory2")], &[vk::Result::SUCCESS], &[]),
+    Command::new("vkUpdateDescriptorSetWithTemplate", Some(Device), &[Core(vk::API_VERSION_1_1)], &[], &[]),
+    Command::new("vkUpdateDescriptorSetWithTemplateKHR", Some(Device), &[Extension("VK_KHR_descriptor_update_template")], &[], &[]),
+    Command::new("vkUpdateDescriptorSets", Some(Device), &[Core(vk::API_VERSION_1_0)], &[], &[]),
+    Command::new("vkUpdateVideoSessionParametersKHR", Some(Device), &[Extension("VK_KHR_video_queue")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_INVALID_VIDEO_STD_PARAMETERS_KHR]),
+    Command::new("vkWaitForFences", Some(Device), &[Core(vk::API_VERSION_1_0)], &[vk::Result::SUCCESS, vk::Result::TIMEOUT], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkWaitForPresentKHR", Some(Device), &[Extension("VK_KHR_present_wait")], &[vk::Result::SUCCESS, vk::Result::TIMEOUT, vk::Result::SUBOPTIMAL_KHR], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST, vk::Result::ERROR_OUT_OF_DATE_KHR, vk::Result::ERROR_SURFACE_LOST_KHR, vk::Result::ERROR_FULL_SCREEN_EXCLUSIVE_MODE_LOST_EXT]),
+    Command::new("vkWaitSemaphores", Some(Device), &[Core(vk::API_VERSION_1_2)], &[vk::Result::SUCCESS, vk::Result::TIMEOUT], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkWaitSemaphoresKHR", Some(Device), &[Extension("VK_KHR_timeline_semaphore")], &[vk::Result::SUCCESS, vk::Result::TIMEOUT], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY, vk::Result::ERROR_DEVICE_LOST]),
+    Command::new("vkWriteAccelerationStructuresPropertiesKHR", Some(Device), &[Extension("VK_KHR_acceleration_structure")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+    Command::new("vkWriteMicromapsPropertiesEXT", Some(Device), &[Extension("VK_EXT_opacity_micromap")], &[vk::Result::SUCCESS], &[vk::Result::ERROR_OUT_OF_HOST_MEMORY, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY]),
+];
+
+pub(super) const INSTANCE_EXTENSIONS: &[&str] = &[
+    "VK_EXT_acquire_drm_display",
+    "VK_EXT_acquire_xlib_display",
+    "VK_EXT_debug_report",
+    "VK_EXT_debug_utils",
+    "VK_EXT_direct_mode_display",
+    "VK_EXT_directfb_surface",
+    "VK_EXT_display_surface_counter",
+    "VK_EXT_headless_surface",
+    "VK_EXT_metal_surface",
+    "VK_EXT_surface_maintenance1",
+    "VK_EXT_swapchain_colorspace",
+    "VK_EXT_validation_features",
+    "VK_EXT_validation_flags",
+    "VK_FUCHSIA_imagepipe_surface",
+    "VK_GGP_stream_descriptor_surface",
+    "VK_GOOGLE_surfaceless_query",
+    "VK_KHR_android_surface",
+    "VK_KHR_device_group_creation",
+    "VK_KHR_display",
+    "VK_KHR_external_fence_capabilities",
+    "VK_KHR_external_memory_capabilities",
+    "VK_KHR_external_semaphore_capabilities",
+    "VK_KHR_get_display_properties2",
+    "VK_KHR_get_physical_device_properties2",
+    "VK_KHR_get_surface_capabilities2",
+    "VK_KHR_portability_enumeration",
+    "VK_KHR_surface",
+    "VK_KHR_surface_protected_capabilities",
+    "VK_KHR_wayland_surface",
+    "VK_KHR_win32_surface",
+    "VK_KHR_xcb_surface",
+    "VK_KHR_xlib_surface",
+    "VK_LUNARG_direct_driver_loading",
+    "VK_MVK_ios_surface",
+    "VK_MVK_macos_surface",
+    "VK_NN_vi_surface",
+    "VK_NV_external_memory_capabilities",
+    "VK_QNX_screen_surface",
+];
+
+/// Typed hook of every command, checking the signature of its function.
+pub mod hooks {
+    #![allow(non_snake_case)]
+
+    use crate::{CommandLevel::*, Hook};
+
+    use core::mem;
+
+    use ash::vk;
+
+    pub const fn vkAcquireDrmDisplayEXT(pfn: vk::PFN_vkAcquireDrmDisplayEXT) -> Hook { Hook::new(PhysicalDevice, "vkAcquireDrmDisplayEXT", unsafe { mem::transmute::<vk::PFN_vkAcquireDrmDisplayEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAcquireFullScreenExclusiveModeEXT(pfn: vk::PFN_vkAcquireFullScreenExclusiveModeEXT) -> Hook { Hook::new(Device, "vkAcquireFullScreenExclusiveModeEXT", unsafe { mem::transmute::<vk::PFN_vkAcquireFullScreenExclusiveModeEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAcquireNextImage2KHR(pfn: vk::PFN_vkAcquireNextImage2KHR) -> Hook { Hook::new(Device, "vkAcquireNextImage2KHR", unsafe { mem::transmute::<vk::PFN_vkAcquireNextImage2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAcquireNextImageKHR(pfn: vk::PFN_vkAcquireNextImageKHR) -> Hook { Hook::new(Device, "vkAcquireNextImageKHR", unsafe { mem::transmute::<vk::PFN_vkAcquireNextImageKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAcquirePerformanceConfigurationINTEL(pfn: vk::PFN_vkAcquirePerformanceConfigurationINTEL) -> Hook { Hook::new(Device, "vkAcquirePerformanceConfigurationINTEL", unsafe { mem::transmute::<vk::PFN_vkAcquirePerformanceConfigurationINTEL, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAcquireProfilingLockKHR(pfn: vk::PFN_vkAcquireProfilingLockKHR) -> Hook { Hook::new(Device, "vkAcquireProfilingLockKHR", unsafe { mem::transmute::<vk::PFN_vkAcquireProfilingLockKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAcquireWinrtDisplayNV(pfn: vk::PFN_vkAcquireWinrtDisplayNV) -> Hook { Hook::new(PhysicalDevice, "vkAcquireWinrtDisplayNV", unsafe { mem::transmute::<vk::PFN_vkAcquireWinrtDisplayNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAcquireXlibDisplayEXT(pfn: vk::PFN_vkAcquireXlibDisplayEXT) -> Hook { Hook::new(PhysicalDevice, "vkAcquireXlibDisplayEXT", unsafe { mem::transmute::<vk::PFN_vkAcquireXlibDisplayEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAllocateCommandBuffers(pfn: vk::PFN_vkAllocateCommandBuffers) -> Hook { Hook::new(Device, "vkAllocateCommandBuffers", unsafe { mem::transmute::<vk::PFN_vkAllocateCommandBuffers, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAllocateDescriptorSets(pfn: vk::PFN_vkAllocateDescriptorSets) -> Hook { Hook::new(Device, "vkAllocateDescriptorSets", unsafe { mem::transmute::<vk::PFN_vkAllocateDescriptorSets, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkAllocateMemory(pfn: vk::PFN_vkAllocateMemory) -> Hook { Hook::new(Device, "vkAllocateMemory", unsafe { mem::transmute::<vk::PFN_vkAllocateMemory, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBeginCommandBuffer(pfn: vk::PFN_vkBeginCommandBuffer) -> Hook { Hook::new(Device, "vkBeginCommandBuffer", unsafe { mem::transmute::<vk::PFN_vkBeginCommandBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBindAccelerationStructureMemoryNV(pfn: vk::PFN_vkBindAccelerationStructureMemoryNV) -> Hook { Hook::new(Device, "vkBindAccelerationStructureMemoryNV", unsafe { mem::transmute::<vk::PFN_vkBindAccelerationStructureMemoryNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBindBufferMemory(pfn: vk::PFN_vkBindBufferMemory) -> Hook { Hook::new(Device, "vkBindBufferMemory", unsafe { mem::transmute::<vk::PFN_vkBindBufferMemory, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBindBufferMemory2(pfn: vk::PFN_vkBindBufferMemory2) -> Hook { Hook::new(Device, "vkBindBufferMemory2", unsafe { mem::transmute::<vk::PFN_vkBindBufferMemory2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBindBufferMemory2KHR(pfn: vk::PFN_vkBindBufferMemory2) -> Hook { Hook::new(Device, "vkBindBufferMemory2KHR", unsafe { mem::transmute::<vk::PFN_vkBindBufferMemory2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBindImageMemory(pfn: vk::PFN_vkBindImageMemory) -> Hook { Hook::new(Device, "vkBindImageMemory", unsafe { mem::transmute::<vk::PFN_vkBindImageMemory, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBindImageMemory2(pfn: vk::PFN_vkBindImageMemory2) -> Hook { Hook::new(Device, "vkBindImageMemory2", unsafe { mem::transmute::<vk::PFN_vkBindImageMemory2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBindImageMemory2KHR(pfn: vk::PFN_vkBindImageMemory2) -> Hook { Hook::new(Device, "vkBindImageMemory2KHR", unsafe { mem::transmute::<vk::PFN_vkBindImageMemory2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBindOpticalFlowSessionImageNV(pfn: vk::PFN_vkBindOpticalFlowSessionImageNV) -> Hook { Hook::new(Device, "vkBindOpticalFlowSessionImageNV", unsafe { mem::transmute::<vk::PFN_vkBindOpticalFlowSessionImageNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBindVideoSessionMemoryKHR(pfn: vk::PFN_vkBindVideoSessionMemoryKHR) -> Hook { Hook::new(Device, "vkBindVideoSessionMemoryKHR", unsafe { mem::transmute::<vk::PFN_vkBindVideoSessionMemoryKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBuildAccelerationStructuresKHR(pfn: vk::PFN_vkBuildAccelerationStructuresKHR) -> Hook { Hook::new(Device, "vkBuildAccelerationStructuresKHR", unsafe { mem::transmute::<vk::PFN_vkBuildAccelerationStructuresKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkBuildMicromapsEXT(pfn: vk::PFN_vkBuildMicromapsEXT) -> Hook { Hook::new(Device, "vkBuildMicromapsEXT", unsafe { mem::transmute::<vk::PFN_vkBuildMicromapsEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginConditionalRenderingEXT(pfn: vk::PFN_vkCmdBeginConditionalRenderingEXT) -> Hook { Hook::new(Device, "vkCmdBeginConditionalRenderingEXT", unsafe { mem::transmute::<vk::PFN_vkCmdBeginConditionalRenderingEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginDebugUtilsLabelEXT(pfn: vk::PFN_vkCmdBeginDebugUtilsLabelEXT) -> Hook { Hook::new(Device, "vkCmdBeginDebugUtilsLabelEXT", unsafe { mem::transmute::<vk::PFN_vkCmdBeginDebugUtilsLabelEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginQuery(pfn: vk::PFN_vkCmdBeginQuery) -> Hook { Hook::new(Device, "vkCmdBeginQuery", unsafe { mem::transmute::<vk::PFN_vkCmdBeginQuery, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginQueryIndexedEXT(pfn: vk::PFN_vkCmdBeginQueryIndexedEXT) -> Hook { Hook::new(Device, "vkCmdBeginQueryIndexedEXT", unsafe { mem::transmute::<vk::PFN_vkCmdBeginQueryIndexedEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginRenderPass(pfn: vk::PFN_vkCmdBeginRenderPass) -> Hook { Hook::new(Device, "vkCmdBeginRenderPass", unsafe { mem::transmute::<vk::PFN_vkCmdBeginRenderPass, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginRenderPass2(pfn: vk::PFN_vkCmdBeginRenderPass2) -> Hook { Hook::new(Device, "vkCmdBeginRenderPass2", unsafe { mem::transmute::<vk::PFN_vkCmdBeginRenderPass2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginRenderPass2KHR(pfn: vk::PFN_vkCmdBeginRenderPass2) -> Hook { Hook::new(Device, "vkCmdBeginRenderPass2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdBeginRenderPass2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginRendering(pfn: vk::PFN_vkCmdBeginRendering) -> Hook { Hook::new(Device, "vkCmdBeginRendering", unsafe { mem::transmute::<vk::PFN_vkCmdBeginRendering, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginRenderingKHR(pfn: vk::PFN_vkCmdBeginRendering) -> Hook { Hook::new(Device, "vkCmdBeginRenderingKHR", unsafe { mem::transmute::<vk::PFN_vkCmdBeginRendering, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginTransformFeedbackEXT(pfn: vk::PFN_vkCmdBeginTransformFeedbackEXT) -> Hook { Hook::new(Device, "vkCmdBeginTransformFeedbackEXT", unsafe { mem::transmute::<vk::PFN_vkCmdBeginTransformFeedbackEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBeginVideoCodingKHR(pfn: vk::PFN_vkCmdBeginVideoCodingKHR) -> Hook { Hook::new(Device, "vkCmdBeginVideoCodingKHR", unsafe { mem::transmute::<vk::PFN_vkCmdBeginVideoCodingKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindDescriptorBufferEmbeddedSamplersEXT(pfn: vk::PFN_vkCmdBindDescriptorBufferEmbeddedSamplersEXT) -> Hook { Hook::new(Device, "vkCmdBindDescriptorBufferEmbeddedSamplersEXT", unsafe { mem::transmute::<vk::PFN_vkCmdBindDescriptorBufferEmbeddedSamplersEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindDescriptorBuffersEXT(pfn: vk::PFN_vkCmdBindDescriptorBuffersEXT) -> Hook { Hook::new(Device, "vkCmdBindDescriptorBuffersEXT", unsafe { mem::transmute::<vk::PFN_vkCmdBindDescriptorBuffersEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindDescriptorSets(pfn: vk::PFN_vkCmdBindDescriptorSets) -> Hook { Hook::new(Device, "vkCmdBindDescriptorSets", unsafe { mem::transmute::<vk::PFN_vkCmdBindDescriptorSets, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindIndexBuffer(pfn: vk::PFN_vkCmdBindIndexBuffer) -> Hook { Hook::new(Device, "vkCmdBindIndexBuffer", unsafe { mem::transmute::<vk::PFN_vkCmdBindIndexBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindInvocationMaskHUAWEI(pfn: vk::PFN_vkCmdBindInvocationMaskHUAWEI) -> Hook { Hook::new(Device, "vkCmdBindInvocationMaskHUAWEI", unsafe { mem::transmute::<vk::PFN_vkCmdBindInvocationMaskHUAWEI, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindPipeline(pfn: vk::PFN_vkCmdBindPipeline) -> Hook { Hook::new(Device, "vkCmdBindPipeline", unsafe { mem::transmute::<vk::PFN_vkCmdBindPipeline, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindPipelineShaderGroupNV(pfn: vk::PFN_vkCmdBindPipelineShaderGroupNV) -> Hook { Hook::new(Device, "vkCmdBindPipelineShaderGroupNV", unsafe { mem::transmute::<vk::PFN_vkCmdBindPipelineShaderGroupNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindShadersEXT(pfn: vk::PFN_vkCmdBindShadersEXT) -> Hook { Hook::new(Device, "vkCmdBindShadersEXT", unsafe { mem::transmute::<vk::PFN_vkCmdBindShadersEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindShadingRateImageNV(pfn: vk::PFN_vkCmdBindShadingRateImageNV) -> Hook { Hook::new(Device, "vkCmdBindShadingRateImageNV", unsafe { mem::transmute::<vk::PFN_vkCmdBindShadingRateImageNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindTransformFeedbackBuffersEXT(pfn: vk::PFN_vkCmdBindTransformFeedbackBuffersEXT) -> Hook { Hook::new(Device, "vkCmdBindTransformFeedbackBuffersEXT", unsafe { mem::transmute::<vk::PFN_vkCmdBindTransformFeedbackBuffersEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindVertexBuffers(pfn: vk::PFN_vkCmdBindVertexBuffers) -> Hook { Hook::new(Device, "vkCmdBindVertexBuffers", unsafe { mem::transmute::<vk::PFN_vkCmdBindVertexBuffers, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindVertexBuffers2(pfn: vk::PFN_vkCmdBindVertexBuffers2) -> Hook { Hook::new(Device, "vkCmdBindVertexBuffers2", unsafe { mem::transmute::<vk::PFN_vkCmdBindVertexBuffers2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBindVertexBuffers2EXT(pfn: vk::PFN_vkCmdBindVertexBuffers2) -> Hook { Hook::new(Device, "vkCmdBindVertexBuffers2EXT", unsafe { mem::transmute::<vk::PFN_vkCmdBindVertexBuffers2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBlitImage(pfn: vk::PFN_vkCmdBlitImage) -> Hook { Hook::new(Device, "vkCmdBlitImage", unsafe { mem::transmute::<vk::PFN_vkCmdBlitImage, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBlitImage2(pfn: vk::PFN_vkCmdBlitImage2) -> Hook { Hook::new(Device, "vkCmdBlitImage2", unsafe { mem::transmute::<vk::PFN_vkCmdBlitImage2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBlitImage2KHR(pfn: vk::PFN_vkCmdBlitImage2) -> Hook { Hook::new(Device, "vkCmdBlitImage2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdBlitImage2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBuildAccelerationStructureNV(pfn: vk::PFN_vkCmdBuildAccelerationStructureNV) -> Hook { Hook::new(Device, "vkCmdBuildAccelerationStructureNV", unsafe { mem::transmute::<vk::PFN_vkCmdBuildAccelerationStructureNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBuildAccelerationStructuresIndirectKHR(pfn: vk::PFN_vkCmdBuildAccelerationStructuresIndirectKHR) -> Hook { Hook::new(Device, "vkCmdBuildAccelerationStructuresIndirectKHR", unsafe { mem::transmute::<vk::PFN_vkCmdBuildAccelerationStructuresIndirectKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBuildAccelerationStructuresKHR(pfn: vk::PFN_vkCmdBuildAccelerationStructuresKHR) -> Hook { Hook::new(Device, "vkCmdBuildAccelerationStructuresKHR", unsafe { mem::transmute::<vk::PFN_vkCmdBuildAccelerationStructuresKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdBuildMicromapsEXT(pfn: vk::PFN_vkCmdBuildMicromapsEXT) -> Hook { Hook::new(Device, "vkCmdBuildMicromapsEXT", unsafe { mem::transmute::<vk::PFN_vkCmdBuildMicromapsEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdClearAttachments(pfn: vk::PFN_vkCmdClearAttachments) -> Hook { Hook::new(Device, "vkCmdClearAttachments", unsafe { mem::transmute::<vk::PFN_vkCmdClearAttachments, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdClearColorImage(pfn: vk::PFN_vkCmdClearColorImage) -> Hook { Hook::new(Device, "vkCmdClearColorImage", unsafe { mem::transmute::<vk::PFN_vkCmdClearColorImage, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdClearDepthStencilImage(pfn: vk::PFN_vkCmdClearDepthStencilImage) -> Hook { Hook::new(Device, "vkCmdClearDepthStencilImage", unsafe { mem::transmute::<vk::PFN_vkCmdClearDepthStencilImage, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdControlVideoCodingKHR(pfn: vk::PFN_vkCmdControlVideoCodingKHR) -> Hook { Hook::new(Device, "vkCmdControlVideoCodingKHR", unsafe { mem::transmute::<vk::PFN_vkCmdControlVideoCodingKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyAccelerationStructureKHR(pfn: vk::PFN_vkCmdCopyAccelerationStructureKHR) -> Hook { Hook::new(Device, "vkCmdCopyAccelerationStructureKHR", unsafe { mem::transmute::<vk::PFN_vkCmdCopyAccelerationStructureKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyAccelerationStructureNV(pfn: vk::PFN_vkCmdCopyAccelerationStructureNV) -> Hook { Hook::new(Device, "vkCmdCopyAccelerationStructureNV", unsafe { mem::transmute::<vk::PFN_vkCmdCopyAccelerationStructureNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyAccelerationStructureToMemoryKHR(pfn: vk::PFN_vkCmdCopyAccelerationStructureToMemoryKHR) -> Hook { Hook::new(Device, "vkCmdCopyAccelerationStructureToMemoryKHR", unsafe { mem::transmute::<vk::PFN_vkCmdCopyAccelerationStructureToMemoryKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyBuffer(pfn: vk::PFN_vkCmdCopyBuffer) -> Hook { Hook::new(Device, "vkCmdCopyBuffer", unsafe { mem::transmute::<vk::PFN_vkCmdCopyBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyBuffer2(pfn: vk::PFN_vkCmdCopyBuffer2) -> Hook { Hook::new(Device, "vkCmdCopyBuffer2", unsafe { mem::transmute::<vk::PFN_vkCmdCopyBuffer2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyBuffer2KHR(pfn: vk::PFN_vkCmdCopyBuffer2) -> Hook { Hook::new(Device, "vkCmdCopyBuffer2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdCopyBuffer2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyBufferToImage(pfn: vk::PFN_vkCmdCopyBufferToImage) -> Hook { Hook::new(Device, "vkCmdCopyBufferToImage", unsafe { mem::transmute::<vk::PFN_vkCmdCopyBufferToImage, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyBufferToImage2(pfn: vk::PFN_vkCmdCopyBufferToImage2) -> Hook { Hook::new(Device, "vkCmdCopyBufferToImage2", unsafe { mem::transmute::<vk::PFN_vkCmdCopyBufferToImage2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyBufferToImage2KHR(pfn: vk::PFN_vkCmdCopyBufferToImage2) -> Hook { Hook::new(Device, "vkCmdCopyBufferToImage2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdCopyBufferToImage2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyImage(pfn: vk::PFN_vkCmdCopyImage) -> Hook { Hook::new(Device, "vkCmdCopyImage", unsafe { mem::transmute::<vk::PFN_vkCmdCopyImage, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyImage2(pfn: vk::PFN_vkCmdCopyImage2) -> Hook { Hook::new(Device, "vkCmdCopyImage2", unsafe { mem::transmute::<vk::PFN_vkCmdCopyImage2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyImage2KHR(pfn: vk::PFN_vkCmdCopyImage2) -> Hook { Hook::new(Device, "vkCmdCopyImage2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdCopyImage2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyImageToBuffer(pfn: vk::PFN_vkCmdCopyImageToBuffer) -> Hook { Hook::new(Device, "vkCmdCopyImageToBuffer", unsafe { mem::transmute::<vk::PFN_vkCmdCopyImageToBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyImageToBuffer2(pfn: vk::PFN_vkCmdCopyImageToBuffer2) -> Hook { Hook::new(Device, "vkCmdCopyImageToBuffer2", unsafe { mem::transmute::<vk::PFN_vkCmdCopyImageToBuffer2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyImageToBuffer2KHR(pfn: vk::PFN_vkCmdCopyImageToBuffer2) -> Hook { Hook::new(Device, "vkCmdCopyImageToBuffer2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdCopyImageToBuffer2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyMemoryIndirectNV(pfn: vk::PFN_vkCmdCopyMemoryIndirectNV) -> Hook { Hook::new(Device, "vkCmdCopyMemoryIndirectNV", unsafe { mem::transmute::<vk::PFN_vkCmdCopyMemoryIndirectNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyMemoryToAccelerationStructureKHR(pfn: vk::PFN_vkCmdCopyMemoryToAccelerationStructureKHR) -> Hook { Hook::new(Device, "vkCmdCopyMemoryToAccelerationStructureKHR", unsafe { mem::transmute::<vk::PFN_vkCmdCopyMemoryToAccelerationStructureKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyMemoryToImageIndirectNV(pfn: vk::PFN_vkCmdCopyMemoryToImageIndirectNV) -> Hook { Hook::new(Device, "vkCmdCopyMemoryToImageIndirectNV", unsafe { mem::transmute::<vk::PFN_vkCmdCopyMemoryToImageIndirectNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyMemoryToMicromapEXT(pfn: vk::PFN_vkCmdCopyMemoryToMicromapEXT) -> Hook { Hook::new(Device, "vkCmdCopyMemoryToMicromapEXT", unsafe { mem::transmute::<vk::PFN_vkCmdCopyMemoryToMicromapEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyMicromapEXT(pfn: vk::PFN_vkCmdCopyMicromapEXT) -> Hook { Hook::new(Device, "vkCmdCopyMicromapEXT", unsafe { mem::transmute::<vk::PFN_vkCmdCopyMicromapEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyMicromapToMemoryEXT(pfn: vk::PFN_vkCmdCopyMicromapToMemoryEXT) -> Hook { Hook::new(Device, "vkCmdCopyMicromapToMemoryEXT", unsafe { mem::transmute::<vk::PFN_vkCmdCopyMicromapToMemoryEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCopyQueryPoolResults(pfn: vk::PFN_vkCmdCopyQueryPoolResults) -> Hook { Hook::new(Device, "vkCmdCopyQueryPoolResults", unsafe { mem::transmute::<vk::PFN_vkCmdCopyQueryPoolResults, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdCuLaunchKernelNVX(pfn: vk::PFN_vkCmdCuLaunchKernelNVX) -> Hook { Hook::new(Device, "vkCmdCuLaunchKernelNVX", unsafe { mem::transmute::<vk::PFN_vkCmdCuLaunchKernelNVX, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDebugMarkerBeginEXT(pfn: vk::PFN_vkCmdDebugMarkerBeginEXT) -> Hook { Hook::new(Device, "vkCmdDebugMarkerBeginEXT", unsafe { mem::transmute::<vk::PFN_vkCmdDebugMarkerBeginEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDebugMarkerEndEXT(pfn: vk::PFN_vkCmdDebugMarkerEndEXT) -> Hook { Hook::new(Device, "vkCmdDebugMarkerEndEXT", unsafe { mem::transmute::<vk::PFN_vkCmdDebugMarkerEndEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDebugMarkerInsertEXT(pfn: vk::PFN_vkCmdDebugMarkerInsertEXT) -> Hook { Hook::new(Device, "vkCmdDebugMarkerInsertEXT", unsafe { mem::transmute::<vk::PFN_vkCmdDebugMarkerInsertEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDecodeVideoKHR(pfn: vk::PFN_vkCmdDecodeVideoKHR) -> Hook { Hook::new(Device, "vkCmdDecodeVideoKHR", unsafe { mem::transmute::<vk::PFN_vkCmdDecodeVideoKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDecompressMemoryIndirectCountNV(pfn: vk::PFN_vkCmdDecompressMemoryIndirectCountNV) -> Hook { Hook::new(Device, "vkCmdDecompressMemoryIndirectCountNV", unsafe { mem::transmute::<vk::PFN_vkCmdDecompressMemoryIndirectCountNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDecompressMemoryNV(pfn: vk::PFN_vkCmdDecompressMemoryNV) -> Hook { Hook::new(Device, "vkCmdDecompressMemoryNV", unsafe { mem::transmute::<vk::PFN_vkCmdDecompressMemoryNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDispatch(pfn: vk::PFN_vkCmdDispatch) -> Hook { Hook::new(Device, "vkCmdDispatch", unsafe { mem::transmute::<vk::PFN_vkCmdDispatch, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDispatchBase(pfn: vk::PFN_vkCmdDispatchBase) -> Hook { Hook::new(Device, "vkCmdDispatchBase", unsafe { mem::transmute::<vk::PFN_vkCmdDispatchBase, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDispatchBaseKHR(pfn: vk::PFN_vkCmdDispatchBase) -> Hook { Hook::new(Device, "vkCmdDispatchBaseKHR", unsafe { mem::transmute::<vk::PFN_vkCmdDispatchBase, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDispatchIndirect(pfn: vk::PFN_vkCmdDispatchIndirect) -> Hook { Hook::new(Device, "vkCmdDispatchIndirect", unsafe { mem::transmute::<vk::PFN_vkCmdDispatchIndirect, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDraw(pfn: vk::PFN_vkCmdDraw) -> Hook { Hook::new(Device, "vkCmdDraw", unsafe { mem::transmute::<vk::PFN_vkCmdDraw, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawClusterHUAWEI(pfn: vk::PFN_vkCmdDrawClusterHUAWEI) -> Hook { Hook::new(Device, "vkCmdDrawClusterHUAWEI", unsafe { mem::transmute::<vk::PFN_vkCmdDrawClusterHUAWEI, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawClusterIndirectHUAWEI(pfn: vk::PFN_vkCmdDrawClusterIndirectHUAWEI) -> Hook { Hook::new(Device, "vkCmdDrawClusterIndirectHUAWEI", unsafe { mem::transmute::<vk::PFN_vkCmdDrawClusterIndirectHUAWEI, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndexed(pfn: vk::PFN_vkCmdDrawIndexed) -> Hook { Hook::new(Device, "vkCmdDrawIndexed", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndexed, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndexedIndirect(pfn: vk::PFN_vkCmdDrawIndexedIndirect) -> Hook { Hook::new(Device, "vkCmdDrawIndexedIndirect", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndexedIndirect, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndexedIndirectCount(pfn: vk::PFN_vkCmdDrawIndexedIndirectCount) -> Hook { Hook::new(Device, "vkCmdDrawIndexedIndirectCount", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndexedIndirectCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndexedIndirectCountAMD(pfn: vk::PFN_vkCmdDrawIndexedIndirectCount) -> Hook { Hook::new(Device, "vkCmdDrawIndexedIndirectCountAMD", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndexedIndirectCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndexedIndirectCountKHR(pfn: vk::PFN_vkCmdDrawIndexedIndirectCount) -> Hook { Hook::new(Device, "vkCmdDrawIndexedIndirectCountKHR", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndexedIndirectCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndirect(pfn: vk::PFN_vkCmdDrawIndirect) -> Hook { Hook::new(Device, "vkCmdDrawIndirect", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndirect, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndirectByteCountEXT(pfn: vk::PFN_vkCmdDrawIndirectByteCountEXT) -> Hook { Hook::new(Device, "vkCmdDrawIndirectByteCountEXT", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndirectByteCountEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndirectCount(pfn: vk::PFN_vkCmdDrawIndirectCount) -> Hook { Hook::new(Device, "vkCmdDrawIndirectCount", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndirectCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndirectCountAMD(pfn: vk::PFN_vkCmdDrawIndirectCount) -> Hook { Hook::new(Device, "vkCmdDrawIndirectCountAMD", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndirectCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawIndirectCountKHR(pfn: vk::PFN_vkCmdDrawIndirectCount) -> Hook { Hook::new(Device, "vkCmdDrawIndirectCountKHR", unsafe { mem::transmute::<vk::PFN_vkCmdDrawIndirectCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawMeshTasksEXT(pfn: vk::PFN_vkCmdDrawMeshTasksEXT) -> Hook { Hook::new(Device, "vkCmdDrawMeshTasksEXT", unsafe { mem::transmute::<vk::PFN_vkCmdDrawMeshTasksEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawMeshTasksIndirectCountEXT(pfn: vk::PFN_vkCmdDrawMeshTasksIndirectCountEXT) -> Hook { Hook::new(Device, "vkCmdDrawMeshTasksIndirectCountEXT", unsafe { mem::transmute::<vk::PFN_vkCmdDrawMeshTasksIndirectCountEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawMeshTasksIndirectCountNV(pfn: vk::PFN_vkCmdDrawMeshTasksIndirectCountNV) -> Hook { Hook::new(Device, "vkCmdDrawMeshTasksIndirectCountNV", unsafe { mem::transmute::<vk::PFN_vkCmdDrawMeshTasksIndirectCountNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawMeshTasksIndirectEXT(pfn: vk::PFN_vkCmdDrawMeshTasksIndirectEXT) -> Hook { Hook::new(Device, "vkCmdDrawMeshTasksIndirectEXT", unsafe { mem::transmute::<vk::PFN_vkCmdDrawMeshTasksIndirectEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawMeshTasksIndirectNV(pfn: vk::PFN_vkCmdDrawMeshTasksIndirectNV) -> Hook { Hook::new(Device, "vkCmdDrawMeshTasksIndirectNV", unsafe { mem::transmute::<vk::PFN_vkCmdDrawMeshTasksIndirectNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawMeshTasksNV(pfn: vk::PFN_vkCmdDrawMeshTasksNV) -> Hook { Hook::new(Device, "vkCmdDrawMeshTasksNV", unsafe { mem::transmute::<vk::PFN_vkCmdDrawMeshTasksNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawMultiEXT(pfn: vk::PFN_vkCmdDrawMultiEXT) -> Hook { Hook::new(Device, "vkCmdDrawMultiEXT", unsafe { mem::transmute::<vk::PFN_vkCmdDrawMultiEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdDrawMultiIndexedEXT(pfn: vk::PFN_vkCmdDrawMultiIndexedEXT) -> Hook { Hook::new(Device, "vkCmdDrawMultiIndexedEXT", unsafe { mem::transmute::<vk::PFN_vkCmdDrawMultiIndexedEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEncodeVideoKHR(pfn: vk::PFN_vkCmdEncodeVideoKHR) -> Hook { Hook::new(Device, "vkCmdEncodeVideoKHR", unsafe { mem::transmute::<vk::PFN_vkCmdEncodeVideoKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndConditionalRenderingEXT(pfn: vk::PFN_vkCmdEndConditionalRenderingEXT) -> Hook { Hook::new(Device, "vkCmdEndConditionalRenderingEXT", unsafe { mem::transmute::<vk::PFN_vkCmdEndConditionalRenderingEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndDebugUtilsLabelEXT(pfn: vk::PFN_vkCmdEndDebugUtilsLabelEXT) -> Hook { Hook::new(Device, "vkCmdEndDebugUtilsLabelEXT", unsafe { mem::transmute::<vk::PFN_vkCmdEndDebugUtilsLabelEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndQuery(pfn: vk::PFN_vkCmdEndQuery) -> Hook { Hook::new(Device, "vkCmdEndQuery", unsafe { mem::transmute::<vk::PFN_vkCmdEndQuery, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndQueryIndexedEXT(pfn: vk::PFN_vkCmdEndQueryIndexedEXT) -> Hook { Hook::new(Device, "vkCmdEndQueryIndexedEXT", unsafe { mem::transmute::<vk::PFN_vkCmdEndQueryIndexedEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndRenderPass(pfn: vk::PFN_vkCmdEndRenderPass) -> Hook { Hook::new(Device, "vkCmdEndRenderPass", unsafe { mem::transmute::<vk::PFN_vkCmdEndRenderPass, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndRenderPass2(pfn: vk::PFN_vkCmdEndRenderPass2) -> Hook { Hook::new(Device, "vkCmdEndRenderPass2", unsafe { mem::transmute::<vk::PFN_vkCmdEndRenderPass2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndRenderPass2KHR(pfn: vk::PFN_vkCmdEndRenderPass2) -> Hook { Hook::new(Device, "vkCmdEndRenderPass2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdEndRenderPass2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndRendering(pfn: vk::PFN_vkCmdEndRendering) -> Hook { Hook::new(Device, "vkCmdEndRendering", unsafe { mem::transmute::<vk::PFN_vkCmdEndRendering, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndRenderingKHR(pfn: vk::PFN_vkCmdEndRendering) -> Hook { Hook::new(Device, "vkCmdEndRenderingKHR", unsafe { mem::transmute::<vk::PFN_vkCmdEndRendering, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndTransformFeedbackEXT(pfn: vk::PFN_vkCmdEndTransformFeedbackEXT) -> Hook { Hook::new(Device, "vkCmdEndTransformFeedbackEXT", unsafe { mem::transmute::<vk::PFN_vkCmdEndTransformFeedbackEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdEndVideoCodingKHR(pfn: vk::PFN_vkCmdEndVideoCodingKHR) -> Hook { Hook::new(Device, "vkCmdEndVideoCodingKHR", unsafe { mem::transmute::<vk::PFN_vkCmdEndVideoCodingKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdExecuteCommands(pfn: vk::PFN_vkCmdExecuteCommands) -> Hook { Hook::new(Device, "vkCmdExecuteCommands", unsafe { mem::transmute::<vk::PFN_vkCmdExecuteCommands, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdExecuteGeneratedCommandsNV(pfn: vk::PFN_vkCmdExecuteGeneratedCommandsNV) -> Hook { Hook::new(Device, "vkCmdExecuteGeneratedCommandsNV", unsafe { mem::transmute::<vk::PFN_vkCmdExecuteGeneratedCommandsNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdFillBuffer(pfn: vk::PFN_vkCmdFillBuffer) -> Hook { Hook::new(Device, "vkCmdFillBuffer", unsafe { mem::transmute::<vk::PFN_vkCmdFillBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdInsertDebugUtilsLabelEXT(pfn: vk::PFN_vkCmdInsertDebugUtilsLabelEXT) -> Hook { Hook::new(Device, "vkCmdInsertDebugUtilsLabelEXT", unsafe { mem::transmute::<vk::PFN_vkCmdInsertDebugUtilsLabelEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdNextSubpass(pfn: vk::PFN_vkCmdNextSubpass) -> Hook { Hook::new(Device, "vkCmdNextSubpass", unsafe { mem::transmute::<vk::PFN_vkCmdNextSubpass, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdNextSubpass2(pfn: vk::PFN_vkCmdNextSubpass2) -> Hook { Hook::new(Device, "vkCmdNextSubpass2", unsafe { mem::transmute::<vk::PFN_vkCmdNextSubpass2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdNextSubpass2KHR(pfn: vk::PFN_vkCmdNextSubpass2) -> Hook { Hook::new(Device, "vkCmdNextSubpass2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdNextSubpass2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdOpticalFlowExecuteNV(pfn: vk::PFN_vkCmdOpticalFlowExecuteNV) -> Hook { Hook::new(Device, "vkCmdOpticalFlowExecuteNV", unsafe { mem::transmute::<vk::PFN_vkCmdOpticalFlowExecuteNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdPipelineBarrier(pfn: vk::PFN_vkCmdPipelineBarrier) -> Hook { Hook::new(Device, "vkCmdPipelineBarrier", unsafe { mem::transmute::<vk::PFN_vkCmdPipelineBarrier, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdPipelineBarrier2(pfn: vk::PFN_vkCmdPipelineBarrier2) -> Hook { Hook::new(Device, "vkCmdPipelineBarrier2", unsafe { mem::transmute::<vk::PFN_vkCmdPipelineBarrier2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdPipelineBarrier2KHR(pfn: vk::PFN_vkCmdPipelineBarrier2) -> Hook { Hook::new(Device, "vkCmdPipelineBarrier2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdPipelineBarrier2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdPreprocessGeneratedCommandsNV(pfn: vk::PFN_vkCmdPreprocessGeneratedCommandsNV) -> Hook { Hook::new(Device, "vkCmdPreprocessGeneratedCommandsNV", unsafe { mem::transmute::<vk::PFN_vkCmdPreprocessGeneratedCommandsNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdPushConstants(pfn: vk::PFN_vkCmdPushConstants) -> Hook { Hook::new(Device, "vkCmdPushConstants", unsafe { mem::transmute::<vk::PFN_vkCmdPushConstants, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdPushDescriptorSetKHR(pfn: vk::PFN_vkCmdPushDescriptorSetKHR) -> Hook { Hook::new(Device, "vkCmdPushDescriptorSetKHR", unsafe { mem::transmute::<vk::PFN_vkCmdPushDescriptorSetKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdPushDescriptorSetWithTemplateKHR(pfn: vk::PFN_vkCmdPushDescriptorSetWithTemplateKHR) -> Hook { Hook::new(Device, "vkCmdPushDescriptorSetWithTemplateKHR", unsafe { mem::transmute::<vk::PFN_vkCmdPushDescriptorSetWithTemplateKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdResetEvent(pfn: vk::PFN_vkCmdResetEvent) -> Hook { Hook::new(Device, "vkCmdResetEvent", unsafe { mem::transmute::<vk::PFN_vkCmdResetEvent, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdResetEvent2(pfn: vk::PFN_vkCmdResetEvent2) -> Hook { Hook::new(Device, "vkCmdResetEvent2", unsafe { mem::transmute::<vk::PFN_vkCmdResetEvent2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdResetEvent2KHR(pfn: vk::PFN_vkCmdResetEvent2) -> Hook { Hook::new(Device, "vkCmdResetEvent2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdResetEvent2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdResetQueryPool(pfn: vk::PFN_vkCmdResetQueryPool) -> Hook { Hook::new(Device, "vkCmdResetQueryPool", unsafe { mem::transmute::<vk::PFN_vkCmdResetQueryPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdResolveImage(pfn: vk::PFN_vkCmdResolveImage) -> Hook { Hook::new(Device, "vkCmdResolveImage", unsafe { mem::transmute::<vk::PFN_vkCmdResolveImage, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdResolveImage2(pfn: vk::PFN_vkCmdResolveImage2) -> Hook { Hook::new(Device, "vkCmdResolveImage2", unsafe { mem::transmute::<vk::PFN_vkCmdResolveImage2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdResolveImage2KHR(pfn: vk::PFN_vkCmdResolveImage2) -> Hook { Hook::new(Device, "vkCmdResolveImage2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdResolveImage2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetAlphaToCoverageEnableEXT(pfn: vk::PFN_vkCmdSetAlphaToCoverageEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetAlphaToCoverageEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetAlphaToCoverageEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetAlphaToOneEnableEXT(pfn: vk::PFN_vkCmdSetAlphaToOneEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetAlphaToOneEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetAlphaToOneEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetAttachmentFeedbackLoopEnableEXT(pfn: vk::PFN_vkCmdSetAttachmentFeedbackLoopEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetAttachmentFeedbackLoopEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetAttachmentFeedbackLoopEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetBlendConstants(pfn: vk::PFN_vkCmdSetBlendConstants) -> Hook { Hook::new(Device, "vkCmdSetBlendConstants", unsafe { mem::transmute::<vk::PFN_vkCmdSetBlendConstants, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCheckpointNV(pfn: vk::PFN_vkCmdSetCheckpointNV) -> Hook { Hook::new(Device, "vkCmdSetCheckpointNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetCheckpointNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCoarseSampleOrderNV(pfn: vk::PFN_vkCmdSetCoarseSampleOrderNV) -> Hook { Hook::new(Device, "vkCmdSetCoarseSampleOrderNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetCoarseSampleOrderNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetColorBlendAdvancedEXT(pfn: vk::PFN_vkCmdSetColorBlendAdvancedEXT) -> Hook { Hook::new(Device, "vkCmdSetColorBlendAdvancedEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetColorBlendAdvancedEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetColorBlendEnableEXT(pfn: vk::PFN_vkCmdSetColorBlendEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetColorBlendEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetColorBlendEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetColorBlendEquationEXT(pfn: vk::PFN_vkCmdSetColorBlendEquationEXT) -> Hook { Hook::new(Device, "vkCmdSetColorBlendEquationEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetColorBlendEquationEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetColorWriteEnableEXT(pfn: vk::PFN_vkCmdSetColorWriteEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetColorWriteEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetColorWriteEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetColorWriteMaskEXT(pfn: vk::PFN_vkCmdSetColorWriteMaskEXT) -> Hook { Hook::new(Device, "vkCmdSetColorWriteMaskEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetColorWriteMaskEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetConservativeRasterizationModeEXT(pfn: vk::PFN_vkCmdSetConservativeRasterizationModeEXT) -> Hook { Hook::new(Device, "vkCmdSetConservativeRasterizationModeEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetConservativeRasterizationModeEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCoverageModulationModeNV(pfn: vk::PFN_vkCmdSetCoverageModulationModeNV) -> Hook { Hook::new(Device, "vkCmdSetCoverageModulationModeNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetCoverageModulationModeNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCoverageModulationTableEnableNV(pfn: vk::PFN_vkCmdSetCoverageModulationTableEnableNV) -> Hook { Hook::new(Device, "vkCmdSetCoverageModulationTableEnableNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetCoverageModulationTableEnableNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCoverageModulationTableNV(pfn: vk::PFN_vkCmdSetCoverageModulationTableNV) -> Hook { Hook::new(Device, "vkCmdSetCoverageModulationTableNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetCoverageModulationTableNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCoverageReductionModeNV(pfn: vk::PFN_vkCmdSetCoverageReductionModeNV) -> Hook { Hook::new(Device, "vkCmdSetCoverageReductionModeNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetCoverageReductionModeNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCoverageToColorEnableNV(pfn: vk::PFN_vkCmdSetCoverageToColorEnableNV) -> Hook { Hook::new(Device, "vkCmdSetCoverageToColorEnableNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetCoverageToColorEnableNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCoverageToColorLocationNV(pfn: vk::PFN_vkCmdSetCoverageToColorLocationNV) -> Hook { Hook::new(Device, "vkCmdSetCoverageToColorLocationNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetCoverageToColorLocationNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCullMode(pfn: vk::PFN_vkCmdSetCullMode) -> Hook { Hook::new(Device, "vkCmdSetCullMode", unsafe { mem::transmute::<vk::PFN_vkCmdSetCullMode, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetCullModeEXT(pfn: vk::PFN_vkCmdSetCullMode) -> Hook { Hook::new(Device, "vkCmdSetCullModeEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetCullMode, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthBias(pfn: vk::PFN_vkCmdSetDepthBias) -> Hook { Hook::new(Device, "vkCmdSetDepthBias", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthBias, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthBiasEnable(pfn: vk::PFN_vkCmdSetDepthBiasEnable) -> Hook { Hook::new(Device, "vkCmdSetDepthBiasEnable", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthBiasEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthBiasEnableEXT(pfn: vk::PFN_vkCmdSetDepthBiasEnable) -> Hook { Hook::new(Device, "vkCmdSetDepthBiasEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthBiasEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthBounds(pfn: vk::PFN_vkCmdSetDepthBounds) -> Hook { Hook::new(Device, "vkCmdSetDepthBounds", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthBounds, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthBoundsTestEnable(pfn: vk::PFN_vkCmdSetDepthBoundsTestEnable) -> Hook { Hook::new(Device, "vkCmdSetDepthBoundsTestEnable", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthBoundsTestEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthBoundsTestEnableEXT(pfn: vk::PFN_vkCmdSetDepthBoundsTestEnable) -> Hook { Hook::new(Device, "vkCmdSetDepthBoundsTestEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthBoundsTestEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthClampEnableEXT(pfn: vk::PFN_vkCmdSetDepthClampEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetDepthClampEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthClampEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthClipEnableEXT(pfn: vk::PFN_vkCmdSetDepthClipEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetDepthClipEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthClipEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthClipNegativeOneToOneEXT(pfn: vk::PFN_vkCmdSetDepthClipNegativeOneToOneEXT) -> Hook { Hook::new(Device, "vkCmdSetDepthClipNegativeOneToOneEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthClipNegativeOneToOneEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthCompareOp(pfn: vk::PFN_vkCmdSetDepthCompareOp) -> Hook { Hook::new(Device, "vkCmdSetDepthCompareOp", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthCompareOp, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthCompareOpEXT(pfn: vk::PFN_vkCmdSetDepthCompareOp) -> Hook { Hook::new(Device, "vkCmdSetDepthCompareOpEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthCompareOp, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthTestEnable(pfn: vk::PFN_vkCmdSetDepthTestEnable) -> Hook { Hook::new(Device, "vkCmdSetDepthTestEnable", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthTestEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthTestEnableEXT(pfn: vk::PFN_vkCmdSetDepthTestEnable) -> Hook { Hook::new(Device, "vkCmdSetDepthTestEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthTestEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthWriteEnable(pfn: vk::PFN_vkCmdSetDepthWriteEnable) -> Hook { Hook::new(Device, "vkCmdSetDepthWriteEnable", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthWriteEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDepthWriteEnableEXT(pfn: vk::PFN_vkCmdSetDepthWriteEnable) -> Hook { Hook::new(Device, "vkCmdSetDepthWriteEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDepthWriteEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDescriptorBufferOffsetsEXT(pfn: vk::PFN_vkCmdSetDescriptorBufferOffsetsEXT) -> Hook { Hook::new(Device, "vkCmdSetDescriptorBufferOffsetsEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDescriptorBufferOffsetsEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDeviceMask(pfn: vk::PFN_vkCmdSetDeviceMask) -> Hook { Hook::new(Device, "vkCmdSetDeviceMask", unsafe { mem::transmute::<vk::PFN_vkCmdSetDeviceMask, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDeviceMaskKHR(pfn: vk::PFN_vkCmdSetDeviceMask) -> Hook { Hook::new(Device, "vkCmdSetDeviceMaskKHR", unsafe { mem::transmute::<vk::PFN_vkCmdSetDeviceMask, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDiscardRectangleEXT(pfn: vk::PFN_vkCmdSetDiscardRectangleEXT) -> Hook { Hook::new(Device, "vkCmdSetDiscardRectangleEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDiscardRectangleEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDiscardRectangleEnableEXT(pfn: vk::PFN_vkCmdSetDiscardRectangleEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetDiscardRectangleEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDiscardRectangleEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetDiscardRectangleModeEXT(pfn: vk::PFN_vkCmdSetDiscardRectangleModeEXT) -> Hook { Hook::new(Device, "vkCmdSetDiscardRectangleModeEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetDiscardRectangleModeEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetEvent(pfn: vk::PFN_vkCmdSetEvent) -> Hook { Hook::new(Device, "vkCmdSetEvent", unsafe { mem::transmute::<vk::PFN_vkCmdSetEvent, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetEvent2(pfn: vk::PFN_vkCmdSetEvent2) -> Hook { Hook::new(Device, "vkCmdSetEvent2", unsafe { mem::transmute::<vk::PFN_vkCmdSetEvent2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetEvent2KHR(pfn: vk::PFN_vkCmdSetEvent2) -> Hook { Hook::new(Device, "vkCmdSetEvent2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdSetEvent2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetExclusiveScissorEnableNV(pfn: vk::PFN_vkCmdSetExclusiveScissorEnableNV) -> Hook { Hook::new(Device, "vkCmdSetExclusiveScissorEnableNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetExclusiveScissorEnableNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetExclusiveScissorNV(pfn: vk::PFN_vkCmdSetExclusiveScissorNV) -> Hook { Hook::new(Device, "vkCmdSetExclusiveScissorNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetExclusiveScissorNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetExtraPrimitiveOverestimationSizeEXT(pfn: vk::PFN_vkCmdSetExtraPrimitiveOverestimationSizeEXT) -> Hook { Hook::new(Device, "vkCmdSetExtraPrimitiveOverestimationSizeEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetExtraPrimitiveOverestimationSizeEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetFragmentShadingRateEnumNV(pfn: vk::PFN_vkCmdSetFragmentShadingRateEnumNV) -> Hook { Hook::new(Device, "vkCmdSetFragmentShadingRateEnumNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetFragmentShadingRateEnumNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetFragmentShadingRateKHR(pfn: vk::PFN_vkCmdSetFragmentShadingRateKHR) -> Hook { Hook::new(Device, "vkCmdSetFragmentShadingRateKHR", unsafe { mem::transmute::<vk::PFN_vkCmdSetFragmentShadingRateKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetFrontFace(pfn: vk::PFN_vkCmdSetFrontFace) -> Hook { Hook::new(Device, "vkCmdSetFrontFace", unsafe { mem::transmute::<vk::PFN_vkCmdSetFrontFace, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetFrontFaceEXT(pfn: vk::PFN_vkCmdSetFrontFace) -> Hook { Hook::new(Device, "vkCmdSetFrontFaceEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetFrontFace, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetLineRasterizationModeEXT(pfn: vk::PFN_vkCmdSetLineRasterizationModeEXT) -> Hook { Hook::new(Device, "vkCmdSetLineRasterizationModeEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetLineRasterizationModeEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetLineStippleEXT(pfn: vk::PFN_vkCmdSetLineStippleEXT) -> Hook { Hook::new(Device, "vkCmdSetLineStippleEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetLineStippleEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetLineStippleEnableEXT(pfn: vk::PFN_vkCmdSetLineStippleEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetLineStippleEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetLineStippleEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetLineWidth(pfn: vk::PFN_vkCmdSetLineWidth) -> Hook { Hook::new(Device, "vkCmdSetLineWidth", unsafe { mem::transmute::<vk::PFN_vkCmdSetLineWidth, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetLogicOpEXT(pfn: vk::PFN_vkCmdSetLogicOpEXT) -> Hook { Hook::new(Device, "vkCmdSetLogicOpEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetLogicOpEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetLogicOpEnableEXT(pfn: vk::PFN_vkCmdSetLogicOpEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetLogicOpEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetLogicOpEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetPatchControlPointsEXT(pfn: vk::PFN_vkCmdSetPatchControlPointsEXT) -> Hook { Hook::new(Device, "vkCmdSetPatchControlPointsEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetPatchControlPointsEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetPerformanceMarkerINTEL(pfn: vk::PFN_vkCmdSetPerformanceMarkerINTEL) -> Hook { Hook::new(Device, "vkCmdSetPerformanceMarkerINTEL", unsafe { mem::transmute::<vk::PFN_vkCmdSetPerformanceMarkerINTEL, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetPerformanceOverrideINTEL(pfn: vk::PFN_vkCmdSetPerformanceOverrideINTEL) -> Hook { Hook::new(Device, "vkCmdSetPerformanceOverrideINTEL", unsafe { mem::transmute::<vk::PFN_vkCmdSetPerformanceOverrideINTEL, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetPerformanceStreamMarkerINTEL(pfn: vk::PFN_vkCmdSetPerformanceStreamMarkerINTEL) -> Hook { Hook::new(Device, "vkCmdSetPerformanceStreamMarkerINTEL", unsafe { mem::transmute::<vk::PFN_vkCmdSetPerformanceStreamMarkerINTEL, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetPolygonModeEXT(pfn: vk::PFN_vkCmdSetPolygonModeEXT) -> Hook { Hook::new(Device, "vkCmdSetPolygonModeEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetPolygonModeEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetPrimitiveRestartEnable(pfn: vk::PFN_vkCmdSetPrimitiveRestartEnable) -> Hook { Hook::new(Device, "vkCmdSetPrimitiveRestartEnable", unsafe { mem::transmute::<vk::PFN_vkCmdSetPrimitiveRestartEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetPrimitiveRestartEnableEXT(pfn: vk::PFN_vkCmdSetPrimitiveRestartEnable) -> Hook { Hook::new(Device, "vkCmdSetPrimitiveRestartEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetPrimitiveRestartEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetPrimitiveTopology(pfn: vk::PFN_vkCmdSetPrimitiveTopology) -> Hook { Hook::new(Device, "vkCmdSetPrimitiveTopology", unsafe { mem::transmute::<vk::PFN_vkCmdSetPrimitiveTopology, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetPrimitiveTopologyEXT(pfn: vk::PFN_vkCmdSetPrimitiveTopology) -> Hook { Hook::new(Device, "vkCmdSetPrimitiveTopologyEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetPrimitiveTopology, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetProvokingVertexModeEXT(pfn: vk::PFN_vkCmdSetProvokingVertexModeEXT) -> Hook { Hook::new(Device, "vkCmdSetProvokingVertexModeEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetProvokingVertexModeEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetRasterizationSamplesEXT(pfn: vk::PFN_vkCmdSetRasterizationSamplesEXT) -> Hook { Hook::new(Device, "vkCmdSetRasterizationSamplesEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetRasterizationSamplesEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetRasterizationStreamEXT(pfn: vk::PFN_vkCmdSetRasterizationStreamEXT) -> Hook { Hook::new(Device, "vkCmdSetRasterizationStreamEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetRasterizationStreamEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetRasterizerDiscardEnable(pfn: vk::PFN_vkCmdSetRasterizerDiscardEnable) -> Hook { Hook::new(Device, "vkCmdSetRasterizerDiscardEnable", unsafe { mem::transmute::<vk::PFN_vkCmdSetRasterizerDiscardEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetRasterizerDiscardEnableEXT(pfn: vk::PFN_vkCmdSetRasterizerDiscardEnable) -> Hook { Hook::new(Device, "vkCmdSetRasterizerDiscardEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetRasterizerDiscardEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetRayTracingPipelineStackSizeKHR(pfn: vk::PFN_vkCmdSetRayTracingPipelineStackSizeKHR) -> Hook { Hook::new(Device, "vkCmdSetRayTracingPipelineStackSizeKHR", unsafe { mem::transmute::<vk::PFN_vkCmdSetRayTracingPipelineStackSizeKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetRepresentativeFragmentTestEnableNV(pfn: vk::PFN_vkCmdSetRepresentativeFragmentTestEnableNV) -> Hook { Hook::new(Device, "vkCmdSetRepresentativeFragmentTestEnableNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetRepresentativeFragmentTestEnableNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetSampleLocationsEXT(pfn: vk::PFN_vkCmdSetSampleLocationsEXT) -> Hook { Hook::new(Device, "vkCmdSetSampleLocationsEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetSampleLocationsEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetSampleLocationsEnableEXT(pfn: vk::PFN_vkCmdSetSampleLocationsEnableEXT) -> Hook { Hook::new(Device, "vkCmdSetSampleLocationsEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetSampleLocationsEnableEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetSampleMaskEXT(pfn: vk::PFN_vkCmdSetSampleMaskEXT) -> Hook { Hook::new(Device, "vkCmdSetSampleMaskEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetSampleMaskEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetScissor(pfn: vk::PFN_vkCmdSetScissor) -> Hook { Hook::new(Device, "vkCmdSetScissor", unsafe { mem::transmute::<vk::PFN_vkCmdSetScissor, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetScissorWithCount(pfn: vk::PFN_vkCmdSetScissorWithCount) -> Hook { Hook::new(Device, "vkCmdSetScissorWithCount", unsafe { mem::transmute::<vk::PFN_vkCmdSetScissorWithCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetScissorWithCountEXT(pfn: vk::PFN_vkCmdSetScissorWithCount) -> Hook { Hook::new(Device, "vkCmdSetScissorWithCountEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetScissorWithCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetShadingRateImageEnableNV(pfn: vk::PFN_vkCmdSetShadingRateImageEnableNV) -> Hook { Hook::new(Device, "vkCmdSetShadingRateImageEnableNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetShadingRateImageEnableNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetStencilCompareMask(pfn: vk::PFN_vkCmdSetStencilCompareMask) -> Hook { Hook::new(Device, "vkCmdSetStencilCompareMask", unsafe { mem::transmute::<vk::PFN_vkCmdSetStencilCompareMask, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetStencilOp(pfn: vk::PFN_vkCmdSetStencilOp) -> Hook { Hook::new(Device, "vkCmdSetStencilOp", unsafe { mem::transmute::<vk::PFN_vkCmdSetStencilOp, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetStencilOpEXT(pfn: vk::PFN_vkCmdSetStencilOp) -> Hook { Hook::new(Device, "vkCmdSetStencilOpEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetStencilOp, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetStencilReference(pfn: vk::PFN_vkCmdSetStencilReference) -> Hook { Hook::new(Device, "vkCmdSetStencilReference", unsafe { mem::transmute::<vk::PFN_vkCmdSetStencilReference, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetStencilTestEnable(pfn: vk::PFN_vkCmdSetStencilTestEnable) -> Hook { Hook::new(Device, "vkCmdSetStencilTestEnable", unsafe { mem::transmute::<vk::PFN_vkCmdSetStencilTestEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetStencilTestEnableEXT(pfn: vk::PFN_vkCmdSetStencilTestEnable) -> Hook { Hook::new(Device, "vkCmdSetStencilTestEnableEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetStencilTestEnable, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetStencilWriteMask(pfn: vk::PFN_vkCmdSetStencilWriteMask) -> Hook { Hook::new(Device, "vkCmdSetStencilWriteMask", unsafe { mem::transmute::<vk::PFN_vkCmdSetStencilWriteMask, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetTessellationDomainOriginEXT(pfn: vk::PFN_vkCmdSetTessellationDomainOriginEXT) -> Hook { Hook::new(Device, "vkCmdSetTessellationDomainOriginEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetTessellationDomainOriginEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetVertexInputEXT(pfn: vk::PFN_vkCmdSetVertexInputEXT) -> Hook { Hook::new(Device, "vkCmdSetVertexInputEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetVertexInputEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetViewport(pfn: vk::PFN_vkCmdSetViewport) -> Hook { Hook::new(Device, "vkCmdSetViewport", unsafe { mem::transmute::<vk::PFN_vkCmdSetViewport, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetViewportShadingRatePaletteNV(pfn: vk::PFN_vkCmdSetViewportShadingRatePaletteNV) -> Hook { Hook::new(Device, "vkCmdSetViewportShadingRatePaletteNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetViewportShadingRatePaletteNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetViewportSwizzleNV(pfn: vk::PFN_vkCmdSetViewportSwizzleNV) -> Hook { Hook::new(Device, "vkCmdSetViewportSwizzleNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetViewportSwizzleNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetViewportWScalingEnableNV(pfn: vk::PFN_vkCmdSetViewportWScalingEnableNV) -> Hook { Hook::new(Device, "vkCmdSetViewportWScalingEnableNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetViewportWScalingEnableNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetViewportWScalingNV(pfn: vk::PFN_vkCmdSetViewportWScalingNV) -> Hook { Hook::new(Device, "vkCmdSetViewportWScalingNV", unsafe { mem::transmute::<vk::PFN_vkCmdSetViewportWScalingNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetViewportWithCount(pfn: vk::PFN_vkCmdSetViewportWithCount) -> Hook { Hook::new(Device, "vkCmdSetViewportWithCount", unsafe { mem::transmute::<vk::PFN_vkCmdSetViewportWithCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSetViewportWithCountEXT(pfn: vk::PFN_vkCmdSetViewportWithCount) -> Hook { Hook::new(Device, "vkCmdSetViewportWithCountEXT", unsafe { mem::transmute::<vk::PFN_vkCmdSetViewportWithCount, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdSubpassShadingHUAWEI(pfn: vk::PFN_vkCmdSubpassShadingHUAWEI) -> Hook { Hook::new(Device, "vkCmdSubpassShadingHUAWEI", unsafe { mem::transmute::<vk::PFN_vkCmdSubpassShadingHUAWEI, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdTraceRaysIndirect2KHR(pfn: vk::PFN_vkCmdTraceRaysIndirect2KHR) -> Hook { Hook::new(Device, "vkCmdTraceRaysIndirect2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdTraceRaysIndirect2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdTraceRaysIndirectKHR(pfn: vk::PFN_vkCmdTraceRaysIndirectKHR) -> Hook { Hook::new(Device, "vkCmdTraceRaysIndirectKHR", unsafe { mem::transmute::<vk::PFN_vkCmdTraceRaysIndirectKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdTraceRaysKHR(pfn: vk::PFN_vkCmdTraceRaysKHR) -> Hook { Hook::new(Device, "vkCmdTraceRaysKHR", unsafe { mem::transmute::<vk::PFN_vkCmdTraceRaysKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdTraceRaysNV(pfn: vk::PFN_vkCmdTraceRaysNV) -> Hook { Hook::new(Device, "vkCmdTraceRaysNV", unsafe { mem::transmute::<vk::PFN_vkCmdTraceRaysNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdUpdateBuffer(pfn: vk::PFN_vkCmdUpdateBuffer) -> Hook { Hook::new(Device, "vkCmdUpdateBuffer", unsafe { mem::transmute::<vk::PFN_vkCmdUpdateBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWaitEvents(pfn: vk::PFN_vkCmdWaitEvents) -> Hook { Hook::new(Device, "vkCmdWaitEvents", unsafe { mem::transmute::<vk::PFN_vkCmdWaitEvents, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWaitEvents2(pfn: vk::PFN_vkCmdWaitEvents2) -> Hook { Hook::new(Device, "vkCmdWaitEvents2", unsafe { mem::transmute::<vk::PFN_vkCmdWaitEvents2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWaitEvents2KHR(pfn: vk::PFN_vkCmdWaitEvents2) -> Hook { Hook::new(Device, "vkCmdWaitEvents2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdWaitEvents2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWriteAccelerationStructuresPropertiesKHR(pfn: vk::PFN_vkCmdWriteAccelerationStructuresPropertiesKHR) -> Hook { Hook::new(Device, "vkCmdWriteAccelerationStructuresPropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkCmdWriteAccelerationStructuresPropertiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWriteAccelerationStructuresPropertiesNV(pfn: vk::PFN_vkCmdWriteAccelerationStructuresPropertiesNV) -> Hook { Hook::new(Device, "vkCmdWriteAccelerationStructuresPropertiesNV", unsafe { mem::transmute::<vk::PFN_vkCmdWriteAccelerationStructuresPropertiesNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWriteBufferMarker2AMD(pfn: vk::PFN_vkCmdWriteBufferMarker2AMD) -> Hook { Hook::new(Device, "vkCmdWriteBufferMarker2AMD", unsafe { mem::transmute::<vk::PFN_vkCmdWriteBufferMarker2AMD, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWriteBufferMarkerAMD(pfn: vk::PFN_vkCmdWriteBufferMarkerAMD) -> Hook { Hook::new(Device, "vkCmdWriteBufferMarkerAMD", unsafe { mem::transmute::<vk::PFN_vkCmdWriteBufferMarkerAMD, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWriteMicromapsPropertiesEXT(pfn: vk::PFN_vkCmdWriteMicromapsPropertiesEXT) -> Hook { Hook::new(Device, "vkCmdWriteMicromapsPropertiesEXT", unsafe { mem::transmute::<vk::PFN_vkCmdWriteMicromapsPropertiesEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWriteTimestamp(pfn: vk::PFN_vkCmdWriteTimestamp) -> Hook { Hook::new(Device, "vkCmdWriteTimestamp", unsafe { mem::transmute::<vk::PFN_vkCmdWriteTimestamp, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWriteTimestamp2(pfn: vk::PFN_vkCmdWriteTimestamp2) -> Hook { Hook::new(Device, "vkCmdWriteTimestamp2", unsafe { mem::transmute::<vk::PFN_vkCmdWriteTimestamp2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCmdWriteTimestamp2KHR(pfn: vk::PFN_vkCmdWriteTimestamp2) -> Hook { Hook::new(Device, "vkCmdWriteTimestamp2KHR", unsafe { mem::transmute::<vk::PFN_vkCmdWriteTimestamp2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCompileDeferredNV(pfn: vk::PFN_vkCompileDeferredNV) -> Hook { Hook::new(Device, "vkCompileDeferredNV", unsafe { mem::transmute::<vk::PFN_vkCompileDeferredNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCopyAccelerationStructureKHR(pfn: vk::PFN_vkCopyAccelerationStructureKHR) -> Hook { Hook::new(Device, "vkCopyAccelerationStructureKHR", unsafe { mem::transmute::<vk::PFN_vkCopyAccelerationStructureKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCopyAccelerationStructureToMemoryKHR(pfn: vk::PFN_vkCopyAccelerationStructureToMemoryKHR) -> Hook { Hook::new(Device, "vkCopyAccelerationStructureToMemoryKHR", unsafe { mem::transmute::<vk::PFN_vkCopyAccelerationStructureToMemoryKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCopyMemoryToAccelerationStructureKHR(pfn: vk::PFN_vkCopyMemoryToAccelerationStructureKHR) -> Hook { Hook::new(Device, "vkCopyMemoryToAccelerationStructureKHR", unsafe { mem::transmute::<vk::PFN_vkCopyMemoryToAccelerationStructureKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCopyMemoryToMicromapEXT(pfn: vk::PFN_vkCopyMemoryToMicromapEXT) -> Hook { Hook::new(Device, "vkCopyMemoryToMicromapEXT", unsafe { mem::transmute::<vk::PFN_vkCopyMemoryToMicromapEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCopyMicromapEXT(pfn: vk::PFN_vkCopyMicromapEXT) -> Hook { Hook::new(Device, "vkCopyMicromapEXT", unsafe { mem::transmute::<vk::PFN_vkCopyMicromapEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCopyMicromapToMemoryEXT(pfn: vk::PFN_vkCopyMicromapToMemoryEXT) -> Hook { Hook::new(Device, "vkCopyMicromapToMemoryEXT", unsafe { mem::transmute::<vk::PFN_vkCopyMicromapToMemoryEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateAccelerationStructureKHR(pfn: vk::PFN_vkCreateAccelerationStructureKHR) -> Hook { Hook::new(Device, "vkCreateAccelerationStructureKHR", unsafe { mem::transmute::<vk::PFN_vkCreateAccelerationStructureKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateAccelerationStructureNV(pfn: vk::PFN_vkCreateAccelerationStructureNV) -> Hook { Hook::new(Device, "vkCreateAccelerationStructureNV", unsafe { mem::transmute::<vk::PFN_vkCreateAccelerationStructureNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateAndroidSurfaceKHR(pfn: vk::PFN_vkCreateAndroidSurfaceKHR) -> Hook { Hook::new(Instance, "vkCreateAndroidSurfaceKHR", unsafe { mem::transmute::<vk::PFN_vkCreateAndroidSurfaceKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateBuffer(pfn: vk::PFN_vkCreateBuffer) -> Hook { Hook::new(Device, "vkCreateBuffer", unsafe { mem::transmute::<vk::PFN_vkCreateBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateBufferCollectionFUCHSIA(pfn: vk::PFN_vkCreateBufferCollectionFUCHSIA) -> Hook { Hook::new(Device, "vkCreateBufferCollectionFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkCreateBufferCollectionFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateBufferView(pfn: vk::PFN_vkCreateBufferView) -> Hook { Hook::new(Device, "vkCreateBufferView", unsafe { mem::transmute::<vk::PFN_vkCreateBufferView, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateCommandPool(pfn: vk::PFN_vkCreateCommandPool) -> Hook { Hook::new(Device, "vkCreateCommandPool", unsafe { mem::transmute::<vk::PFN_vkCreateCommandPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateComputePipelines(pfn: vk::PFN_vkCreateComputePipelines) -> Hook { Hook::new(Device, "vkCreateComputePipelines", unsafe { mem::transmute::<vk::PFN_vkCreateComputePipelines, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateCuFunctionNVX(pfn: vk::PFN_vkCreateCuFunctionNVX) -> Hook { Hook::new(Device, "vkCreateCuFunctionNVX", unsafe { mem::transmute::<vk::PFN_vkCreateCuFunctionNVX, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateCuModuleNVX(pfn: vk::PFN_vkCreateCuModuleNVX) -> Hook { Hook::new(Device, "vkCreateCuModuleNVX", unsafe { mem::transmute::<vk::PFN_vkCreateCuModuleNVX, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDebugReportCallbackEXT(pfn: vk::PFN_vkCreateDebugReportCallbackEXT) -> Hook { Hook::new(Instance, "vkCreateDebugReportCallbackEXT", unsafe { mem::transmute::<vk::PFN_vkCreateDebugReportCallbackEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDebugUtilsMessengerEXT(pfn: vk::PFN_vkCreateDebugUtilsMessengerEXT) -> Hook { Hook::new(Instance, "vkCreateDebugUtilsMessengerEXT", unsafe { mem::transmute::<vk::PFN_vkCreateDebugUtilsMessengerEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDeferredOperationKHR(pfn: vk::PFN_vkCreateDeferredOperationKHR) -> Hook { Hook::new(Device, "vkCreateDeferredOperationKHR", unsafe { mem::transmute::<vk::PFN_vkCreateDeferredOperationKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDescriptorPool(pfn: vk::PFN_vkCreateDescriptorPool) -> Hook { Hook::new(Device, "vkCreateDescriptorPool", unsafe { mem::transmute::<vk::PFN_vkCreateDescriptorPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDescriptorSetLayout(pfn: vk::PFN_vkCreateDescriptorSetLayout) -> Hook { Hook::new(Device, "vkCreateDescriptorSetLayout", unsafe { mem::transmute::<vk::PFN_vkCreateDescriptorSetLayout, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDescriptorUpdateTemplate(pfn: vk::PFN_vkCreateDescriptorUpdateTemplate) -> Hook { Hook::new(Device, "vkCreateDescriptorUpdateTemplate", unsafe { mem::transmute::<vk::PFN_vkCreateDescriptorUpdateTemplate, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDescriptorUpdateTemplateKHR(pfn: vk::PFN_vkCreateDescriptorUpdateTemplate) -> Hook { Hook::new(Device, "vkCreateDescriptorUpdateTemplateKHR", unsafe { mem::transmute::<vk::PFN_vkCreateDescriptorUpdateTemplate, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDevice(pfn: vk::PFN_vkCreateDevice) -> Hook { Hook::new(PhysicalDevice, "vkCreateDevice", unsafe { mem::transmute::<vk::PFN_vkCreateDevice, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDirectFBSurfaceEXT(pfn: vk::PFN_vkCreateDirectFBSurfaceEXT) -> Hook { Hook::new(Instance, "vkCreateDirectFBSurfaceEXT", unsafe { mem::transmute::<vk::PFN_vkCreateDirectFBSurfaceEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDisplayModeKHR(pfn: vk::PFN_vkCreateDisplayModeKHR) -> Hook { Hook::new(PhysicalDevice, "vkCreateDisplayModeKHR", unsafe { mem::transmute::<vk::PFN_vkCreateDisplayModeKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateDisplayPlaneSurfaceKHR(pfn: vk::PFN_vkCreateDisplayPlaneSurfaceKHR) -> Hook { Hook::new(Instance, "vkCreateDisplayPlaneSurfaceKHR", unsafe { mem::transmute::<vk::PFN_vkCreateDisplayPlaneSurfaceKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateEvent(pfn: vk::PFN_vkCreateEvent) -> Hook { Hook::new(Device, "vkCreateEvent", unsafe { mem::transmute::<vk::PFN_vkCreateEvent, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateFence(pfn: vk::PFN_vkCreateFence) -> Hook { Hook::new(Device, "vkCreateFence", unsafe { mem::transmute::<vk::PFN_vkCreateFence, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateFramebuffer(pfn: vk::PFN_vkCreateFramebuffer) -> Hook { Hook::new(Device, "vkCreateFramebuffer", unsafe { mem::transmute::<vk::PFN_vkCreateFramebuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateGraphicsPipelines(pfn: vk::PFN_vkCreateGraphicsPipelines) -> Hook { Hook::new(Device, "vkCreateGraphicsPipelines", unsafe { mem::transmute::<vk::PFN_vkCreateGraphicsPipelines, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateHeadlessSurfaceEXT(pfn: vk::PFN_vkCreateHeadlessSurfaceEXT) -> Hook { Hook::new(Instance, "vkCreateHeadlessSurfaceEXT", unsafe { mem::transmute::<vk::PFN_vkCreateHeadlessSurfaceEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateIOSSurfaceMVK(pfn: vk::PFN_vkCreateIOSSurfaceMVK) -> Hook { Hook::new(Instance, "vkCreateIOSSurfaceMVK", unsafe { mem::transmute::<vk::PFN_vkCreateIOSSurfaceMVK, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateImage(pfn: vk::PFN_vkCreateImage) -> Hook { Hook::new(Device, "vkCreateImage", unsafe { mem::transmute::<vk::PFN_vkCreateImage, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateImagePipeSurfaceFUCHSIA(pfn: vk::PFN_vkCreateImagePipeSurfaceFUCHSIA) -> Hook { Hook::new(Instance, "vkCreateImagePipeSurfaceFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkCreateImagePipeSurfaceFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateImageView(pfn: vk::PFN_vkCreateImageView) -> Hook { Hook::new(Device, "vkCreateImageView", unsafe { mem::transmute::<vk::PFN_vkCreateImageView, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateIndirectCommandsLayoutNV(pfn: vk::PFN_vkCreateIndirectCommandsLayoutNV) -> Hook { Hook::new(Device, "vkCreateIndirectCommandsLayoutNV", unsafe { mem::transmute::<vk::PFN_vkCreateIndirectCommandsLayoutNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateInstance(pfn: vk::PFN_vkCreateInstance) -> Hook { Hook::new(Global, "vkCreateInstance", unsafe { mem::transmute::<vk::PFN_vkCreateInstance, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateMacOSSurfaceMVK(pfn: vk::PFN_vkCreateMacOSSurfaceMVK) -> Hook { Hook::new(Instance, "vkCreateMacOSSurfaceMVK", unsafe { mem::transmute::<vk::PFN_vkCreateMacOSSurfaceMVK, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateMetalSurfaceEXT(pfn: vk::PFN_vkCreateMetalSurfaceEXT) -> Hook { Hook::new(Instance, "vkCreateMetalSurfaceEXT", unsafe { mem::transmute::<vk::PFN_vkCreateMetalSurfaceEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateMicromapEXT(pfn: vk::PFN_vkCreateMicromapEXT) -> Hook { Hook::new(Device, "vkCreateMicromapEXT", unsafe { mem::transmute::<vk::PFN_vkCreateMicromapEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateOpticalFlowSessionNV(pfn: vk::PFN_vkCreateOpticalFlowSessionNV) -> Hook { Hook::new(Device, "vkCreateOpticalFlowSessionNV", unsafe { mem::transmute::<vk::PFN_vkCreateOpticalFlowSessionNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreatePipelineCache(pfn: vk::PFN_vkCreatePipelineCache) -> Hook { Hook::new(Device, "vkCreatePipelineCache", unsafe { mem::transmute::<vk::PFN_vkCreatePipelineCache, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreatePipelineLayout(pfn: vk::PFN_vkCreatePipelineLayout) -> Hook { Hook::new(Device, "vkCreatePipelineLayout", unsafe { mem::transmute::<vk::PFN_vkCreatePipelineLayout, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreatePrivateDataSlot(pfn: vk::PFN_vkCreatePrivateDataSlot) -> Hook { Hook::new(Device, "vkCreatePrivateDataSlot", unsafe { mem::transmute::<vk::PFN_vkCreatePrivateDataSlot, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreatePrivateDataSlotEXT(pfn: vk::PFN_vkCreatePrivateDataSlot) -> Hook { Hook::new(Device, "vkCreatePrivateDataSlotEXT", unsafe { mem::transmute::<vk::PFN_vkCreatePrivateDataSlot, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateQueryPool(pfn: vk::PFN_vkCreateQueryPool) -> Hook { Hook::new(Device, "vkCreateQueryPool", unsafe { mem::transmute::<vk::PFN_vkCreateQueryPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateRayTracingPipelinesKHR(pfn: vk::PFN_vkCreateRayTracingPipelinesKHR) -> Hook { Hook::new(Device, "vkCreateRayTracingPipelinesKHR", unsafe { mem::transmute::<vk::PFN_vkCreateRayTracingPipelinesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateRayTracingPipelinesNV(pfn: vk::PFN_vkCreateRayTracingPipelinesNV) -> Hook { Hook::new(Device, "vkCreateRayTracingPipelinesNV", unsafe { mem::transmute::<vk::PFN_vkCreateRayTracingPipelinesNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateRenderPass(pfn: vk::PFN_vkCreateRenderPass) -> Hook { Hook::new(Device, "vkCreateRenderPass", unsafe { mem::transmute::<vk::PFN_vkCreateRenderPass, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateRenderPass2(pfn: vk::PFN_vkCreateRenderPass2) -> Hook { Hook::new(Device, "vkCreateRenderPass2", unsafe { mem::transmute::<vk::PFN_vkCreateRenderPass2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateRenderPass2KHR(pfn: vk::PFN_vkCreateRenderPass2) -> Hook { Hook::new(Device, "vkCreateRenderPass2KHR", unsafe { mem::transmute::<vk::PFN_vkCreateRenderPass2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateSampler(pfn: vk::PFN_vkCreateSampler) -> Hook { Hook::new(Device, "vkCreateSampler", unsafe { mem::transmute::<vk::PFN_vkCreateSampler, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateSamplerYcbcrConversion(pfn: vk::PFN_vkCreateSamplerYcbcrConversion) -> Hook { Hook::new(Device, "vkCreateSamplerYcbcrConversion", unsafe { mem::transmute::<vk::PFN_vkCreateSamplerYcbcrConversion, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateSamplerYcbcrConversionKHR(pfn: vk::PFN_vkCreateSamplerYcbcrConversion) -> Hook { Hook::new(Device, "vkCreateSamplerYcbcrConversionKHR", unsafe { mem::transmute::<vk::PFN_vkCreateSamplerYcbcrConversion, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateScreenSurfaceQNX(pfn: vk::PFN_vkCreateScreenSurfaceQNX) -> Hook { Hook::new(Instance, "vkCreateScreenSurfaceQNX", unsafe { mem::transmute::<vk::PFN_vkCreateScreenSurfaceQNX, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateSemaphore(pfn: vk::PFN_vkCreateSemaphore) -> Hook { Hook::new(Device, "vkCreateSemaphore", unsafe { mem::transmute::<vk::PFN_vkCreateSemaphore, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateShaderModule(pfn: vk::PFN_vkCreateShaderModule) -> Hook { Hook::new(Device, "vkCreateShaderModule", unsafe { mem::transmute::<vk::PFN_vkCreateShaderModule, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateShadersEXT(pfn: vk::PFN_vkCreateShadersEXT) -> Hook { Hook::new(Device, "vkCreateShadersEXT", unsafe { mem::transmute::<vk::PFN_vkCreateShadersEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateSharedSwapchainsKHR(pfn: vk::PFN_vkCreateSharedSwapchainsKHR) -> Hook { Hook::new(Device, "vkCreateSharedSwapchainsKHR", unsafe { mem::transmute::<vk::PFN_vkCreateSharedSwapchainsKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateStreamDescriptorSurfaceGGP(pfn: vk::PFN_vkCreateStreamDescriptorSurfaceGGP) -> Hook { Hook::new(Instance, "vkCreateStreamDescriptorSurfaceGGP", unsafe { mem::transmute::<vk::PFN_vkCreateStreamDescriptorSurfaceGGP, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateSwapchainKHR(pfn: vk::PFN_vkCreateSwapchainKHR) -> Hook { Hook::new(Device, "vkCreateSwapchainKHR", unsafe { mem::transmute::<vk::PFN_vkCreateSwapchainKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateValidationCacheEXT(pfn: vk::PFN_vkCreateValidationCacheEXT) -> Hook { Hook::new(Device, "vkCreateValidationCacheEXT", unsafe { mem::transmute::<vk::PFN_vkCreateValidationCacheEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateViSurfaceNN(pfn: vk::PFN_vkCreateViSurfaceNN) -> Hook { Hook::new(Instance, "vkCreateViSurfaceNN", unsafe { mem::transmute::<vk::PFN_vkCreateViSurfaceNN, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateVideoSessionKHR(pfn: vk::PFN_vkCreateVideoSessionKHR) -> Hook { Hook::new(Device, "vkCreateVideoSessionKHR", unsafe { mem::transmute::<vk::PFN_vkCreateVideoSessionKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateVideoSessionParametersKHR(pfn: vk::PFN_vkCreateVideoSessionParametersKHR) -> Hook { Hook::new(Device, "vkCreateVideoSessionParametersKHR", unsafe { mem::transmute::<vk::PFN_vkCreateVideoSessionParametersKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateWaylandSurfaceKHR(pfn: vk::PFN_vkCreateWaylandSurfaceKHR) -> Hook { Hook::new(Instance, "vkCreateWaylandSurfaceKHR", unsafe { mem::transmute::<vk::PFN_vkCreateWaylandSurfaceKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateWin32SurfaceKHR(pfn: vk::PFN_vkCreateWin32SurfaceKHR) -> Hook { Hook::new(Instance, "vkCreateWin32SurfaceKHR", unsafe { mem::transmute::<vk::PFN_vkCreateWin32SurfaceKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateXcbSurfaceKHR(pfn: vk::PFN_vkCreateXcbSurfaceKHR) -> Hook { Hook::new(Instance, "vkCreateXcbSurfaceKHR", unsafe { mem::transmute::<vk::PFN_vkCreateXcbSurfaceKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkCreateXlibSurfaceKHR(pfn: vk::PFN_vkCreateXlibSurfaceKHR) -> Hook { Hook::new(Instance, "vkCreateXlibSurfaceKHR", unsafe { mem::transmute::<vk::PFN_vkCreateXlibSurfaceKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDebugMarkerSetObjectNameEXT(pfn: vk::PFN_vkDebugMarkerSetObjectNameEXT) -> Hook { Hook::new(Device, "vkDebugMarkerSetObjectNameEXT", unsafe { mem::transmute::<vk::PFN_vkDebugMarkerSetObjectNameEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDebugMarkerSetObjectTagEXT(pfn: vk::PFN_vkDebugMarkerSetObjectTagEXT) -> Hook { Hook::new(Device, "vkDebugMarkerSetObjectTagEXT", unsafe { mem::transmute::<vk::PFN_vkDebugMarkerSetObjectTagEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDebugReportMessageEXT(pfn: vk::PFN_vkDebugReportMessageEXT) -> Hook { Hook::new(Instance, "vkDebugReportMessageEXT", unsafe { mem::transmute::<vk::PFN_vkDebugReportMessageEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDeferredOperationJoinKHR(pfn: vk::PFN_vkDeferredOperationJoinKHR) -> Hook { Hook::new(Device, "vkDeferredOperationJoinKHR", unsafe { mem::transmute::<vk::PFN_vkDeferredOperationJoinKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyAccelerationStructureKHR(pfn: vk::PFN_vkDestroyAccelerationStructureKHR) -> Hook { Hook::new(Device, "vkDestroyAccelerationStructureKHR", unsafe { mem::transmute::<vk::PFN_vkDestroyAccelerationStructureKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyAccelerationStructureNV(pfn: vk::PFN_vkDestroyAccelerationStructureNV) -> Hook { Hook::new(Device, "vkDestroyAccelerationStructureNV", unsafe { mem::transmute::<vk::PFN_vkDestroyAccelerationStructureNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyBuffer(pfn: vk::PFN_vkDestroyBuffer) -> Hook { Hook::new(Device, "vkDestroyBuffer", unsafe { mem::transmute::<vk::PFN_vkDestroyBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyBufferCollectionFUCHSIA(pfn: vk::PFN_vkDestroyBufferCollectionFUCHSIA) -> Hook { Hook::new(Device, "vkDestroyBufferCollectionFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkDestroyBufferCollectionFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyBufferView(pfn: vk::PFN_vkDestroyBufferView) -> Hook { Hook::new(Device, "vkDestroyBufferView", unsafe { mem::transmute::<vk::PFN_vkDestroyBufferView, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyCommandPool(pfn: vk::PFN_vkDestroyCommandPool) -> Hook { Hook::new(Device, "vkDestroyCommandPool", unsafe { mem::transmute::<vk::PFN_vkDestroyCommandPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyCuFunctionNVX(pfn: vk::PFN_vkDestroyCuFunctionNVX) -> Hook { Hook::new(Device, "vkDestroyCuFunctionNVX", unsafe { mem::transmute::<vk::PFN_vkDestroyCuFunctionNVX, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyCuModuleNVX(pfn: vk::PFN_vkDestroyCuModuleNVX) -> Hook { Hook::new(Device, "vkDestroyCuModuleNVX", unsafe { mem::transmute::<vk::PFN_vkDestroyCuModuleNVX, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyDebugReportCallbackEXT(pfn: vk::PFN_vkDestroyDebugReportCallbackEXT) -> Hook { Hook::new(Instance, "vkDestroyDebugReportCallbackEXT", unsafe { mem::transmute::<vk::PFN_vkDestroyDebugReportCallbackEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyDebugUtilsMessengerEXT(pfn: vk::PFN_vkDestroyDebugUtilsMessengerEXT) -> Hook { Hook::new(Instance, "vkDestroyDebugUtilsMessengerEXT", unsafe { mem::transmute::<vk::PFN_vkDestroyDebugUtilsMessengerEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyDeferredOperationKHR(pfn: vk::PFN_vkDestroyDeferredOperationKHR) -> Hook { Hook::new(Device, "vkDestroyDeferredOperationKHR", unsafe { mem::transmute::<vk::PFN_vkDestroyDeferredOperationKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyDescriptorPool(pfn: vk::PFN_vkDestroyDescriptorPool) -> Hook { Hook::new(Device, "vkDestroyDescriptorPool", unsafe { mem::transmute::<vk::PFN_vkDestroyDescriptorPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyDescriptorSetLayout(pfn: vk::PFN_vkDestroyDescriptorSetLayout) -> Hook { Hook::new(Device, "vkDestroyDescriptorSetLayout", unsafe { mem::transmute::<vk::PFN_vkDestroyDescriptorSetLayout, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyDescriptorUpdateTemplate(pfn: vk::PFN_vkDestroyDescriptorUpdateTemplate) -> Hook { Hook::new(Device, "vkDestroyDescriptorUpdateTemplate", unsafe { mem::transmute::<vk::PFN_vkDestroyDescriptorUpdateTemplate, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyDescriptorUpdateTemplateKHR(pfn: vk::PFN_vkDestroyDescriptorUpdateTemplate) -> Hook { Hook::new(Device, "vkDestroyDescriptorUpdateTemplateKHR", unsafe { mem::transmute::<vk::PFN_vkDestroyDescriptorUpdateTemplate, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyDevice(pfn: vk::PFN_vkDestroyDevice) -> Hook { Hook::new(Device, "vkDestroyDevice", unsafe { mem::transmute::<vk::PFN_vkDestroyDevice, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyEvent(pfn: vk::PFN_vkDestroyEvent) -> Hook { Hook::new(Device, "vkDestroyEvent", unsafe { mem::transmute::<vk::PFN_vkDestroyEvent, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyFence(pfn: vk::PFN_vkDestroyFence) -> Hook { Hook::new(Device, "vkDestroyFence", unsafe { mem::transmute::<vk::PFN_vkDestroyFence, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyFramebuffer(pfn: vk::PFN_vkDestroyFramebuffer) -> Hook { Hook::new(Device, "vkDestroyFramebuffer", unsafe { mem::transmute::<vk::PFN_vkDestroyFramebuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyImage(pfn: vk::PFN_vkDestroyImage) -> Hook { Hook::new(Device, "vkDestroyImage", unsafe { mem::transmute::<vk::PFN_vkDestroyImage, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyImageView(pfn: vk::PFN_vkDestroyImageView) -> Hook { Hook::new(Device, "vkDestroyImageView", unsafe { mem::transmute::<vk::PFN_vkDestroyImageView, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyIndirectCommandsLayoutNV(pfn: vk::PFN_vkDestroyIndirectCommandsLayoutNV) -> Hook { Hook::new(Device, "vkDestroyIndirectCommandsLayoutNV", unsafe { mem::transmute::<vk::PFN_vkDestroyIndirectCommandsLayoutNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyInstance(pfn: vk::PFN_vkDestroyInstance) -> Hook { Hook::new(Instance, "vkDestroyInstance", unsafe { mem::transmute::<vk::PFN_vkDestroyInstance, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyMicromapEXT(pfn: vk::PFN_vkDestroyMicromapEXT) -> Hook { Hook::new(Device, "vkDestroyMicromapEXT", unsafe { mem::transmute::<vk::PFN_vkDestroyMicromapEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyOpticalFlowSessionNV(pfn: vk::PFN_vkDestroyOpticalFlowSessionNV) -> Hook { Hook::new(Device, "vkDestroyOpticalFlowSessionNV", unsafe { mem::transmute::<vk::PFN_vkDestroyOpticalFlowSessionNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyPipeline(pfn: vk::PFN_vkDestroyPipeline) -> Hook { Hook::new(Device, "vkDestroyPipeline", unsafe { mem::transmute::<vk::PFN_vkDestroyPipeline, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyPipelineCache(pfn: vk::PFN_vkDestroyPipelineCache) -> Hook { Hook::new(Device, "vkDestroyPipelineCache", unsafe { mem::transmute::<vk::PFN_vkDestroyPipelineCache, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyPipelineLayout(pfn: vk::PFN_vkDestroyPipelineLayout) -> Hook { Hook::new(Device, "vkDestroyPipelineLayout", unsafe { mem::transmute::<vk::PFN_vkDestroyPipelineLayout, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyPrivateDataSlot(pfn: vk::PFN_vkDestroyPrivateDataSlot) -> Hook { Hook::new(Device, "vkDestroyPrivateDataSlot", unsafe { mem::transmute::<vk::PFN_vkDestroyPrivateDataSlot, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyPrivateDataSlotEXT(pfn: vk::PFN_vkDestroyPrivateDataSlot) -> Hook { Hook::new(Device, "vkDestroyPrivateDataSlotEXT", unsafe { mem::transmute::<vk::PFN_vkDestroyPrivateDataSlot, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyQueryPool(pfn: vk::PFN_vkDestroyQueryPool) -> Hook { Hook::new(Device, "vkDestroyQueryPool", unsafe { mem::transmute::<vk::PFN_vkDestroyQueryPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyRenderPass(pfn: vk::PFN_vkDestroyRenderPass) -> Hook { Hook::new(Device, "vkDestroyRenderPass", unsafe { mem::transmute::<vk::PFN_vkDestroyRenderPass, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroySampler(pfn: vk::PFN_vkDestroySampler) -> Hook { Hook::new(Device, "vkDestroySampler", unsafe { mem::transmute::<vk::PFN_vkDestroySampler, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroySamplerYcbcrConversion(pfn: vk::PFN_vkDestroySamplerYcbcrConversion) -> Hook { Hook::new(Device, "vkDestroySamplerYcbcrConversion", unsafe { mem::transmute::<vk::PFN_vkDestroySamplerYcbcrConversion, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroySamplerYcbcrConversionKHR(pfn: vk::PFN_vkDestroySamplerYcbcrConversion) -> Hook { Hook::new(Device, "vkDestroySamplerYcbcrConversionKHR", unsafe { mem::transmute::<vk::PFN_vkDestroySamplerYcbcrConversion, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroySemaphore(pfn: vk::PFN_vkDestroySemaphore) -> Hook { Hook::new(Device, "vkDestroySemaphore", unsafe { mem::transmute::<vk::PFN_vkDestroySemaphore, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyShaderEXT(pfn: vk::PFN_vkDestroyShaderEXT) -> Hook { Hook::new(Device, "vkDestroyShaderEXT", unsafe { mem::transmute::<vk::PFN_vkDestroyShaderEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyShaderModule(pfn: vk::PFN_vkDestroyShaderModule) -> Hook { Hook::new(Device, "vkDestroyShaderModule", unsafe { mem::transmute::<vk::PFN_vkDestroyShaderModule, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroySurfaceKHR(pfn: vk::PFN_vkDestroySurfaceKHR) -> Hook { Hook::new(Instance, "vkDestroySurfaceKHR", unsafe { mem::transmute::<vk::PFN_vkDestroySurfaceKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroySwapchainKHR(pfn: vk::PFN_vkDestroySwapchainKHR) -> Hook { Hook::new(Device, "vkDestroySwapchainKHR", unsafe { mem::transmute::<vk::PFN_vkDestroySwapchainKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyValidationCacheEXT(pfn: vk::PFN_vkDestroyValidationCacheEXT) -> Hook { Hook::new(Device, "vkDestroyValidationCacheEXT", unsafe { mem::transmute::<vk::PFN_vkDestroyValidationCacheEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyVideoSessionKHR(pfn: vk::PFN_vkDestroyVideoSessionKHR) -> Hook { Hook::new(Device, "vkDestroyVideoSessionKHR", unsafe { mem::transmute::<vk::PFN_vkDestroyVideoSessionKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDestroyVideoSessionParametersKHR(pfn: vk::PFN_vkDestroyVideoSessionParametersKHR) -> Hook { Hook::new(Device, "vkDestroyVideoSessionParametersKHR", unsafe { mem::transmute::<vk::PFN_vkDestroyVideoSessionParametersKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDeviceWaitIdle(pfn: vk::PFN_vkDeviceWaitIdle) -> Hook { Hook::new(Device, "vkDeviceWaitIdle", unsafe { mem::transmute::<vk::PFN_vkDeviceWaitIdle, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkDisplayPowerControlEXT(pfn: vk::PFN_vkDisplayPowerControlEXT) -> Hook { Hook::new(Device, "vkDisplayPowerControlEXT", unsafe { mem::transmute::<vk::PFN_vkDisplayPowerControlEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEndCommandBuffer(pfn: vk::PFN_vkEndCommandBuffer) -> Hook { Hook::new(Device, "vkEndCommandBuffer", unsafe { mem::transmute::<vk::PFN_vkEndCommandBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEnumerateDeviceExtensionProperties(pfn: vk::PFN_vkEnumerateDeviceExtensionProperties) -> Hook { Hook::new(PhysicalDevice, "vkEnumerateDeviceExtensionProperties", unsafe { mem::transmute::<vk::PFN_vkEnumerateDeviceExtensionProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEnumerateDeviceLayerProperties(pfn: vk::PFN_vkEnumerateDeviceLayerProperties) -> Hook { Hook::new(PhysicalDevice, "vkEnumerateDeviceLayerProperties", unsafe { mem::transmute::<vk::PFN_vkEnumerateDeviceLayerProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEnumerateInstanceExtensionProperties(pfn: vk::PFN_vkEnumerateInstanceExtensionProperties) -> Hook { Hook::new(Global, "vkEnumerateInstanceExtensionProperties", unsafe { mem::transmute::<vk::PFN_vkEnumerateInstanceExtensionProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEnumerateInstanceLayerProperties(pfn: vk::PFN_vkEnumerateInstanceLayerProperties) -> Hook { Hook::new(Global, "vkEnumerateInstanceLayerProperties", unsafe { mem::transmute::<vk::PFN_vkEnumerateInstanceLayerProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEnumerateInstanceVersion(pfn: vk::PFN_vkEnumerateInstanceVersion) -> Hook { Hook::new(Global, "vkEnumerateInstanceVersion", unsafe { mem::transmute::<vk::PFN_vkEnumerateInstanceVersion, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEnumeratePhysicalDeviceGroups(pfn: vk::PFN_vkEnumeratePhysicalDeviceGroups) -> Hook { Hook::new(Instance, "vkEnumeratePhysicalDeviceGroups", unsafe { mem::transmute::<vk::PFN_vkEnumeratePhysicalDeviceGroups, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEnumeratePhysicalDeviceGroupsKHR(pfn: vk::PFN_vkEnumeratePhysicalDeviceGroups) -> Hook { Hook::new(Instance, "vkEnumeratePhysicalDeviceGroupsKHR", unsafe { mem::transmute::<vk::PFN_vkEnumeratePhysicalDeviceGroups, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR(pfn: vk::PFN_vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR) -> Hook { Hook::new(PhysicalDevice, "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR", unsafe { mem::transmute::<vk::PFN_vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkEnumeratePhysicalDevices(pfn: vk::PFN_vkEnumeratePhysicalDevices) -> Hook { Hook::new(Instance, "vkEnumeratePhysicalDevices", unsafe { mem::transmute::<vk::PFN_vkEnumeratePhysicalDevices, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkExportMetalObjectsEXT(pfn: vk::PFN_vkExportMetalObjectsEXT) -> Hook { Hook::new(Device, "vkExportMetalObjectsEXT", unsafe { mem::transmute::<vk::PFN_vkExportMetalObjectsEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkFlushMappedMemoryRanges(pfn: vk::PFN_vkFlushMappedMemoryRanges) -> Hook { Hook::new(Device, "vkFlushMappedMemoryRanges", unsafe { mem::transmute::<vk::PFN_vkFlushMappedMemoryRanges, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkFreeCommandBuffers(pfn: vk::PFN_vkFreeCommandBuffers) -> Hook { Hook::new(Device, "vkFreeCommandBuffers", unsafe { mem::transmute::<vk::PFN_vkFreeCommandBuffers, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkFreeDescriptorSets(pfn: vk::PFN_vkFreeDescriptorSets) -> Hook { Hook::new(Device, "vkFreeDescriptorSets", unsafe { mem::transmute::<vk::PFN_vkFreeDescriptorSets, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkFreeMemory(pfn: vk::PFN_vkFreeMemory) -> Hook { Hook::new(Device, "vkFreeMemory", unsafe { mem::transmute::<vk::PFN_vkFreeMemory, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetAccelerationStructureBuildSizesKHR(pfn: vk::PFN_vkGetAccelerationStructureBuildSizesKHR) -> Hook { Hook::new(Device, "vkGetAccelerationStructureBuildSizesKHR", unsafe { mem::transmute::<vk::PFN_vkGetAccelerationStructureBuildSizesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetAccelerationStructureDeviceAddressKHR(pfn: vk::PFN_vkGetAccelerationStructureDeviceAddressKHR) -> Hook { Hook::new(Device, "vkGetAccelerationStructureDeviceAddressKHR", unsafe { mem::transmute::<vk::PFN_vkGetAccelerationStructureDeviceAddressKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetAccelerationStructureHandleNV(pfn: vk::PFN_vkGetAccelerationStructureHandleNV) -> Hook { Hook::new(Device, "vkGetAccelerationStructureHandleNV", unsafe { mem::transmute::<vk::PFN_vkGetAccelerationStructureHandleNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetAccelerationStructureMemoryRequirementsNV(pfn: vk::PFN_vkGetAccelerationStructureMemoryRequirementsNV) -> Hook { Hook::new(Device, "vkGetAccelerationStructureMemoryRequirementsNV", unsafe { mem::transmute::<vk::PFN_vkGetAccelerationStructureMemoryRequirementsNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT(pfn: vk::PFN_vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT) -> Hook { Hook::new(Device, "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT", unsafe { mem::transmute::<vk::PFN_vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetAndroidHardwareBufferPropertiesANDROID(pfn: vk::PFN_vkGetAndroidHardwareBufferPropertiesANDROID) -> Hook { Hook::new(Device, "vkGetAndroidHardwareBufferPropertiesANDROID", unsafe { mem::transmute::<vk::PFN_vkGetAndroidHardwareBufferPropertiesANDROID, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferCollectionPropertiesFUCHSIA(pfn: vk::PFN_vkGetBufferCollectionPropertiesFUCHSIA) -> Hook { Hook::new(Device, "vkGetBufferCollectionPropertiesFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkGetBufferCollectionPropertiesFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferDeviceAddress(pfn: vk::PFN_vkGetBufferDeviceAddress) -> Hook { Hook::new(Device, "vkGetBufferDeviceAddress", unsafe { mem::transmute::<vk::PFN_vkGetBufferDeviceAddress, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferDeviceAddressEXT(pfn: vk::PFN_vkGetBufferDeviceAddress) -> Hook { Hook::new(Device, "vkGetBufferDeviceAddressEXT", unsafe { mem::transmute::<vk::PFN_vkGetBufferDeviceAddress, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferDeviceAddressKHR(pfn: vk::PFN_vkGetBufferDeviceAddress) -> Hook { Hook::new(Device, "vkGetBufferDeviceAddressKHR", unsafe { mem::transmute::<vk::PFN_vkGetBufferDeviceAddress, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferMemoryRequirements(pfn: vk::PFN_vkGetBufferMemoryRequirements) -> Hook { Hook::new(Device, "vkGetBufferMemoryRequirements", unsafe { mem::transmute::<vk::PFN_vkGetBufferMemoryRequirements, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferMemoryRequirements2(pfn: vk::PFN_vkGetBufferMemoryRequirements2) -> Hook { Hook::new(Device, "vkGetBufferMemoryRequirements2", unsafe { mem::transmute::<vk::PFN_vkGetBufferMemoryRequirements2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferMemoryRequirements2KHR(pfn: vk::PFN_vkGetBufferMemoryRequirements2) -> Hook { Hook::new(Device, "vkGetBufferMemoryRequirements2KHR", unsafe { mem::transmute::<vk::PFN_vkGetBufferMemoryRequirements2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferOpaqueCaptureAddress(pfn: vk::PFN_vkGetBufferOpaqueCaptureAddress) -> Hook { Hook::new(Device, "vkGetBufferOpaqueCaptureAddress", unsafe { mem::transmute::<vk::PFN_vkGetBufferOpaqueCaptureAddress, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferOpaqueCaptureAddressKHR(pfn: vk::PFN_vkGetBufferOpaqueCaptureAddress) -> Hook { Hook::new(Device, "vkGetBufferOpaqueCaptureAddressKHR", unsafe { mem::transmute::<vk::PFN_vkGetBufferOpaqueCaptureAddress, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetBufferOpaqueCaptureDescriptorDataEXT(pfn: vk::PFN_vkGetBufferOpaqueCaptureDescriptorDataEXT) -> Hook { Hook::new(Device, "vkGetBufferOpaqueCaptureDescriptorDataEXT", unsafe { mem::transmute::<vk::PFN_vkGetBufferOpaqueCaptureDescriptorDataEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetCalibratedTimestampsEXT(pfn: vk::PFN_vkGetCalibratedTimestampsEXT) -> Hook { Hook::new(Device, "vkGetCalibratedTimestampsEXT", unsafe { mem::transmute::<vk::PFN_vkGetCalibratedTimestampsEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeferredOperationMaxConcurrencyKHR(pfn: vk::PFN_vkGetDeferredOperationMaxConcurrencyKHR) -> Hook { Hook::new(Device, "vkGetDeferredOperationMaxConcurrencyKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeferredOperationMaxConcurrencyKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeferredOperationResultKHR(pfn: vk::PFN_vkGetDeferredOperationResultKHR) -> Hook { Hook::new(Device, "vkGetDeferredOperationResultKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeferredOperationResultKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDescriptorEXT(pfn: vk::PFN_vkGetDescriptorEXT) -> Hook { Hook::new(Device, "vkGetDescriptorEXT", unsafe { mem::transmute::<vk::PFN_vkGetDescriptorEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDescriptorSetHostMappingVALVE(pfn: vk::PFN_vkGetDescriptorSetHostMappingVALVE) -> Hook { Hook::new(Device, "vkGetDescriptorSetHostMappingVALVE", unsafe { mem::transmute::<vk::PFN_vkGetDescriptorSetHostMappingVALVE, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDescriptorSetLayoutBindingOffsetEXT(pfn: vk::PFN_vkGetDescriptorSetLayoutBindingOffsetEXT) -> Hook { Hook::new(Device, "vkGetDescriptorSetLayoutBindingOffsetEXT", unsafe { mem::transmute::<vk::PFN_vkGetDescriptorSetLayoutBindingOffsetEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDescriptorSetLayoutHostMappingInfoVALVE(pfn: vk::PFN_vkGetDescriptorSetLayoutHostMappingInfoVALVE) -> Hook { Hook::new(Device, "vkGetDescriptorSetLayoutHostMappingInfoVALVE", unsafe { mem::transmute::<vk::PFN_vkGetDescriptorSetLayoutHostMappingInfoVALVE, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDescriptorSetLayoutSizeEXT(pfn: vk::PFN_vkGetDescriptorSetLayoutSizeEXT) -> Hook { Hook::new(Device, "vkGetDescriptorSetLayoutSizeEXT", unsafe { mem::transmute::<vk::PFN_vkGetDescriptorSetLayoutSizeEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDescriptorSetLayoutSupport(pfn: vk::PFN_vkGetDescriptorSetLayoutSupport) -> Hook { Hook::new(Device, "vkGetDescriptorSetLayoutSupport", unsafe { mem::transmute::<vk::PFN_vkGetDescriptorSetLayoutSupport, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDescriptorSetLayoutSupportKHR(pfn: vk::PFN_vkGetDescriptorSetLayoutSupport) -> Hook { Hook::new(Device, "vkGetDescriptorSetLayoutSupportKHR", unsafe { mem::transmute::<vk::PFN_vkGetDescriptorSetLayoutSupport, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceAccelerationStructureCompatibilityKHR(pfn: vk::PFN_vkGetDeviceAccelerationStructureCompatibilityKHR) -> Hook { Hook::new(Device, "vkGetDeviceAccelerationStructureCompatibilityKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeviceAccelerationStructureCompatibilityKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceBufferMemoryRequirements(pfn: vk::PFN_vkGetDeviceBufferMemoryRequirements) -> Hook { Hook::new(Device, "vkGetDeviceBufferMemoryRequirements", unsafe { mem::transmute::<vk::PFN_vkGetDeviceBufferMemoryRequirements, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceBufferMemoryRequirementsKHR(pfn: vk::PFN_vkGetDeviceBufferMemoryRequirements) -> Hook { Hook::new(Device, "vkGetDeviceBufferMemoryRequirementsKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeviceBufferMemoryRequirements, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceFaultInfoEXT(pfn: vk::PFN_vkGetDeviceFaultInfoEXT) -> Hook { Hook::new(Device, "vkGetDeviceFaultInfoEXT", unsafe { mem::transmute::<vk::PFN_vkGetDeviceFaultInfoEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceGroupPeerMemoryFeatures(pfn: vk::PFN_vkGetDeviceGroupPeerMemoryFeatures) -> Hook { Hook::new(Device, "vkGetDeviceGroupPeerMemoryFeatures", unsafe { mem::transmute::<vk::PFN_vkGetDeviceGroupPeerMemoryFeatures, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceGroupPeerMemoryFeaturesKHR(pfn: vk::PFN_vkGetDeviceGroupPeerMemoryFeatures) -> Hook { Hook::new(Device, "vkGetDeviceGroupPeerMemoryFeaturesKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeviceGroupPeerMemoryFeatures, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceGroupPresentCapabilitiesKHR(pfn: vk::PFN_vkGetDeviceGroupPresentCapabilitiesKHR) -> Hook { Hook::new(Device, "vkGetDeviceGroupPresentCapabilitiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeviceGroupPresentCapabilitiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceGroupSurfacePresentModes2EXT(pfn: vk::PFN_vkGetDeviceGroupSurfacePresentModes2EXT) -> Hook { Hook::new(Device, "vkGetDeviceGroupSurfacePresentModes2EXT", unsafe { mem::transmute::<vk::PFN_vkGetDeviceGroupSurfacePresentModes2EXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceGroupSurfacePresentModesKHR(pfn: vk::PFN_vkGetDeviceGroupSurfacePresentModesKHR) -> Hook { Hook::new(Device, "vkGetDeviceGroupSurfacePresentModesKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeviceGroupSurfacePresentModesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceImageMemoryRequirements(pfn: vk::PFN_vkGetDeviceImageMemoryRequirements) -> Hook { Hook::new(Device, "vkGetDeviceImageMemoryRequirements", unsafe { mem::transmute::<vk::PFN_vkGetDeviceImageMemoryRequirements, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceImageMemoryRequirementsKHR(pfn: vk::PFN_vkGetDeviceImageMemoryRequirements) -> Hook { Hook::new(Device, "vkGetDeviceImageMemoryRequirementsKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeviceImageMemoryRequirements, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceImageSparseMemoryRequirements(pfn: vk::PFN_vkGetDeviceImageSparseMemoryRequirements) -> Hook { Hook::new(Device, "vkGetDeviceImageSparseMemoryRequirements", unsafe { mem::transmute::<vk::PFN_vkGetDeviceImageSparseMemoryRequirements, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceImageSparseMemoryRequirementsKHR(pfn: vk::PFN_vkGetDeviceImageSparseMemoryRequirements) -> Hook { Hook::new(Device, "vkGetDeviceImageSparseMemoryRequirementsKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeviceImageSparseMemoryRequirements, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceMemoryCommitment(pfn: vk::PFN_vkGetDeviceMemoryCommitment) -> Hook { Hook::new(Device, "vkGetDeviceMemoryCommitment", unsafe { mem::transmute::<vk::PFN_vkGetDeviceMemoryCommitment, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceMemoryOpaqueCaptureAddress(pfn: vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress) -> Hook { Hook::new(Device, "vkGetDeviceMemoryOpaqueCaptureAddress", unsafe { mem::transmute::<vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceMemoryOpaqueCaptureAddressKHR(pfn: vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress) -> Hook { Hook::new(Device, "vkGetDeviceMemoryOpaqueCaptureAddressKHR", unsafe { mem::transmute::<vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceMicromapCompatibilityEXT(pfn: vk::PFN_vkGetDeviceMicromapCompatibilityEXT) -> Hook { Hook::new(Device, "vkGetDeviceMicromapCompatibilityEXT", unsafe { mem::transmute::<vk::PFN_vkGetDeviceMicromapCompatibilityEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceProcAddr(pfn: vk::PFN_vkGetDeviceProcAddr) -> Hook { Hook::new(Device, "vkGetDeviceProcAddr", unsafe { mem::transmute::<vk::PFN_vkGetDeviceProcAddr, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceQueue(pfn: vk::PFN_vkGetDeviceQueue) -> Hook { Hook::new(Device, "vkGetDeviceQueue", unsafe { mem::transmute::<vk::PFN_vkGetDeviceQueue, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceQueue2(pfn: vk::PFN_vkGetDeviceQueue2) -> Hook { Hook::new(Device, "vkGetDeviceQueue2", unsafe { mem::transmute::<vk::PFN_vkGetDeviceQueue2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI(pfn: vk::PFN_vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI) -> Hook { Hook::new(Device, "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI", unsafe { mem::transmute::<vk::PFN_vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDisplayModeProperties2KHR(pfn: vk::PFN_vkGetDisplayModeProperties2KHR) -> Hook { Hook::new(PhysicalDevice, "vkGetDisplayModeProperties2KHR", unsafe { mem::transmute::<vk::PFN_vkGetDisplayModeProperties2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDisplayModePropertiesKHR(pfn: vk::PFN_vkGetDisplayModePropertiesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetDisplayModePropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetDisplayModePropertiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDisplayPlaneCapabilities2KHR(pfn: vk::PFN_vkGetDisplayPlaneCapabilities2KHR) -> Hook { Hook::new(PhysicalDevice, "vkGetDisplayPlaneCapabilities2KHR", unsafe { mem::transmute::<vk::PFN_vkGetDisplayPlaneCapabilities2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDisplayPlaneCapabilitiesKHR(pfn: vk::PFN_vkGetDisplayPlaneCapabilitiesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetDisplayPlaneCapabilitiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetDisplayPlaneCapabilitiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDisplayPlaneSupportedDisplaysKHR(pfn: vk::PFN_vkGetDisplayPlaneSupportedDisplaysKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetDisplayPlaneSupportedDisplaysKHR", unsafe { mem::transmute::<vk::PFN_vkGetDisplayPlaneSupportedDisplaysKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDrmDisplayEXT(pfn: vk::PFN_vkGetDrmDisplayEXT) -> Hook { Hook::new(PhysicalDevice, "vkGetDrmDisplayEXT", unsafe { mem::transmute::<vk::PFN_vkGetDrmDisplayEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetDynamicRenderingTilePropertiesQCOM(pfn: vk::PFN_vkGetDynamicRenderingTilePropertiesQCOM) -> Hook { Hook::new(Device, "vkGetDynamicRenderingTilePropertiesQCOM", unsafe { mem::transmute::<vk::PFN_vkGetDynamicRenderingTilePropertiesQCOM, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetEventStatus(pfn: vk::PFN_vkGetEventStatus) -> Hook { Hook::new(Device, "vkGetEventStatus", unsafe { mem::transmute::<vk::PFN_vkGetEventStatus, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetFenceFdKHR(pfn: vk::PFN_vkGetFenceFdKHR) -> Hook { Hook::new(Device, "vkGetFenceFdKHR", unsafe { mem::transmute::<vk::PFN_vkGetFenceFdKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetFenceStatus(pfn: vk::PFN_vkGetFenceStatus) -> Hook { Hook::new(Device, "vkGetFenceStatus", unsafe { mem::transmute::<vk::PFN_vkGetFenceStatus, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetFenceWin32HandleKHR(pfn: vk::PFN_vkGetFenceWin32HandleKHR) -> Hook { Hook::new(Device, "vkGetFenceWin32HandleKHR", unsafe { mem::transmute::<vk::PFN_vkGetFenceWin32HandleKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetFramebufferTilePropertiesQCOM(pfn: vk::PFN_vkGetFramebufferTilePropertiesQCOM) -> Hook { Hook::new(Device, "vkGetFramebufferTilePropertiesQCOM", unsafe { mem::transmute::<vk::PFN_vkGetFramebufferTilePropertiesQCOM, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetGeneratedCommandsMemoryRequirementsNV(pfn: vk::PFN_vkGetGeneratedCommandsMemoryRequirementsNV) -> Hook { Hook::new(Device, "vkGetGeneratedCommandsMemoryRequirementsNV", unsafe { mem::transmute::<vk::PFN_vkGetGeneratedCommandsMemoryRequirementsNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageDrmFormatModifierPropertiesEXT(pfn: vk::PFN_vkGetImageDrmFormatModifierPropertiesEXT) -> Hook { Hook::new(Device, "vkGetImageDrmFormatModifierPropertiesEXT", unsafe { mem::transmute::<vk::PFN_vkGetImageDrmFormatModifierPropertiesEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageMemoryRequirements(pfn: vk::PFN_vkGetImageMemoryRequirements) -> Hook { Hook::new(Device, "vkGetImageMemoryRequirements", unsafe { mem::transmute::<vk::PFN_vkGetImageMemoryRequirements, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageMemoryRequirements2(pfn: vk::PFN_vkGetImageMemoryRequirements2) -> Hook { Hook::new(Device, "vkGetImageMemoryRequirements2", unsafe { mem::transmute::<vk::PFN_vkGetImageMemoryRequirements2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageMemoryRequirements2KHR(pfn: vk::PFN_vkGetImageMemoryRequirements2) -> Hook { Hook::new(Device, "vkGetImageMemoryRequirements2KHR", unsafe { mem::transmute::<vk::PFN_vkGetImageMemoryRequirements2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageOpaqueCaptureDescriptorDataEXT(pfn: vk::PFN_vkGetImageOpaqueCaptureDescriptorDataEXT) -> Hook { Hook::new(Device, "vkGetImageOpaqueCaptureDescriptorDataEXT", unsafe { mem::transmute::<vk::PFN_vkGetImageOpaqueCaptureDescriptorDataEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageSparseMemoryRequirements(pfn: vk::PFN_vkGetImageSparseMemoryRequirements) -> Hook { Hook::new(Device, "vkGetImageSparseMemoryRequirements", unsafe { mem::transmute::<vk::PFN_vkGetImageSparseMemoryRequirements, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageSparseMemoryRequirements2(pfn: vk::PFN_vkGetImageSparseMemoryRequirements2) -> Hook { Hook::new(Device, "vkGetImageSparseMemoryRequirements2", unsafe { mem::transmute::<vk::PFN_vkGetImageSparseMemoryRequirements2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageSparseMemoryRequirements2KHR(pfn: vk::PFN_vkGetImageSparseMemoryRequirements2) -> Hook { Hook::new(Device, "vkGetImageSparseMemoryRequirements2KHR", unsafe { mem::transmute::<vk::PFN_vkGetImageSparseMemoryRequirements2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageSubresourceLayout(pfn: vk::PFN_vkGetImageSubresourceLayout) -> Hook { Hook::new(Device, "vkGetImageSubresourceLayout", unsafe { mem::transmute::<vk::PFN_vkGetImageSubresourceLayout, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageSubresourceLayout2EXT(pfn: vk::PFN_vkGetImageSubresourceLayout2EXT) -> Hook { Hook::new(Device, "vkGetImageSubresourceLayout2EXT", unsafe { mem::transmute::<vk::PFN_vkGetImageSubresourceLayout2EXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageViewAddressNVX(pfn: vk::PFN_vkGetImageViewAddressNVX) -> Hook { Hook::new(Device, "vkGetImageViewAddressNVX", unsafe { mem::transmute::<vk::PFN_vkGetImageViewAddressNVX, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageViewHandleNVX(pfn: vk::PFN_vkGetImageViewHandleNVX) -> Hook { Hook::new(Device, "vkGetImageViewHandleNVX", unsafe { mem::transmute::<vk::PFN_vkGetImageViewHandleNVX, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetImageViewOpaqueCaptureDescriptorDataEXT(pfn: vk::PFN_vkGetImageViewOpaqueCaptureDescriptorDataEXT) -> Hook { Hook::new(Device, "vkGetImageViewOpaqueCaptureDescriptorDataEXT", unsafe { mem::transmute::<vk::PFN_vkGetImageViewOpaqueCaptureDescriptorDataEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetInstanceProcAddr(pfn: vk::PFN_vkGetInstanceProcAddr) -> Hook { Hook::new(Instance, "vkGetInstanceProcAddr", unsafe { mem::transmute::<vk::PFN_vkGetInstanceProcAddr, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryAndroidHardwareBufferANDROID(pfn: vk::PFN_vkGetMemoryAndroidHardwareBufferANDROID) -> Hook { Hook::new(Device, "vkGetMemoryAndroidHardwareBufferANDROID", unsafe { mem::transmute::<vk::PFN_vkGetMemoryAndroidHardwareBufferANDROID, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryFdKHR(pfn: vk::PFN_vkGetMemoryFdKHR) -> Hook { Hook::new(Device, "vkGetMemoryFdKHR", unsafe { mem::transmute::<vk::PFN_vkGetMemoryFdKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryFdPropertiesKHR(pfn: vk::PFN_vkGetMemoryFdPropertiesKHR) -> Hook { Hook::new(Device, "vkGetMemoryFdPropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetMemoryFdPropertiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryHostPointerPropertiesEXT(pfn: vk::PFN_vkGetMemoryHostPointerPropertiesEXT) -> Hook { Hook::new(Device, "vkGetMemoryHostPointerPropertiesEXT", unsafe { mem::transmute::<vk::PFN_vkGetMemoryHostPointerPropertiesEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryRemoteAddressNV(pfn: vk::PFN_vkGetMemoryRemoteAddressNV) -> Hook { Hook::new(Device, "vkGetMemoryRemoteAddressNV", unsafe { mem::transmute::<vk::PFN_vkGetMemoryRemoteAddressNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryWin32HandleKHR(pfn: vk::PFN_vkGetMemoryWin32HandleKHR) -> Hook { Hook::new(Device, "vkGetMemoryWin32HandleKHR", unsafe { mem::transmute::<vk::PFN_vkGetMemoryWin32HandleKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryWin32HandleNV(pfn: vk::PFN_vkGetMemoryWin32HandleNV) -> Hook { Hook::new(Device, "vkGetMemoryWin32HandleNV", unsafe { mem::transmute::<vk::PFN_vkGetMemoryWin32HandleNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryWin32HandlePropertiesKHR(pfn: vk::PFN_vkGetMemoryWin32HandlePropertiesKHR) -> Hook { Hook::new(Device, "vkGetMemoryWin32HandlePropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetMemoryWin32HandlePropertiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryZirconHandleFUCHSIA(pfn: vk::PFN_vkGetMemoryZirconHandleFUCHSIA) -> Hook { Hook::new(Device, "vkGetMemoryZirconHandleFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkGetMemoryZirconHandleFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMemoryZirconHandlePropertiesFUCHSIA(pfn: vk::PFN_vkGetMemoryZirconHandlePropertiesFUCHSIA) -> Hook { Hook::new(Device, "vkGetMemoryZirconHandlePropertiesFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkGetMemoryZirconHandlePropertiesFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetMicromapBuildSizesEXT(pfn: vk::PFN_vkGetMicromapBuildSizesEXT) -> Hook { Hook::new(Device, "vkGetMicromapBuildSizesEXT", unsafe { mem::transmute::<vk::PFN_vkGetMicromapBuildSizesEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPastPresentationTimingGOOGLE(pfn: vk::PFN_vkGetPastPresentationTimingGOOGLE) -> Hook { Hook::new(Device, "vkGetPastPresentationTimingGOOGLE", unsafe { mem::transmute::<vk::PFN_vkGetPastPresentationTimingGOOGLE, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPerformanceParameterINTEL(pfn: vk::PFN_vkGetPerformanceParameterINTEL) -> Hook { Hook::new(Device, "vkGetPerformanceParameterINTEL", unsafe { mem::transmute::<vk::PFN_vkGetPerformanceParameterINTEL, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceCalibrateableTimeDomainsEXT(pfn: vk::PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceCooperativeMatrixPropertiesNV(pfn: vk::PFN_vkGetPhysicalDeviceCooperativeMatrixPropertiesNV) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceCooperativeMatrixPropertiesNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceDirectFBPresentationSupportEXT(pfn: vk::PFN_vkGetPhysicalDeviceDirectFBPresentationSupportEXT) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceDirectFBPresentationSupportEXT", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceDirectFBPresentationSupportEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceDisplayPlaneProperties2KHR(pfn: vk::PFN_vkGetPhysicalDeviceDisplayPlaneProperties2KHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceDisplayPlaneProperties2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceDisplayPlaneProperties2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceDisplayPlanePropertiesKHR(pfn: vk::PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceDisplayPlanePropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceDisplayProperties2KHR(pfn: vk::PFN_vkGetPhysicalDeviceDisplayProperties2KHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceDisplayProperties2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceDisplayProperties2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceDisplayPropertiesKHR(pfn: vk::PFN_vkGetPhysicalDeviceDisplayPropertiesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceDisplayPropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceDisplayPropertiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceExternalBufferProperties(pfn: vk::PFN_vkGetPhysicalDeviceExternalBufferProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceExternalBufferProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceExternalBufferProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceExternalBufferPropertiesKHR(pfn: vk::PFN_vkGetPhysicalDeviceExternalBufferProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceExternalBufferPropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceExternalBufferProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceExternalFenceProperties(pfn: vk::PFN_vkGetPhysicalDeviceExternalFenceProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceExternalFenceProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceExternalFenceProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceExternalFencePropertiesKHR(pfn: vk::PFN_vkGetPhysicalDeviceExternalFenceProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceExternalFencePropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceExternalFenceProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceExternalImageFormatPropertiesNV(pfn: vk::PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceExternalImageFormatPropertiesNV", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceExternalSemaphoreProperties(pfn: vk::PFN_vkGetPhysicalDeviceExternalSemaphoreProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceExternalSemaphoreProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceExternalSemaphoreProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceExternalSemaphorePropertiesKHR(pfn: vk::PFN_vkGetPhysicalDeviceExternalSemaphoreProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceExternalSemaphoreProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceFeatures(pfn: vk::PFN_vkGetPhysicalDeviceFeatures) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceFeatures", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceFeatures, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceFeatures2(pfn: vk::PFN_vkGetPhysicalDeviceFeatures2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceFeatures2", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceFeatures2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceFeatures2KHR(pfn: vk::PFN_vkGetPhysicalDeviceFeatures2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceFeatures2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceFeatures2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceFormatProperties(pfn: vk::PFN_vkGetPhysicalDeviceFormatProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceFormatProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceFormatProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceFormatProperties2(pfn: vk::PFN_vkGetPhysicalDeviceFormatProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceFormatProperties2", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceFormatProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceFormatProperties2KHR(pfn: vk::PFN_vkGetPhysicalDeviceFormatProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceFormatProperties2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceFormatProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceFragmentShadingRatesKHR(pfn: vk::PFN_vkGetPhysicalDeviceFragmentShadingRatesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceFragmentShadingRatesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceFragmentShadingRatesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceImageFormatProperties(pfn: vk::PFN_vkGetPhysicalDeviceImageFormatProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceImageFormatProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceImageFormatProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceImageFormatProperties2(pfn: vk::PFN_vkGetPhysicalDeviceImageFormatProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceImageFormatProperties2", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceImageFormatProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceImageFormatProperties2KHR(pfn: vk::PFN_vkGetPhysicalDeviceImageFormatProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceImageFormatProperties2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceImageFormatProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceMemoryProperties(pfn: vk::PFN_vkGetPhysicalDeviceMemoryProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceMemoryProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceMemoryProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceMemoryProperties2(pfn: vk::PFN_vkGetPhysicalDeviceMemoryProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceMemoryProperties2", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceMemoryProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceMemoryProperties2KHR(pfn: vk::PFN_vkGetPhysicalDeviceMemoryProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceMemoryProperties2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceMemoryProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceMultisamplePropertiesEXT(pfn: vk::PFN_vkGetPhysicalDeviceMultisamplePropertiesEXT) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceMultisamplePropertiesEXT", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceMultisamplePropertiesEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceOpticalFlowImageFormatsNV(pfn: vk::PFN_vkGetPhysicalDeviceOpticalFlowImageFormatsNV) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceOpticalFlowImageFormatsNV", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceOpticalFlowImageFormatsNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDevicePresentRectanglesKHR(pfn: vk::PFN_vkGetPhysicalDevicePresentRectanglesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDevicePresentRectanglesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDevicePresentRectanglesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceProperties(pfn: vk::PFN_vkGetPhysicalDeviceProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceProperties2(pfn: vk::PFN_vkGetPhysicalDeviceProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceProperties2", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceProperties2KHR(pfn: vk::PFN_vkGetPhysicalDeviceProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceProperties2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR(pfn: vk::PFN_vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceQueueFamilyProperties(pfn: vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceQueueFamilyProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceQueueFamilyProperties2(pfn: vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceQueueFamilyProperties2", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceQueueFamilyProperties2KHR(pfn: vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceQueueFamilyProperties2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceScreenPresentationSupportQNX(pfn: vk::PFN_vkGetPhysicalDeviceScreenPresentationSupportQNX) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceScreenPresentationSupportQNX", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceScreenPresentationSupportQNX, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSparseImageFormatProperties(pfn: vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSparseImageFormatProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSparseImageFormatProperties2(pfn: vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSparseImageFormatProperties2", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSparseImageFormatProperties2KHR(pfn: vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties2) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSparseImageFormatProperties2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV(pfn: vk::PFN_vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSurfaceCapabilities2EXT(pfn: vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSurfaceCapabilities2EXT", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSurfaceCapabilities2KHR(pfn: vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSurfaceCapabilities2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSurfaceCapabilitiesKHR(pfn: vk::PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSurfaceCapabilitiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSurfaceFormats2KHR(pfn: vk::PFN_vkGetPhysicalDeviceSurfaceFormats2KHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSurfaceFormats2KHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSurfaceFormats2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSurfaceFormatsKHR(pfn: vk::PFN_vkGetPhysicalDeviceSurfaceFormatsKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSurfaceFormatsKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSurfaceFormatsKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSurfacePresentModes2EXT(pfn: vk::PFN_vkGetPhysicalDeviceSurfacePresentModes2EXT) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSurfacePresentModes2EXT", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSurfacePresentModes2EXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSurfacePresentModesKHR(pfn: vk::PFN_vkGetPhysicalDeviceSurfacePresentModesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSurfacePresentModesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSurfacePresentModesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceSurfaceSupportKHR(pfn: vk::PFN_vkGetPhysicalDeviceSurfaceSupportKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceSurfaceSupportKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceSurfaceSupportKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceToolProperties(pfn: vk::PFN_vkGetPhysicalDeviceToolProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceToolProperties", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceToolProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceToolPropertiesEXT(pfn: vk::PFN_vkGetPhysicalDeviceToolProperties) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceToolPropertiesEXT", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceToolProperties, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceVideoCapabilitiesKHR(pfn: vk::PFN_vkGetPhysicalDeviceVideoCapabilitiesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceVideoCapabilitiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceVideoCapabilitiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceVideoFormatPropertiesKHR(pfn: vk::PFN_vkGetPhysicalDeviceVideoFormatPropertiesKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceVideoFormatPropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceVideoFormatPropertiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceWaylandPresentationSupportKHR(pfn: vk::PFN_vkGetPhysicalDeviceWaylandPresentationSupportKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceWaylandPresentationSupportKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceWaylandPresentationSupportKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceWin32PresentationSupportKHR(pfn: vk::PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceWin32PresentationSupportKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceXcbPresentationSupportKHR(pfn: vk::PFN_vkGetPhysicalDeviceXcbPresentationSupportKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceXcbPresentationSupportKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceXcbPresentationSupportKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPhysicalDeviceXlibPresentationSupportKHR(pfn: vk::PFN_vkGetPhysicalDeviceXlibPresentationSupportKHR) -> Hook { Hook::new(PhysicalDevice, "vkGetPhysicalDeviceXlibPresentationSupportKHR", unsafe { mem::transmute::<vk::PFN_vkGetPhysicalDeviceXlibPresentationSupportKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPipelineCacheData(pfn: vk::PFN_vkGetPipelineCacheData) -> Hook { Hook::new(Device, "vkGetPipelineCacheData", unsafe { mem::transmute::<vk::PFN_vkGetPipelineCacheData, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPipelineExecutableInternalRepresentationsKHR(pfn: vk::PFN_vkGetPipelineExecutableInternalRepresentationsKHR) -> Hook { Hook::new(Device, "vkGetPipelineExecutableInternalRepresentationsKHR", unsafe { mem::transmute::<vk::PFN_vkGetPipelineExecutableInternalRepresentationsKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPipelineExecutablePropertiesKHR(pfn: vk::PFN_vkGetPipelineExecutablePropertiesKHR) -> Hook { Hook::new(Device, "vkGetPipelineExecutablePropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkGetPipelineExecutablePropertiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPipelineExecutableStatisticsKHR(pfn: vk::PFN_vkGetPipelineExecutableStatisticsKHR) -> Hook { Hook::new(Device, "vkGetPipelineExecutableStatisticsKHR", unsafe { mem::transmute::<vk::PFN_vkGetPipelineExecutableStatisticsKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPipelinePropertiesEXT(pfn: vk::PFN_vkGetPipelinePropertiesEXT) -> Hook { Hook::new(Device, "vkGetPipelinePropertiesEXT", unsafe { mem::transmute::<vk::PFN_vkGetPipelinePropertiesEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPrivateData(pfn: vk::PFN_vkGetPrivateData) -> Hook { Hook::new(Device, "vkGetPrivateData", unsafe { mem::transmute::<vk::PFN_vkGetPrivateData, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetPrivateDataEXT(pfn: vk::PFN_vkGetPrivateData) -> Hook { Hook::new(Device, "vkGetPrivateDataEXT", unsafe { mem::transmute::<vk::PFN_vkGetPrivateData, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetQueryPoolResults(pfn: vk::PFN_vkGetQueryPoolResults) -> Hook { Hook::new(Device, "vkGetQueryPoolResults", unsafe { mem::transmute::<vk::PFN_vkGetQueryPoolResults, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetQueueCheckpointData2NV(pfn: vk::PFN_vkGetQueueCheckpointData2NV) -> Hook { Hook::new(Device, "vkGetQueueCheckpointData2NV", unsafe { mem::transmute::<vk::PFN_vkGetQueueCheckpointData2NV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetQueueCheckpointDataNV(pfn: vk::PFN_vkGetQueueCheckpointDataNV) -> Hook { Hook::new(Device, "vkGetQueueCheckpointDataNV", unsafe { mem::transmute::<vk::PFN_vkGetQueueCheckpointDataNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetRandROutputDisplayEXT(pfn: vk::PFN_vkGetRandROutputDisplayEXT) -> Hook { Hook::new(PhysicalDevice, "vkGetRandROutputDisplayEXT", unsafe { mem::transmute::<vk::PFN_vkGetRandROutputDisplayEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetRayTracingCaptureReplayShaderGroupHandlesKHR(pfn: vk::PFN_vkGetRayTracingCaptureReplayShaderGroupHandlesKHR) -> Hook { Hook::new(Device, "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR", unsafe { mem::transmute::<vk::PFN_vkGetRayTracingCaptureReplayShaderGroupHandlesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetRayTracingShaderGroupHandlesKHR(pfn: vk::PFN_vkGetRayTracingShaderGroupHandlesKHR) -> Hook { Hook::new(Device, "vkGetRayTracingShaderGroupHandlesKHR", unsafe { mem::transmute::<vk::PFN_vkGetRayTracingShaderGroupHandlesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetRayTracingShaderGroupHandlesNV(pfn: vk::PFN_vkGetRayTracingShaderGroupHandlesKHR) -> Hook { Hook::new(Device, "vkGetRayTracingShaderGroupHandlesNV", unsafe { mem::transmute::<vk::PFN_vkGetRayTracingShaderGroupHandlesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetRayTracingShaderGroupStackSizeKHR(pfn: vk::PFN_vkGetRayTracingShaderGroupStackSizeKHR) -> Hook { Hook::new(Device, "vkGetRayTracingShaderGroupStackSizeKHR", unsafe { mem::transmute::<vk::PFN_vkGetRayTracingShaderGroupStackSizeKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetRefreshCycleDurationGOOGLE(pfn: vk::PFN_vkGetRefreshCycleDurationGOOGLE) -> Hook { Hook::new(Device, "vkGetRefreshCycleDurationGOOGLE", unsafe { mem::transmute::<vk::PFN_vkGetRefreshCycleDurationGOOGLE, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetRenderAreaGranularity(pfn: vk::PFN_vkGetRenderAreaGranularity) -> Hook { Hook::new(Device, "vkGetRenderAreaGranularity", unsafe { mem::transmute::<vk::PFN_vkGetRenderAreaGranularity, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetSamplerOpaqueCaptureDescriptorDataEXT(pfn: vk::PFN_vkGetSamplerOpaqueCaptureDescriptorDataEXT) -> Hook { Hook::new(Device, "vkGetSamplerOpaqueCaptureDescriptorDataEXT", unsafe { mem::transmute::<vk::PFN_vkGetSamplerOpaqueCaptureDescriptorDataEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetSemaphoreCounterValue(pfn: vk::PFN_vkGetSemaphoreCounterValue) -> Hook { Hook::new(Device, "vkGetSemaphoreCounterValue", unsafe { mem::transmute::<vk::PFN_vkGetSemaphoreCounterValue, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetSemaphoreCounterValueKHR(pfn: vk::PFN_vkGetSemaphoreCounterValue) -> Hook { Hook::new(Device, "vkGetSemaphoreCounterValueKHR", unsafe { mem::transmute::<vk::PFN_vkGetSemaphoreCounterValue, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetSemaphoreFdKHR(pfn: vk::PFN_vkGetSemaphoreFdKHR) -> Hook { Hook::new(Device, "vkGetSemaphoreFdKHR", unsafe { mem::transmute::<vk::PFN_vkGetSemaphoreFdKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetSemaphoreWin32HandleKHR(pfn: vk::PFN_vkGetSemaphoreWin32HandleKHR) -> Hook { Hook::new(Device, "vkGetSemaphoreWin32HandleKHR", unsafe { mem::transmute::<vk::PFN_vkGetSemaphoreWin32HandleKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetSemaphoreZirconHandleFUCHSIA(pfn: vk::PFN_vkGetSemaphoreZirconHandleFUCHSIA) -> Hook { Hook::new(Device, "vkGetSemaphoreZirconHandleFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkGetSemaphoreZirconHandleFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetShaderBinaryDataEXT(pfn: vk::PFN_vkGetShaderBinaryDataEXT) -> Hook { Hook::new(Device, "vkGetShaderBinaryDataEXT", unsafe { mem::transmute::<vk::PFN_vkGetShaderBinaryDataEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetShaderInfoAMD(pfn: vk::PFN_vkGetShaderInfoAMD) -> Hook { Hook::new(Device, "vkGetShaderInfoAMD", unsafe { mem::transmute::<vk::PFN_vkGetShaderInfoAMD, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetShaderModuleCreateInfoIdentifierEXT(pfn: vk::PFN_vkGetShaderModuleCreateInfoIdentifierEXT) -> Hook { Hook::new(Device, "vkGetShaderModuleCreateInfoIdentifierEXT", unsafe { mem::transmute::<vk::PFN_vkGetShaderModuleCreateInfoIdentifierEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetShaderModuleIdentifierEXT(pfn: vk::PFN_vkGetShaderModuleIdentifierEXT) -> Hook { Hook::new(Device, "vkGetShaderModuleIdentifierEXT", unsafe { mem::transmute::<vk::PFN_vkGetShaderModuleIdentifierEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetSwapchainCounterEXT(pfn: vk::PFN_vkGetSwapchainCounterEXT) -> Hook { Hook::new(Device, "vkGetSwapchainCounterEXT", unsafe { mem::transmute::<vk::PFN_vkGetSwapchainCounterEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetSwapchainImagesKHR(pfn: vk::PFN_vkGetSwapchainImagesKHR) -> Hook { Hook::new(Device, "vkGetSwapchainImagesKHR", unsafe { mem::transmute::<vk::PFN_vkGetSwapchainImagesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetSwapchainStatusKHR(pfn: vk::PFN_vkGetSwapchainStatusKHR) -> Hook { Hook::new(Device, "vkGetSwapchainStatusKHR", unsafe { mem::transmute::<vk::PFN_vkGetSwapchainStatusKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetValidationCacheDataEXT(pfn: vk::PFN_vkGetValidationCacheDataEXT) -> Hook { Hook::new(Device, "vkGetValidationCacheDataEXT", unsafe { mem::transmute::<vk::PFN_vkGetValidationCacheDataEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetVideoSessionMemoryRequirementsKHR(pfn: vk::PFN_vkGetVideoSessionMemoryRequirementsKHR) -> Hook { Hook::new(Device, "vkGetVideoSessionMemoryRequirementsKHR", unsafe { mem::transmute::<vk::PFN_vkGetVideoSessionMemoryRequirementsKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkGetWinrtDisplayNV(pfn: vk::PFN_vkGetWinrtDisplayNV) -> Hook { Hook::new(PhysicalDevice, "vkGetWinrtDisplayNV", unsafe { mem::transmute::<vk::PFN_vkGetWinrtDisplayNV, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkImportFenceFdKHR(pfn: vk::PFN_vkImportFenceFdKHR) -> Hook { Hook::new(Device, "vkImportFenceFdKHR", unsafe { mem::transmute::<vk::PFN_vkImportFenceFdKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkImportFenceWin32HandleKHR(pfn: vk::PFN_vkImportFenceWin32HandleKHR) -> Hook { Hook::new(Device, "vkImportFenceWin32HandleKHR", unsafe { mem::transmute::<vk::PFN_vkImportFenceWin32HandleKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkImportSemaphoreFdKHR(pfn: vk::PFN_vkImportSemaphoreFdKHR) -> Hook { Hook::new(Device, "vkImportSemaphoreFdKHR", unsafe { mem::transmute::<vk::PFN_vkImportSemaphoreFdKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkImportSemaphoreWin32HandleKHR(pfn: vk::PFN_vkImportSemaphoreWin32HandleKHR) -> Hook { Hook::new(Device, "vkImportSemaphoreWin32HandleKHR", unsafe { mem::transmute::<vk::PFN_vkImportSemaphoreWin32HandleKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkImportSemaphoreZirconHandleFUCHSIA(pfn: vk::PFN_vkImportSemaphoreZirconHandleFUCHSIA) -> Hook { Hook::new(Device, "vkImportSemaphoreZirconHandleFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkImportSemaphoreZirconHandleFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkInitializePerformanceApiINTEL(pfn: vk::PFN_vkInitializePerformanceApiINTEL) -> Hook { Hook::new(Device, "vkInitializePerformanceApiINTEL", unsafe { mem::transmute::<vk::PFN_vkInitializePerformanceApiINTEL, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkInvalidateMappedMemoryRanges(pfn: vk::PFN_vkInvalidateMappedMemoryRanges) -> Hook { Hook::new(Device, "vkInvalidateMappedMemoryRanges", unsafe { mem::transmute::<vk::PFN_vkInvalidateMappedMemoryRanges, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkMapMemory(pfn: vk::PFN_vkMapMemory) -> Hook { Hook::new(Device, "vkMapMemory", unsafe { mem::transmute::<vk::PFN_vkMapMemory, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkMapMemory2KHR(pfn: vk::PFN_vkMapMemory2KHR) -> Hook { Hook::new(Device, "vkMapMemory2KHR", unsafe { mem::transmute::<vk::PFN_vkMapMemory2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkMergePipelineCaches(pfn: vk::PFN_vkMergePipelineCaches) -> Hook { Hook::new(Device, "vkMergePipelineCaches", unsafe { mem::transmute::<vk::PFN_vkMergePipelineCaches, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkMergeValidationCachesEXT(pfn: vk::PFN_vkMergeValidationCachesEXT) -> Hook { Hook::new(Device, "vkMergeValidationCachesEXT", unsafe { mem::transmute::<vk::PFN_vkMergeValidationCachesEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueueBeginDebugUtilsLabelEXT(pfn: vk::PFN_vkQueueBeginDebugUtilsLabelEXT) -> Hook { Hook::new(Device, "vkQueueBeginDebugUtilsLabelEXT", unsafe { mem::transmute::<vk::PFN_vkQueueBeginDebugUtilsLabelEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueueBindSparse(pfn: vk::PFN_vkQueueBindSparse) -> Hook { Hook::new(Device, "vkQueueBindSparse", unsafe { mem::transmute::<vk::PFN_vkQueueBindSparse, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueueEndDebugUtilsLabelEXT(pfn: vk::PFN_vkQueueEndDebugUtilsLabelEXT) -> Hook { Hook::new(Device, "vkQueueEndDebugUtilsLabelEXT", unsafe { mem::transmute::<vk::PFN_vkQueueEndDebugUtilsLabelEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueueInsertDebugUtilsLabelEXT(pfn: vk::PFN_vkQueueInsertDebugUtilsLabelEXT) -> Hook { Hook::new(Device, "vkQueueInsertDebugUtilsLabelEXT", unsafe { mem::transmute::<vk::PFN_vkQueueInsertDebugUtilsLabelEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueuePresentKHR(pfn: vk::PFN_vkQueuePresentKHR) -> Hook { Hook::new(Device, "vkQueuePresentKHR", unsafe { mem::transmute::<vk::PFN_vkQueuePresentKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueueSetPerformanceConfigurationINTEL(pfn: vk::PFN_vkQueueSetPerformanceConfigurationINTEL) -> Hook { Hook::new(Device, "vkQueueSetPerformanceConfigurationINTEL", unsafe { mem::transmute::<vk::PFN_vkQueueSetPerformanceConfigurationINTEL, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueueSubmit(pfn: vk::PFN_vkQueueSubmit) -> Hook { Hook::new(Device, "vkQueueSubmit", unsafe { mem::transmute::<vk::PFN_vkQueueSubmit, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueueSubmit2(pfn: vk::PFN_vkQueueSubmit2) -> Hook { Hook::new(Device, "vkQueueSubmit2", unsafe { mem::transmute::<vk::PFN_vkQueueSubmit2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueueSubmit2KHR(pfn: vk::PFN_vkQueueSubmit2) -> Hook { Hook::new(Device, "vkQueueSubmit2KHR", unsafe { mem::transmute::<vk::PFN_vkQueueSubmit2, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkQueueWaitIdle(pfn: vk::PFN_vkQueueWaitIdle) -> Hook { Hook::new(Device, "vkQueueWaitIdle", unsafe { mem::transmute::<vk::PFN_vkQueueWaitIdle, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkRegisterDeviceEventEXT(pfn: vk::PFN_vkRegisterDeviceEventEXT) -> Hook { Hook::new(Device, "vkRegisterDeviceEventEXT", unsafe { mem::transmute::<vk::PFN_vkRegisterDeviceEventEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkRegisterDisplayEventEXT(pfn: vk::PFN_vkRegisterDisplayEventEXT) -> Hook { Hook::new(Device, "vkRegisterDisplayEventEXT", unsafe { mem::transmute::<vk::PFN_vkRegisterDisplayEventEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkReleaseDisplayEXT(pfn: vk::PFN_vkReleaseDisplayEXT) -> Hook { Hook::new(PhysicalDevice, "vkReleaseDisplayEXT", unsafe { mem::transmute::<vk::PFN_vkReleaseDisplayEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkReleaseFullScreenExclusiveModeEXT(pfn: vk::PFN_vkReleaseFullScreenExclusiveModeEXT) -> Hook { Hook::new(Device, "vkReleaseFullScreenExclusiveModeEXT", unsafe { mem::transmute::<vk::PFN_vkReleaseFullScreenExclusiveModeEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkReleasePerformanceConfigurationINTEL(pfn: vk::PFN_vkReleasePerformanceConfigurationINTEL) -> Hook { Hook::new(Device, "vkReleasePerformanceConfigurationINTEL", unsafe { mem::transmute::<vk::PFN_vkReleasePerformanceConfigurationINTEL, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkReleaseProfilingLockKHR(pfn: vk::PFN_vkReleaseProfilingLockKHR) -> Hook { Hook::new(Device, "vkReleaseProfilingLockKHR", unsafe { mem::transmute::<vk::PFN_vkReleaseProfilingLockKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkReleaseSwapchainImagesEXT(pfn: vk::PFN_vkReleaseSwapchainImagesEXT) -> Hook { Hook::new(Device, "vkReleaseSwapchainImagesEXT", unsafe { mem::transmute::<vk::PFN_vkReleaseSwapchainImagesEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkResetCommandBuffer(pfn: vk::PFN_vkResetCommandBuffer) -> Hook { Hook::new(Device, "vkResetCommandBuffer", unsafe { mem::transmute::<vk::PFN_vkResetCommandBuffer, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkResetCommandPool(pfn: vk::PFN_vkResetCommandPool) -> Hook { Hook::new(Device, "vkResetCommandPool", unsafe { mem::transmute::<vk::PFN_vkResetCommandPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkResetDescriptorPool(pfn: vk::PFN_vkResetDescriptorPool) -> Hook { Hook::new(Device, "vkResetDescriptorPool", unsafe { mem::transmute::<vk::PFN_vkResetDescriptorPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkResetEvent(pfn: vk::PFN_vkResetEvent) -> Hook { Hook::new(Device, "vkResetEvent", unsafe { mem::transmute::<vk::PFN_vkResetEvent, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkResetFences(pfn: vk::PFN_vkResetFences) -> Hook { Hook::new(Device, "vkResetFences", unsafe { mem::transmute::<vk::PFN_vkResetFences, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkResetQueryPool(pfn: vk::PFN_vkResetQueryPool) -> Hook { Hook::new(Device, "vkResetQueryPool", unsafe { mem::transmute::<vk::PFN_vkResetQueryPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkResetQueryPoolEXT(pfn: vk::PFN_vkResetQueryPool) -> Hook { Hook::new(Device, "vkResetQueryPoolEXT", unsafe { mem::transmute::<vk::PFN_vkResetQueryPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetBufferCollectionBufferConstraintsFUCHSIA(pfn: vk::PFN_vkSetBufferCollectionBufferConstraintsFUCHSIA) -> Hook { Hook::new(Device, "vkSetBufferCollectionBufferConstraintsFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkSetBufferCollectionBufferConstraintsFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetBufferCollectionImageConstraintsFUCHSIA(pfn: vk::PFN_vkSetBufferCollectionImageConstraintsFUCHSIA) -> Hook { Hook::new(Device, "vkSetBufferCollectionImageConstraintsFUCHSIA", unsafe { mem::transmute::<vk::PFN_vkSetBufferCollectionImageConstraintsFUCHSIA, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetDebugUtilsObjectNameEXT(pfn: vk::PFN_vkSetDebugUtilsObjectNameEXT) -> Hook { Hook::new(Device, "vkSetDebugUtilsObjectNameEXT", unsafe { mem::transmute::<vk::PFN_vkSetDebugUtilsObjectNameEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetDebugUtilsObjectTagEXT(pfn: vk::PFN_vkSetDebugUtilsObjectTagEXT) -> Hook { Hook::new(Device, "vkSetDebugUtilsObjectTagEXT", unsafe { mem::transmute::<vk::PFN_vkSetDebugUtilsObjectTagEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetDeviceMemoryPriorityEXT(pfn: vk::PFN_vkSetDeviceMemoryPriorityEXT) -> Hook { Hook::new(Device, "vkSetDeviceMemoryPriorityEXT", unsafe { mem::transmute::<vk::PFN_vkSetDeviceMemoryPriorityEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetEvent(pfn: vk::PFN_vkSetEvent) -> Hook { Hook::new(Device, "vkSetEvent", unsafe { mem::transmute::<vk::PFN_vkSetEvent, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetHdrMetadataEXT(pfn: vk::PFN_vkSetHdrMetadataEXT) -> Hook { Hook::new(Device, "vkSetHdrMetadataEXT", unsafe { mem::transmute::<vk::PFN_vkSetHdrMetadataEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetLocalDimmingAMD(pfn: vk::PFN_vkSetLocalDimmingAMD) -> Hook { Hook::new(Device, "vkSetLocalDimmingAMD", unsafe { mem::transmute::<vk::PFN_vkSetLocalDimmingAMD, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetPrivateData(pfn: vk::PFN_vkSetPrivateData) -> Hook { Hook::new(Device, "vkSetPrivateData", unsafe { mem::transmute::<vk::PFN_vkSetPrivateData, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSetPrivateDataEXT(pfn: vk::PFN_vkSetPrivateData) -> Hook { Hook::new(Device, "vkSetPrivateDataEXT", unsafe { mem::transmute::<vk::PFN_vkSetPrivateData, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSignalSemaphore(pfn: vk::PFN_vkSignalSemaphore) -> Hook { Hook::new(Device, "vkSignalSemaphore", unsafe { mem::transmute::<vk::PFN_vkSignalSemaphore, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSignalSemaphoreKHR(pfn: vk::PFN_vkSignalSemaphore) -> Hook { Hook::new(Device, "vkSignalSemaphoreKHR", unsafe { mem::transmute::<vk::PFN_vkSignalSemaphore, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkSubmitDebugUtilsMessageEXT(pfn: vk::PFN_vkSubmitDebugUtilsMessageEXT) -> Hook { Hook::new(Instance, "vkSubmitDebugUtilsMessageEXT", unsafe { mem::transmute::<vk::PFN_vkSubmitDebugUtilsMessageEXT, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkTrimCommandPool(pfn: vk::PFN_vkTrimCommandPool) -> Hook { Hook::new(Device, "vkTrimCommandPool", unsafe { mem::transmute::<vk::PFN_vkTrimCommandPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkTrimCommandPoolKHR(pfn: vk::PFN_vkTrimCommandPool) -> Hook { Hook::new(Device, "vkTrimCommandPoolKHR", unsafe { mem::transmute::<vk::PFN_vkTrimCommandPool, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkUninitializePerformanceApiINTEL(pfn: vk::PFN_vkUninitializePerformanceApiINTEL) -> Hook { Hook::new(Device, "vkUninitializePerformanceApiINTEL", unsafe { mem::transmute::<vk::PFN_vkUninitializePerformanceApiINTEL, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkUnmapMemory(pfn: vk::PFN_vkUnmapMemory) -> Hook { Hook::new(Device, "vkUnmapMemory", unsafe { mem::transmute::<vk::PFN_vkUnmapMemory, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkUnmapMemory2KHR(pfn: vk::PFN_vkUnmapMemory2KHR) -> Hook { Hook::new(Device, "vkUnmapMemory2KHR", unsafe { mem::transmute::<vk::PFN_vkUnmapMemory2KHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkUpdateDescriptorSetWithTemplate(pfn: vk::PFN_vkUpdateDescriptorSetWithTemplate) -> Hook { Hook::new(Device, "vkUpdateDescriptorSetWithTemplate", unsafe { mem::transmute::<vk::PFN_vkUpdateDescriptorSetWithTemplate, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkUpdateDescriptorSetWithTemplateKHR(pfn: vk::PFN_vkUpdateDescriptorSetWithTemplate) -> Hook { Hook::new(Device, "vkUpdateDescriptorSetWithTemplateKHR", unsafe { mem::transmute::<vk::PFN_vkUpdateDescriptorSetWithTemplate, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkUpdateDescriptorSets(pfn: vk::PFN_vkUpdateDescriptorSets) -> Hook { Hook::new(Device, "vkUpdateDescriptorSets", unsafe { mem::transmute::<vk::PFN_vkUpdateDescriptorSets, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkUpdateVideoSessionParametersKHR(pfn: vk::PFN_vkUpdateVideoSessionParametersKHR) -> Hook { Hook::new(Device, "vkUpdateVideoSessionParametersKHR", unsafe { mem::transmute::<vk::PFN_vkUpdateVideoSessionParametersKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkWaitForFences(pfn: vk::PFN_vkWaitForFences) -> Hook { Hook::new(Device, "vkWaitForFences", unsafe { mem::transmute::<vk::PFN_vkWaitForFences, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkWaitForPresentKHR(pfn: vk::PFN_vkWaitForPresentKHR) -> Hook { Hook::new(Device, "vkWaitForPresentKHR", unsafe { mem::transmute::<vk::PFN_vkWaitForPresentKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkWaitSemaphores(pfn: vk::PFN_vkWaitSemaphores) -> Hook { Hook::new(Device, "vkWaitSemaphores", unsafe { mem::transmute::<vk::PFN_vkWaitSemaphores, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkWaitSemaphoresKHR(pfn: vk::PFN_vkWaitSemaphores) -> Hook { Hook::new(Device, "vkWaitSemaphoresKHR", unsafe { mem::transmute::<vk::PFN_vkWaitSemaphores, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkWriteAccelerationStructuresPropertiesKHR(pfn: vk::PFN_vkWriteAccelerationStructuresPropertiesKHR) -> Hook { Hook::new(Device, "vkWriteAccelerationStructuresPropertiesKHR", unsafe { mem::transmute::<vk::PFN_vkWriteAccelerationStructuresPropertiesKHR, unsafe extern "system" fn()>(pfn) }) }
+    pub const fn vkWriteMicromapsPropertiesEXT(pfn: vk::PFN_vkWriteMicromapsPropertiesEXT) -> Hook { Hook::new(Device, "vkWriteMicromapsPropertiesEXT", unsafe { mem::transmute::<vk::PFN_vkWriteMicromapsPropertiesEXT, unsafe extern "system" fn()>(pfn) }) }
+}
+
+/// Marker type of every command called on a dispatchable handle, but the loader ones.
+pub mod commands {
+    #![allow(non_camel_case_types)]
+
+    use crate::registry::TypedCommand;
+    use crate::CommandLevel::{self, *};
+
+    use ash::vk;
+
+    pub struct vkAcquireDrmDisplayEXT; impl TypedCommand for vkAcquireDrmDisplayEXT { const NAME: &'static str = "vkAcquireDrmDisplayEXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkAcquireDrmDisplayEXT; }
+    pub struct vkAcquireFullScreenExclusiveModeEXT; impl TypedCommand for vkAcquireFullScreenExclusiveModeEXT { const NAME: &'static str = "vkAcquireFullScreenExclusiveModeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkAcquireFullScreenExclusiveModeEXT; }
+    pub struct vkAcquireNextImage2KHR; impl TypedCommand for vkAcquireNextImage2KHR { const NAME: &'static str = "vkAcquireNextImage2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkAcquireNextImage2KHR; }
+    pub struct vkAcquireNextImageKHR; impl TypedCommand for vkAcquireNextImageKHR { const NAME: &'static str = "vkAcquireNextImageKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkAcquireNextImageKHR; }
+    pub struct vkAcquirePerformanceConfigurationINTEL; impl TypedCommand for vkAcquirePerformanceConfigurationINTEL { const NAME: &'static str = "vkAcquirePerformanceConfigurationINTEL"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkAcquirePerformanceConfigurationINTEL; }
+    pub struct vkAcquireProfilingLockKHR; impl TypedCommand for vkAcquireProfilingLockKHR { const NAME: &'static str = "vkAcquireProfilingLockKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkAcquireProfilingLockKHR; }
+    pub struct vkAcquireWinrtDisplayNV; impl TypedCommand for vkAcquireWinrtDisplayNV { const NAME: &'static str = "vkAcquireWinrtDisplayNV"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkAcquireWinrtDisplayNV; }
+    pub struct vkAcquireXlibDisplayEXT; impl TypedCommand for vkAcquireXlibDisplayEXT { const NAME: &'static str = "vkAcquireXlibDisplayEXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkAcquireXlibDisplayEXT; }
+    pub struct vkAllocateCommandBuffers; impl TypedCommand for vkAllocateCommandBuffers { const NAME: &'static str = "vkAllocateCommandBuffers"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkAllocateCommandBuffers; }
+    pub struct vkAllocateDescriptorSets; impl TypedCommand for vkAllocateDescriptorSets { const NAME: &'static str = "vkAllocateDescriptorSets"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkAllocateDescriptorSets; }
+    pub struct vkAllocateMemory; impl TypedCommand for vkAllocateMemory { const NAME: &'static str = "vkAllocateMemory"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkAllocateMemory; }
+    pub struct vkBeginCommandBuffer; impl TypedCommand for vkBeginCommandBuffer { const NAME: &'static str = "vkBeginCommandBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBeginCommandBuffer; }
+    pub struct vkBindAccelerationStructureMemoryNV; impl TypedCommand for vkBindAccelerationStructureMemoryNV { const NAME: &'static str = "vkBindAccelerationStructureMemoryNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBindAccelerationStructureMemoryNV; }
+    pub struct vkBindBufferMemory; impl TypedCommand for vkBindBufferMemory { const NAME: &'static str = "vkBindBufferMemory"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBindBufferMemory; }
+    pub struct vkBindBufferMemory2; impl TypedCommand for vkBindBufferMemory2 { const NAME: &'static str = "vkBindBufferMemory2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBindBufferMemory2; }
+    pub struct vkBindBufferMemory2KHR; impl TypedCommand for vkBindBufferMemory2KHR { const NAME: &'static str = "vkBindBufferMemory2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBindBufferMemory2; }
+    pub struct vkBindImageMemory; impl TypedCommand for vkBindImageMemory { const NAME: &'static str = "vkBindImageMemory"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBindImageMemory; }
+    pub struct vkBindImageMemory2; impl TypedCommand for vkBindImageMemory2 { const NAME: &'static str = "vkBindImageMemory2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBindImageMemory2; }
+    pub struct vkBindImageMemory2KHR; impl TypedCommand for vkBindImageMemory2KHR { const NAME: &'static str = "vkBindImageMemory2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBindImageMemory2; }
+    pub struct vkBindOpticalFlowSessionImageNV; impl TypedCommand for vkBindOpticalFlowSessionImageNV { const NAME: &'static str = "vkBindOpticalFlowSessionImageNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBindOpticalFlowSessionImageNV; }
+    pub struct vkBindVideoSessionMemoryKHR; impl TypedCommand for vkBindVideoSessionMemoryKHR { const NAME: &'static str = "vkBindVideoSessionMemoryKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBindVideoSessionMemoryKHR; }
+    pub struct vkBuildAccelerationStructuresKHR; impl TypedCommand for vkBuildAccelerationStructuresKHR { const NAME: &'static str = "vkBuildAccelerationStructuresKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBuildAccelerationStructuresKHR; }
+    pub struct vkBuildMicromapsEXT; impl TypedCommand for vkBuildMicromapsEXT { const NAME: &'static str = "vkBuildMicromapsEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkBuildMicromapsEXT; }
+    pub struct vkCmdBeginConditionalRenderingEXT; impl TypedCommand for vkCmdBeginConditionalRenderingEXT { const NAME: &'static str = "vkCmdBeginConditionalRenderingEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginConditionalRenderingEXT; }
+    pub struct vkCmdBeginDebugUtilsLabelEXT; impl TypedCommand for vkCmdBeginDebugUtilsLabelEXT { const NAME: &'static str = "vkCmdBeginDebugUtilsLabelEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginDebugUtilsLabelEXT; }
+    pub struct vkCmdBeginQuery; impl TypedCommand for vkCmdBeginQuery { const NAME: &'static str = "vkCmdBeginQuery"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginQuery; }
+    pub struct vkCmdBeginQueryIndexedEXT; impl TypedCommand for vkCmdBeginQueryIndexedEXT { const NAME: &'static str = "vkCmdBeginQueryIndexedEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginQueryIndexedEXT; }
+    pub struct vkCmdBeginRenderPass; impl TypedCommand for vkCmdBeginRenderPass { const NAME: &'static str = "vkCmdBeginRenderPass"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginRenderPass; }
+    pub struct vkCmdBeginRenderPass2; impl TypedCommand for vkCmdBeginRenderPass2 { const NAME: &'static str = "vkCmdBeginRenderPass2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginRenderPass2; }
+    pub struct vkCmdBeginRenderPass2KHR; impl TypedCommand for vkCmdBeginRenderPass2KHR { const NAME: &'static str = "vkCmdBeginRenderPass2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginRenderPass2; }
+    pub struct vkCmdBeginRendering; impl TypedCommand for vkCmdBeginRendering { const NAME: &'static str = "vkCmdBeginRendering"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginRendering; }
+    pub struct vkCmdBeginRenderingKHR; impl TypedCommand for vkCmdBeginRenderingKHR { const NAME: &'static str = "vkCmdBeginRenderingKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginRendering; }
+    pub struct vkCmdBeginTransformFeedbackEXT; impl TypedCommand for vkCmdBeginTransformFeedbackEXT { const NAME: &'static str = "vkCmdBeginTransformFeedbackEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginTransformFeedbackEXT; }
+    pub struct vkCmdBeginVideoCodingKHR; impl TypedCommand for vkCmdBeginVideoCodingKHR { const NAME: &'static str = "vkCmdBeginVideoCodingKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBeginVideoCodingKHR; }
+    pub struct vkCmdBindDescriptorBufferEmbeddedSamplersEXT; impl TypedCommand for vkCmdBindDescriptorBufferEmbeddedSamplersEXT { const NAME: &'static str = "vkCmdBindDescriptorBufferEmbeddedSamplersEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindDescriptorBufferEmbeddedSamplersEXT; }
+    pub struct vkCmdBindDescriptorBuffersEXT; impl TypedCommand for vkCmdBindDescriptorBuffersEXT { const NAME: &'static str = "vkCmdBindDescriptorBuffersEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindDescriptorBuffersEXT; }
+    pub struct vkCmdBindDescriptorSets; impl TypedCommand for vkCmdBindDescriptorSets { const NAME: &'static str = "vkCmdBindDescriptorSets"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindDescriptorSets; }
+    pub struct vkCmdBindIndexBuffer; impl TypedCommand for vkCmdBindIndexBuffer { const NAME: &'static str = "vkCmdBindIndexBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindIndexBuffer; }
+    pub struct vkCmdBindInvocationMaskHUAWEI; impl TypedCommand for vkCmdBindInvocationMaskHUAWEI { const NAME: &'static str = "vkCmdBindInvocationMaskHUAWEI"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindInvocationMaskHUAWEI; }
+    pub struct vkCmdBindPipeline; impl TypedCommand for vkCmdBindPipeline { const NAME: &'static str = "vkCmdBindPipeline"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindPipeline; }
+    pub struct vkCmdBindPipelineShaderGroupNV; impl TypedCommand for vkCmdBindPipelineShaderGroupNV { const NAME: &'static str = "vkCmdBindPipelineShaderGroupNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindPipelineShaderGroupNV; }
+    pub struct vkCmdBindShadersEXT; impl TypedCommand for vkCmdBindShadersEXT { const NAME: &'static str = "vkCmdBindShadersEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindShadersEXT; }
+    pub struct vkCmdBindShadingRateImageNV; impl TypedCommand for vkCmdBindShadingRateImageNV { const NAME: &'static str = "vkCmdBindShadingRateImageNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindShadingRateImageNV; }
+    pub struct vkCmdBindTransformFeedbackBuffersEXT; impl TypedCommand for vkCmdBindTransformFeedbackBuffersEXT { const NAME: &'static str = "vkCmdBindTransformFeedbackBuffersEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindTransformFeedbackBuffersEXT; }
+    pub struct vkCmdBindVertexBuffers; impl TypedCommand for vkCmdBindVertexBuffers { const NAME: &'static str = "vkCmdBindVertexBuffers"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindVertexBuffers; }
+    pub struct vkCmdBindVertexBuffers2; impl TypedCommand for vkCmdBindVertexBuffers2 { const NAME: &'static str = "vkCmdBindVertexBuffers2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindVertexBuffers2; }
+    pub struct vkCmdBindVertexBuffers2EXT; impl TypedCommand for vkCmdBindVertexBuffers2EXT { const NAME: &'static str = "vkCmdBindVertexBuffers2EXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBindVertexBuffers2; }
+    pub struct vkCmdBlitImage; impl TypedCommand for vkCmdBlitImage { const NAME: &'static str = "vkCmdBlitImage"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBlitImage; }
+    pub struct vkCmdBlitImage2; impl TypedCommand for vkCmdBlitImage2 { const NAME: &'static str = "vkCmdBlitImage2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBlitImage2; }
+    pub struct vkCmdBlitImage2KHR; impl TypedCommand for vkCmdBlitImage2KHR { const NAME: &'static str = "vkCmdBlitImage2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBlitImage2; }
+    pub struct vkCmdBuildAccelerationStructureNV; impl TypedCommand for vkCmdBuildAccelerationStructureNV { const NAME: &'static str = "vkCmdBuildAccelerationStructureNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBuildAccelerationStructureNV; }
+    pub struct vkCmdBuildAccelerationStructuresIndirectKHR; impl TypedCommand for vkCmdBuildAccelerationStructuresIndirectKHR { const NAME: &'static str = "vkCmdBuildAccelerationStructuresIndirectKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBuildAccelerationStructuresIndirectKHR; }
+    pub struct vkCmdBuildAccelerationStructuresKHR; impl TypedCommand for vkCmdBuildAccelerationStructuresKHR { const NAME: &'static str = "vkCmdBuildAccelerationStructuresKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBuildAccelerationStructuresKHR; }
+    pub struct vkCmdBuildMicromapsEXT; impl TypedCommand for vkCmdBuildMicromapsEXT { const NAME: &'static str = "vkCmdBuildMicromapsEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdBuildMicromapsEXT; }
+    pub struct vkCmdClearAttachments; impl TypedCommand for vkCmdClearAttachments { const NAME: &'static str = "vkCmdClearAttachments"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdClearAttachments; }
+    pub struct vkCmdClearColorImage; impl TypedCommand for vkCmdClearColorImage { const NAME: &'static str = "vkCmdClearColorImage"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdClearColorImage; }
+    pub struct vkCmdClearDepthStencilImage; impl TypedCommand for vkCmdClearDepthStencilImage { const NAME: &'static str = "vkCmdClearDepthStencilImage"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdClearDepthStencilImage; }
+    pub struct vkCmdControlVideoCodingKHR; impl TypedCommand for vkCmdControlVideoCodingKHR { const NAME: &'static str = "vkCmdControlVideoCodingKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdControlVideoCodingKHR; }
+    pub struct vkCmdCopyAccelerationStructureKHR; impl TypedCommand for vkCmdCopyAccelerationStructureKHR { const NAME: &'static str = "vkCmdCopyAccelerationStructureKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyAccelerationStructureKHR; }
+    pub struct vkCmdCopyAccelerationStructureNV; impl TypedCommand for vkCmdCopyAccelerationStructureNV { const NAME: &'static str = "vkCmdCopyAccelerationStructureNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyAccelerationStructureNV; }
+    pub struct vkCmdCopyAccelerationStructureToMemoryKHR; impl TypedCommand for vkCmdCopyAccelerationStructureToMemoryKHR { const NAME: &'static str = "vkCmdCopyAccelerationStructureToMemoryKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyAccelerationStructureToMemoryKHR; }
+    pub struct vkCmdCopyBuffer; impl TypedCommand for vkCmdCopyBuffer { const NAME: &'static str = "vkCmdCopyBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyBuffer; }
+    pub struct vkCmdCopyBuffer2; impl TypedCommand for vkCmdCopyBuffer2 { const NAME: &'static str = "vkCmdCopyBuffer2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyBuffer2; }
+    pub struct vkCmdCopyBuffer2KHR; impl TypedCommand for vkCmdCopyBuffer2KHR { const NAME: &'static str = "vkCmdCopyBuffer2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyBuffer2; }
+    pub struct vkCmdCopyBufferToImage; impl TypedCommand for vkCmdCopyBufferToImage { const NAME: &'static str = "vkCmdCopyBufferToImage"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyBufferToImage; }
+    pub struct vkCmdCopyBufferToImage2; impl TypedCommand for vkCmdCopyBufferToImage2 { const NAME: &'static str = "vkCmdCopyBufferToImage2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyBufferToImage2; }
+    pub struct vkCmdCopyBufferToImage2KHR; impl TypedCommand for vkCmdCopyBufferToImage2KHR { const NAME: &'static str = "vkCmdCopyBufferToImage2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyBufferToImage2; }
+    pub struct vkCmdCopyImage; impl TypedCommand for vkCmdCopyImage { const NAME: &'static str = "vkCmdCopyImage"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyImage; }
+    pub struct vkCmdCopyImage2; impl TypedCommand for vkCmdCopyImage2 { const NAME: &'static str = "vkCmdCopyImage2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyImage2; }
+    pub struct vkCmdCopyImage2KHR; impl TypedCommand for vkCmdCopyImage2KHR { const NAME: &'static str = "vkCmdCopyImage2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyImage2; }
+    pub struct vkCmdCopyImageToBuffer; impl TypedCommand for vkCmdCopyImageToBuffer { const NAME: &'static str = "vkCmdCopyImageToBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyImageToBuffer; }
+    pub struct vkCmdCopyImageToBuffer2; impl TypedCommand for vkCmdCopyImageToBuffer2 { const NAME: &'static str = "vkCmdCopyImageToBuffer2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyImageToBuffer2; }
+    pub struct vkCmdCopyImageToBuffer2KHR; impl TypedCommand for vkCmdCopyImageToBuffer2KHR { const NAME: &'static str = "vkCmdCopyImageToBuffer2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyImageToBuffer2; }
+    pub struct vkCmdCopyMemoryIndirectNV; impl TypedCommand for vkCmdCopyMemoryIndirectNV { const NAME: &'static str = "vkCmdCopyMemoryIndirectNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyMemoryIndirectNV; }
+    pub struct vkCmdCopyMemoryToAccelerationStructureKHR; impl TypedCommand for vkCmdCopyMemoryToAccelerationStructureKHR { const NAME: &'static str = "vkCmdCopyMemoryToAccelerationStructureKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyMemoryToAccelerationStructureKHR; }
+    pub struct vkCmdCopyMemoryToImageIndirectNV; impl TypedCommand for vkCmdCopyMemoryToImageIndirectNV { const NAME: &'static str = "vkCmdCopyMemoryToImageIndirectNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyMemoryToImageIndirectNV; }
+    pub struct vkCmdCopyMemoryToMicromapEXT; impl TypedCommand for vkCmdCopyMemoryToMicromapEXT { const NAME: &'static str = "vkCmdCopyMemoryToMicromapEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyMemoryToMicromapEXT; }
+    pub struct vkCmdCopyMicromapEXT; impl TypedCommand for vkCmdCopyMicromapEXT { const NAME: &'static str = "vkCmdCopyMicromapEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyMicromapEXT; }
+    pub struct vkCmdCopyMicromapToMemoryEXT; impl TypedCommand for vkCmdCopyMicromapToMemoryEXT { const NAME: &'static str = "vkCmdCopyMicromapToMemoryEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyMicromapToMemoryEXT; }
+    pub struct vkCmdCopyQueryPoolResults; impl TypedCommand for vkCmdCopyQueryPoolResults { const NAME: &'static str = "vkCmdCopyQueryPoolResults"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCopyQueryPoolResults; }
+    pub struct vkCmdCuLaunchKernelNVX; impl TypedCommand for vkCmdCuLaunchKernelNVX { const NAME: &'static str = "vkCmdCuLaunchKernelNVX"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdCuLaunchKernelNVX; }
+    pub struct vkCmdDebugMarkerBeginEXT; impl TypedCommand for vkCmdDebugMarkerBeginEXT { const NAME: &'static str = "vkCmdDebugMarkerBeginEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDebugMarkerBeginEXT; }
+    pub struct vkCmdDebugMarkerEndEXT; impl TypedCommand for vkCmdDebugMarkerEndEXT { const NAME: &'static str = "vkCmdDebugMarkerEndEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDebugMarkerEndEXT; }
+    pub struct vkCmdDebugMarkerInsertEXT; impl TypedCommand for vkCmdDebugMarkerInsertEXT { const NAME: &'static str = "vkCmdDebugMarkerInsertEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDebugMarkerInsertEXT; }
+    pub struct vkCmdDecodeVideoKHR; impl TypedCommand for vkCmdDecodeVideoKHR { const NAME: &'static str = "vkCmdDecodeVideoKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDecodeVideoKHR; }
+    pub struct vkCmdDecompressMemoryIndirectCountNV; impl TypedCommand for vkCmdDecompressMemoryIndirectCountNV { const NAME: &'static str = "vkCmdDecompressMemoryIndirectCountNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDecompressMemoryIndirectCountNV; }
+    pub struct vkCmdDecompressMemoryNV; impl TypedCommand for vkCmdDecompressMemoryNV { const NAME: &'static str = "vkCmdDecompressMemoryNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDecompressMemoryNV; }
+    pub struct vkCmdDispatch; impl TypedCommand for vkCmdDispatch { const NAME: &'static str = "vkCmdDispatch"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDispatch; }
+    pub struct vkCmdDispatchBase; impl TypedCommand for vkCmdDispatchBase { const NAME: &'static str = "vkCmdDispatchBase"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDispatchBase; }
+    pub struct vkCmdDispatchBaseKHR; impl TypedCommand for vkCmdDispatchBaseKHR { const NAME: &'static str = "vkCmdDispatchBaseKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDispatchBase; }
+    pub struct vkCmdDispatchIndirect; impl TypedCommand for vkCmdDispatchIndirect { const NAME: &'static str = "vkCmdDispatchIndirect"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDispatchIndirect; }
+    pub struct vkCmdDraw; impl TypedCommand for vkCmdDraw { const NAME: &'static str = "vkCmdDraw"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDraw; }
+    pub struct vkCmdDrawClusterHUAWEI; impl TypedCommand for vkCmdDrawClusterHUAWEI { const NAME: &'static str = "vkCmdDrawClusterHUAWEI"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawClusterHUAWEI; }
+    pub struct vkCmdDrawClusterIndirectHUAWEI; impl TypedCommand for vkCmdDrawClusterIndirectHUAWEI { const NAME: &'static str = "vkCmdDrawClusterIndirectHUAWEI"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawClusterIndirectHUAWEI; }
+    pub struct vkCmdDrawIndexed; impl TypedCommand for vkCmdDrawIndexed { const NAME: &'static str = "vkCmdDrawIndexed"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndexed; }
+    pub struct vkCmdDrawIndexedIndirect; impl TypedCommand for vkCmdDrawIndexedIndirect { const NAME: &'static str = "vkCmdDrawIndexedIndirect"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndexedIndirect; }
+    pub struct vkCmdDrawIndexedIndirectCount; impl TypedCommand for vkCmdDrawIndexedIndirectCount { const NAME: &'static str = "vkCmdDrawIndexedIndirectCount"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndexedIndirectCount; }
+    pub struct vkCmdDrawIndexedIndirectCountAMD; impl TypedCommand for vkCmdDrawIndexedIndirectCountAMD { const NAME: &'static str = "vkCmdDrawIndexedIndirectCountAMD"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndexedIndirectCount; }
+    pub struct vkCmdDrawIndexedIndirectCountKHR; impl TypedCommand for vkCmdDrawIndexedIndirectCountKHR { const NAME: &'static str = "vkCmdDrawIndexedIndirectCountKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndexedIndirectCount; }
+    pub struct vkCmdDrawIndirect; impl TypedCommand for vkCmdDrawIndirect { const NAME: &'static str = "vkCmdDrawIndirect"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndirect; }
+    pub struct vkCmdDrawIndirectByteCountEXT; impl TypedCommand for vkCmdDrawIndirectByteCountEXT { const NAME: &'static str = "vkCmdDrawIndirectByteCountEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndirectByteCountEXT; }
+    pub struct vkCmdDrawIndirectCount; impl TypedCommand for vkCmdDrawIndirectCount { const NAME: &'static str = "vkCmdDrawIndirectCount"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndirectCount; }
+    pub struct vkCmdDrawIndirectCountAMD; impl TypedCommand for vkCmdDrawIndirectCountAMD { const NAME: &'static str = "vkCmdDrawIndirectCountAMD"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndirectCount; }
+    pub struct vkCmdDrawIndirectCountKHR; impl TypedCommand for vkCmdDrawIndirectCountKHR { const NAME: &'static str = "vkCmdDrawIndirectCountKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawIndirectCount; }
+    pub struct vkCmdDrawMeshTasksEXT; impl TypedCommand for vkCmdDrawMeshTasksEXT { const NAME: &'static str = "vkCmdDrawMeshTasksEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawMeshTasksEXT; }
+    pub struct vkCmdDrawMeshTasksIndirectCountEXT; impl TypedCommand for vkCmdDrawMeshTasksIndirectCountEXT { const NAME: &'static str = "vkCmdDrawMeshTasksIndirectCountEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawMeshTasksIndirectCountEXT; }
+    pub struct vkCmdDrawMeshTasksIndirectCountNV; impl TypedCommand for vkCmdDrawMeshTasksIndirectCountNV { const NAME: &'static str = "vkCmdDrawMeshTasksIndirectCountNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawMeshTasksIndirectCountNV; }
+    pub struct vkCmdDrawMeshTasksIndirectEXT; impl TypedCommand for vkCmdDrawMeshTasksIndirectEXT { const NAME: &'static str = "vkCmdDrawMeshTasksIndirectEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawMeshTasksIndirectEXT; }
+    pub struct vkCmdDrawMeshTasksIndirectNV; impl TypedCommand for vkCmdDrawMeshTasksIndirectNV { const NAME: &'static str = "vkCmdDrawMeshTasksIndirectNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawMeshTasksIndirectNV; }
+    pub struct vkCmdDrawMeshTasksNV; impl TypedCommand for vkCmdDrawMeshTasksNV { const NAME: &'static str = "vkCmdDrawMeshTasksNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawMeshTasksNV; }
+    pub struct vkCmdDrawMultiEXT; impl TypedCommand for vkCmdDrawMultiEXT { const NAME: &'static str = "vkCmdDrawMultiEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawMultiEXT; }
+    pub struct vkCmdDrawMultiIndexedEXT; impl TypedCommand for vkCmdDrawMultiIndexedEXT { const NAME: &'static str = "vkCmdDrawMultiIndexedEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdDrawMultiIndexedEXT; }
+    pub struct vkCmdEncodeVideoKHR; impl TypedCommand for vkCmdEncodeVideoKHR { const NAME: &'static str = "vkCmdEncodeVideoKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEncodeVideoKHR; }
+    pub struct vkCmdEndConditionalRenderingEXT; impl TypedCommand for vkCmdEndConditionalRenderingEXT { const NAME: &'static str = "vkCmdEndConditionalRenderingEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndConditionalRenderingEXT; }
+    pub struct vkCmdEndDebugUtilsLabelEXT; impl TypedCommand for vkCmdEndDebugUtilsLabelEXT { const NAME: &'static str = "vkCmdEndDebugUtilsLabelEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndDebugUtilsLabelEXT; }
+    pub struct vkCmdEndQuery; impl TypedCommand for vkCmdEndQuery { const NAME: &'static str = "vkCmdEndQuery"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndQuery; }
+    pub struct vkCmdEndQueryIndexedEXT; impl TypedCommand for vkCmdEndQueryIndexedEXT { const NAME: &'static str = "vkCmdEndQueryIndexedEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndQueryIndexedEXT; }
+    pub struct vkCmdEndRenderPass; impl TypedCommand for vkCmdEndRenderPass { const NAME: &'static str = "vkCmdEndRenderPass"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndRenderPass; }
+    pub struct vkCmdEndRenderPass2; impl TypedCommand for vkCmdEndRenderPass2 { const NAME: &'static str = "vkCmdEndRenderPass2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndRenderPass2; }
+    pub struct vkCmdEndRenderPass2KHR; impl TypedCommand for vkCmdEndRenderPass2KHR { const NAME: &'static str = "vkCmdEndRenderPass2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndRenderPass2; }
+    pub struct vkCmdEndRendering; impl TypedCommand for vkCmdEndRendering { const NAME: &'static str = "vkCmdEndRendering"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndRendering; }
+    pub struct vkCmdEndRenderingKHR; impl TypedCommand for vkCmdEndRenderingKHR { const NAME: &'static str = "vkCmdEndRenderingKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndRendering; }
+    pub struct vkCmdEndTransformFeedbackEXT; impl TypedCommand for vkCmdEndTransformFeedbackEXT { const NAME: &'static str = "vkCmdEndTransformFeedbackEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndTransformFeedbackEXT; }
+    pub struct vkCmdEndVideoCodingKHR; impl TypedCommand for vkCmdEndVideoCodingKHR { const NAME: &'static str = "vkCmdEndVideoCodingKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdEndVideoCodingKHR; }
+    pub struct vkCmdExecuteCommands; impl TypedCommand for vkCmdExecuteCommands { const NAME: &'static str = "vkCmdExecuteCommands"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdExecuteCommands; }
+    pub struct vkCmdExecuteGeneratedCommandsNV; impl TypedCommand for vkCmdExecuteGeneratedCommandsNV { const NAME: &'static str = "vkCmdExecuteGeneratedCommandsNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdExecuteGeneratedCommandsNV; }
+    pub struct vkCmdFillBuffer; impl TypedCommand for vkCmdFillBuffer { const NAME: &'static str = "vkCmdFillBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdFillBuffer; }
+    pub struct vkCmdInsertDebugUtilsLabelEXT; impl TypedCommand for vkCmdInsertDebugUtilsLabelEXT { const NAME: &'static str = "vkCmdInsertDebugUtilsLabelEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdInsertDebugUtilsLabelEXT; }
+    pub struct vkCmdNextSubpass; impl TypedCommand for vkCmdNextSubpass { const NAME: &'static str = "vkCmdNextSubpass"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdNextSubpass; }
+    pub struct vkCmdNextSubpass2; impl TypedCommand for vkCmdNextSubpass2 { const NAME: &'static str = "vkCmdNextSubpass2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdNextSubpass2; }
+    pub struct vkCmdNextSubpass2KHR; impl TypedCommand for vkCmdNextSubpass2KHR { const NAME: &'static str = "vkCmdNextSubpass2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdNextSubpass2; }
+    pub struct vkCmdOpticalFlowExecuteNV; impl TypedCommand for vkCmdOpticalFlowExecuteNV { const NAME: &'static str = "vkCmdOpticalFlowExecuteNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdOpticalFlowExecuteNV; }
+    pub struct vkCmdPipelineBarrier; impl TypedCommand for vkCmdPipelineBarrier { const NAME: &'static str = "vkCmdPipelineBarrier"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdPipelineBarrier; }
+    pub struct vkCmdPipelineBarrier2; impl TypedCommand for vkCmdPipelineBarrier2 { const NAME: &'static str = "vkCmdPipelineBarrier2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdPipelineBarrier2; }
+    pub struct vkCmdPipelineBarrier2KHR; impl TypedCommand for vkCmdPipelineBarrier2KHR { const NAME: &'static str = "vkCmdPipelineBarrier2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdPipelineBarrier2; }
+    pub struct vkCmdPreprocessGeneratedCommandsNV; impl TypedCommand for vkCmdPreprocessGeneratedCommandsNV { const NAME: &'static str = "vkCmdPreprocessGeneratedCommandsNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdPreprocessGeneratedCommandsNV; }
+    pub struct vkCmdPushConstants; impl TypedCommand for vkCmdPushConstants { const NAME: &'static str = "vkCmdPushConstants"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdPushConstants; }
+    pub struct vkCmdPushDescriptorSetKHR; impl TypedCommand for vkCmdPushDescriptorSetKHR { const NAME: &'static str = "vkCmdPushDescriptorSetKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdPushDescriptorSetKHR; }
+    pub struct vkCmdPushDescriptorSetWithTemplateKHR; impl TypedCommand for vkCmdPushDescriptorSetWithTemplateKHR { const NAME: &'static str = "vkCmdPushDescriptorSetWithTemplateKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdPushDescriptorSetWithTemplateKHR; }
+    pub struct vkCmdResetEvent; impl TypedCommand for vkCmdResetEvent { const NAME: &'static str = "vkCmdResetEvent"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdResetEvent; }
+    pub struct vkCmdResetEvent2; impl TypedCommand for vkCmdResetEvent2 { const NAME: &'static str = "vkCmdResetEvent2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdResetEvent2; }
+    pub struct vkCmdResetEvent2KHR; impl TypedCommand for vkCmdResetEvent2KHR { const NAME: &'static str = "vkCmdResetEvent2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdResetEvent2; }
+    pub struct vkCmdResetQueryPool; impl TypedCommand for vkCmdResetQueryPool { const NAME: &'static str = "vkCmdResetQueryPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdResetQueryPool; }
+    pub struct vkCmdResolveImage; impl TypedCommand for vkCmdResolveImage { const NAME: &'static str = "vkCmdResolveImage"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdResolveImage; }
+    pub struct vkCmdResolveImage2; impl TypedCommand for vkCmdResolveImage2 { const NAME: &'static str = "vkCmdResolveImage2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdResolveImage2; }
+    pub struct vkCmdResolveImage2KHR; impl TypedCommand for vkCmdResolveImage2KHR { const NAME: &'static str = "vkCmdResolveImage2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdResolveImage2; }
+    pub struct vkCmdSetAlphaToCoverageEnableEXT; impl TypedCommand for vkCmdSetAlphaToCoverageEnableEXT { const NAME: &'static str = "vkCmdSetAlphaToCoverageEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetAlphaToCoverageEnableEXT; }
+    pub struct vkCmdSetAlphaToOneEnableEXT; impl TypedCommand for vkCmdSetAlphaToOneEnableEXT { const NAME: &'static str = "vkCmdSetAlphaToOneEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetAlphaToOneEnableEXT; }
+    pub struct vkCmdSetAttachmentFeedbackLoopEnableEXT; impl TypedCommand for vkCmdSetAttachmentFeedbackLoopEnableEXT { const NAME: &'static str = "vkCmdSetAttachmentFeedbackLoopEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetAttachmentFeedbackLoopEnableEXT; }
+    pub struct vkCmdSetBlendConstants; impl TypedCommand for vkCmdSetBlendConstants { const NAME: &'static str = "vkCmdSetBlendConstants"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetBlendConstants; }
+    pub struct vkCmdSetCheckpointNV; impl TypedCommand for vkCmdSetCheckpointNV { const NAME: &'static str = "vkCmdSetCheckpointNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCheckpointNV; }
+    pub struct vkCmdSetCoarseSampleOrderNV; impl TypedCommand for vkCmdSetCoarseSampleOrderNV { const NAME: &'static str = "vkCmdSetCoarseSampleOrderNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCoarseSampleOrderNV; }
+    pub struct vkCmdSetColorBlendAdvancedEXT; impl TypedCommand for vkCmdSetColorBlendAdvancedEXT { const NAME: &'static str = "vkCmdSetColorBlendAdvancedEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetColorBlendAdvancedEXT; }
+    pub struct vkCmdSetColorBlendEnableEXT; impl TypedCommand for vkCmdSetColorBlendEnableEXT { const NAME: &'static str = "vkCmdSetColorBlendEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetColorBlendEnableEXT; }
+    pub struct vkCmdSetColorBlendEquationEXT; impl TypedCommand for vkCmdSetColorBlendEquationEXT { const NAME: &'static str = "vkCmdSetColorBlendEquationEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetColorBlendEquationEXT; }
+    pub struct vkCmdSetColorWriteEnableEXT; impl TypedCommand for vkCmdSetColorWriteEnableEXT { const NAME: &'static str = "vkCmdSetColorWriteEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetColorWriteEnableEXT; }
+    pub struct vkCmdSetColorWriteMaskEXT; impl TypedCommand for vkCmdSetColorWriteMaskEXT { const NAME: &'static str = "vkCmdSetColorWriteMaskEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetColorWriteMaskEXT; }
+    pub struct vkCmdSetConservativeRasterizationModeEXT; impl TypedCommand for vkCmdSetConservativeRasterizationModeEXT { const NAME: &'static str = "vkCmdSetConservativeRasterizationModeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetConservativeRasterizationModeEXT; }
+    pub struct vkCmdSetCoverageModulationModeNV; impl TypedCommand for vkCmdSetCoverageModulationModeNV { const NAME: &'static str = "vkCmdSetCoverageModulationModeNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCoverageModulationModeNV; }
+    pub struct vkCmdSetCoverageModulationTableEnableNV; impl TypedCommand for vkCmdSetCoverageModulationTableEnableNV { const NAME: &'static str = "vkCmdSetCoverageModulationTableEnableNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCoverageModulationTableEnableNV; }
+    pub struct vkCmdSetCoverageModulationTableNV; impl TypedCommand for vkCmdSetCoverageModulationTableNV { const NAME: &'static str = "vkCmdSetCoverageModulationTableNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCoverageModulationTableNV; }
+    pub struct vkCmdSetCoverageReductionModeNV; impl TypedCommand for vkCmdSetCoverageReductionModeNV { const NAME: &'static str = "vkCmdSetCoverageReductionModeNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCoverageReductionModeNV; }
+    pub struct vkCmdSetCoverageToColorEnableNV; impl TypedCommand for vkCmdSetCoverageToColorEnableNV { const NAME: &'static str = "vkCmdSetCoverageToColorEnableNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCoverageToColorEnableNV; }
+    pub struct vkCmdSetCoverageToColorLocationNV; impl TypedCommand for vkCmdSetCoverageToColorLocationNV { const NAME: &'static str = "vkCmdSetCoverageToColorLocationNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCoverageToColorLocationNV; }
+    pub struct vkCmdSetCullMode; impl TypedCommand for vkCmdSetCullMode { const NAME: &'static str = "vkCmdSetCullMode"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCullMode; }
+    pub struct vkCmdSetCullModeEXT; impl TypedCommand for vkCmdSetCullModeEXT { const NAME: &'static str = "vkCmdSetCullModeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetCullMode; }
+    pub struct vkCmdSetDepthBias; impl TypedCommand for vkCmdSetDepthBias { const NAME: &'static str = "vkCmdSetDepthBias"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthBias; }
+    pub struct vkCmdSetDepthBiasEnable; impl TypedCommand for vkCmdSetDepthBiasEnable { const NAME: &'static str = "vkCmdSetDepthBiasEnable"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthBiasEnable; }
+    pub struct vkCmdSetDepthBiasEnableEXT; impl TypedCommand for vkCmdSetDepthBiasEnableEXT { const NAME: &'static str = "vkCmdSetDepthBiasEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthBiasEnable; }
+    pub struct vkCmdSetDepthBounds; impl TypedCommand for vkCmdSetDepthBounds { const NAME: &'static str = "vkCmdSetDepthBounds"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthBounds; }
+    pub struct vkCmdSetDepthBoundsTestEnable; impl TypedCommand for vkCmdSetDepthBoundsTestEnable { const NAME: &'static str = "vkCmdSetDepthBoundsTestEnable"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthBoundsTestEnable; }
+    pub struct vkCmdSetDepthBoundsTestEnableEXT; impl TypedCommand for vkCmdSetDepthBoundsTestEnableEXT { const NAME: &'static str = "vkCmdSetDepthBoundsTestEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthBoundsTestEnable; }
+    pub struct vkCmdSetDepthClampEnableEXT; impl TypedCommand for vkCmdSetDepthClampEnableEXT { const NAME: &'static str = "vkCmdSetDepthClampEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthClampEnableEXT; }
+    pub struct vkCmdSetDepthClipEnableEXT; impl TypedCommand for vkCmdSetDepthClipEnableEXT { const NAME: &'static str = "vkCmdSetDepthClipEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthClipEnableEXT; }
+    pub struct vkCmdSetDepthClipNegativeOneToOneEXT; impl TypedCommand for vkCmdSetDepthClipNegativeOneToOneEXT { const NAME: &'static str = "vkCmdSetDepthClipNegativeOneToOneEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthClipNegativeOneToOneEXT; }
+    pub struct vkCmdSetDepthCompareOp; impl TypedCommand for vkCmdSetDepthCompareOp { const NAME: &'static str = "vkCmdSetDepthCompareOp"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthCompareOp; }
+    pub struct vkCmdSetDepthCompareOpEXT; impl TypedCommand for vkCmdSetDepthCompareOpEXT { const NAME: &'static str = "vkCmdSetDepthCompareOpEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthCompareOp; }
+    pub struct vkCmdSetDepthTestEnable; impl TypedCommand for vkCmdSetDepthTestEnable { const NAME: &'static str = "vkCmdSetDepthTestEnable"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthTestEnable; }
+    pub struct vkCmdSetDepthTestEnableEXT; impl TypedCommand for vkCmdSetDepthTestEnableEXT { const NAME: &'static str = "vkCmdSetDepthTestEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthTestEnable; }
+    pub struct vkCmdSetDepthWriteEnable; impl TypedCommand for vkCmdSetDepthWriteEnable { const NAME: &'static str = "vkCmdSetDepthWriteEnable"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthWriteEnable; }
+    pub struct vkCmdSetDepthWriteEnableEXT; impl TypedCommand for vkCmdSetDepthWriteEnableEXT { const NAME: &'static str = "vkCmdSetDepthWriteEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDepthWriteEnable; }
+    pub struct vkCmdSetDescriptorBufferOffsetsEXT; impl TypedCommand for vkCmdSetDescriptorBufferOffsetsEXT { const NAME: &'static str = "vkCmdSetDescriptorBufferOffsetsEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDescriptorBufferOffsetsEXT; }
+    pub struct vkCmdSetDeviceMask; impl TypedCommand for vkCmdSetDeviceMask { const NAME: &'static str = "vkCmdSetDeviceMask"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDeviceMask; }
+    pub struct vkCmdSetDeviceMaskKHR; impl TypedCommand for vkCmdSetDeviceMaskKHR { const NAME: &'static str = "vkCmdSetDeviceMaskKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDeviceMask; }
+    pub struct vkCmdSetDiscardRectangleEXT; impl TypedCommand for vkCmdSetDiscardRectangleEXT { const NAME: &'static str = "vkCmdSetDiscardRectangleEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDiscardRectangleEXT; }
+    pub struct vkCmdSetDiscardRectangleEnableEXT; impl TypedCommand for vkCmdSetDiscardRectangleEnableEXT { const NAME: &'static str = "vkCmdSetDiscardRectangleEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDiscardRectangleEnableEXT; }
+    pub struct vkCmdSetDiscardRectangleModeEXT; impl TypedCommand for vkCmdSetDiscardRectangleModeEXT { const NAME: &'static str = "vkCmdSetDiscardRectangleModeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetDiscardRectangleModeEXT; }
+    pub struct vkCmdSetEvent; impl TypedCommand for vkCmdSetEvent { const NAME: &'static str = "vkCmdSetEvent"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetEvent; }
+    pub struct vkCmdSetEvent2; impl TypedCommand for vkCmdSetEvent2 { const NAME: &'static str = "vkCmdSetEvent2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetEvent2; }
+    pub struct vkCmdSetEvent2KHR; impl TypedCommand for vkCmdSetEvent2KHR { const NAME: &'static str = "vkCmdSetEvent2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetEvent2; }
+    pub struct vkCmdSetExclusiveScissorEnableNV; impl TypedCommand for vkCmdSetExclusiveScissorEnableNV { const NAME: &'static str = "vkCmdSetExclusiveScissorEnableNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetExclusiveScissorEnableNV; }
+    pub struct vkCmdSetExclusiveScissorNV; impl TypedCommand for vkCmdSetExclusiveScissorNV { const NAME: &'static str = "vkCmdSetExclusiveScissorNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetExclusiveScissorNV; }
+    pub struct vkCmdSetExtraPrimitiveOverestimationSizeEXT; impl TypedCommand for vkCmdSetExtraPrimitiveOverestimationSizeEXT { const NAME: &'static str = "vkCmdSetExtraPrimitiveOverestimationSizeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetExtraPrimitiveOverestimationSizeEXT; }
+    pub struct vkCmdSetFragmentShadingRateEnumNV; impl TypedCommand for vkCmdSetFragmentShadingRateEnumNV { const NAME: &'static str = "vkCmdSetFragmentShadingRateEnumNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetFragmentShadingRateEnumNV; }
+    pub struct vkCmdSetFragmentShadingRateKHR; impl TypedCommand for vkCmdSetFragmentShadingRateKHR { const NAME: &'static str = "vkCmdSetFragmentShadingRateKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetFragmentShadingRateKHR; }
+    pub struct vkCmdSetFrontFace; impl TypedCommand for vkCmdSetFrontFace { const NAME: &'static str = "vkCmdSetFrontFace"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetFrontFace; }
+    pub struct vkCmdSetFrontFaceEXT; impl TypedCommand for vkCmdSetFrontFaceEXT { const NAME: &'static str = "vkCmdSetFrontFaceEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetFrontFace; }
+    pub struct vkCmdSetLineRasterizationModeEXT; impl TypedCommand for vkCmdSetLineRasterizationModeEXT { const NAME: &'static str = "vkCmdSetLineRasterizationModeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetLineRasterizationModeEXT; }
+    pub struct vkCmdSetLineStippleEXT; impl TypedCommand for vkCmdSetLineStippleEXT { const NAME: &'static str = "vkCmdSetLineStippleEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetLineStippleEXT; }
+    pub struct vkCmdSetLineStippleEnableEXT; impl TypedCommand for vkCmdSetLineStippleEnableEXT { const NAME: &'static str = "vkCmdSetLineStippleEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetLineStippleEnableEXT; }
+    pub struct vkCmdSetLineWidth; impl TypedCommand for vkCmdSetLineWidth { const NAME: &'static str = "vkCmdSetLineWidth"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetLineWidth; }
+    pub struct vkCmdSetLogicOpEXT; impl TypedCommand for vkCmdSetLogicOpEXT { const NAME: &'static str = "vkCmdSetLogicOpEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetLogicOpEXT; }
+    pub struct vkCmdSetLogicOpEnableEXT; impl TypedCommand for vkCmdSetLogicOpEnableEXT { const NAME: &'static str = "vkCmdSetLogicOpEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetLogicOpEnableEXT; }
+    pub struct vkCmdSetPatchControlPointsEXT; impl TypedCommand for vkCmdSetPatchControlPointsEXT { const NAME: &'static str = "vkCmdSetPatchControlPointsEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetPatchControlPointsEXT; }
+    pub struct vkCmdSetPerformanceMarkerINTEL; impl TypedCommand for vkCmdSetPerformanceMarkerINTEL { const NAME: &'static str = "vkCmdSetPerformanceMarkerINTEL"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetPerformanceMarkerINTEL; }
+    pub struct vkCmdSetPerformanceOverrideINTEL; impl TypedCommand for vkCmdSetPerformanceOverrideINTEL { const NAME: &'static str = "vkCmdSetPerformanceOverrideINTEL"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetPerformanceOverrideINTEL; }
+    pub struct vkCmdSetPerformanceStreamMarkerINTEL; impl TypedCommand for vkCmdSetPerformanceStreamMarkerINTEL { const NAME: &'static str = "vkCmdSetPerformanceStreamMarkerINTEL"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetPerformanceStreamMarkerINTEL; }
+    pub struct vkCmdSetPolygonModeEXT; impl TypedCommand for vkCmdSetPolygonModeEXT { const NAME: &'static str = "vkCmdSetPolygonModeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetPolygonModeEXT; }
+    pub struct vkCmdSetPrimitiveRestartEnable; impl TypedCommand for vkCmdSetPrimitiveRestartEnable { const NAME: &'static str = "vkCmdSetPrimitiveRestartEnable"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetPrimitiveRestartEnable; }
+    pub struct vkCmdSetPrimitiveRestartEnableEXT; impl TypedCommand for vkCmdSetPrimitiveRestartEnableEXT { const NAME: &'static str = "vkCmdSetPrimitiveRestartEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetPrimitiveRestartEnable; }
+    pub struct vkCmdSetPrimitiveTopology; impl TypedCommand for vkCmdSetPrimitiveTopology { const NAME: &'static str = "vkCmdSetPrimitiveTopology"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetPrimitiveTopology; }
+    pub struct vkCmdSetPrimitiveTopologyEXT; impl TypedCommand for vkCmdSetPrimitiveTopologyEXT { const NAME: &'static str = "vkCmdSetPrimitiveTopologyEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetPrimitiveTopology; }
+    pub struct vkCmdSetProvokingVertexModeEXT; impl TypedCommand for vkCmdSetProvokingVertexModeEXT { const NAME: &'static str = "vkCmdSetProvokingVertexModeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetProvokingVertexModeEXT; }
+    pub struct vkCmdSetRasterizationSamplesEXT; impl TypedCommand for vkCmdSetRasterizationSamplesEXT { const NAME: &'static str = "vkCmdSetRasterizationSamplesEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetRasterizationSamplesEXT; }
+    pub struct vkCmdSetRasterizationStreamEXT; impl TypedCommand for vkCmdSetRasterizationStreamEXT { const NAME: &'static str = "vkCmdSetRasterizationStreamEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetRasterizationStreamEXT; }
+    pub struct vkCmdSetRasterizerDiscardEnable; impl TypedCommand for vkCmdSetRasterizerDiscardEnable { const NAME: &'static str = "vkCmdSetRasterizerDiscardEnable"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetRasterizerDiscardEnable; }
+    pub struct vkCmdSetRasterizerDiscardEnableEXT; impl TypedCommand for vkCmdSetRasterizerDiscardEnableEXT { const NAME: &'static str = "vkCmdSetRasterizerDiscardEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetRasterizerDiscardEnable; }
+    pub struct vkCmdSetRayTracingPipelineStackSizeKHR; impl TypedCommand for vkCmdSetRayTracingPipelineStackSizeKHR { const NAME: &'static str = "vkCmdSetRayTracingPipelineStackSizeKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetRayTracingPipelineStackSizeKHR; }
+    pub struct vkCmdSetRepresentativeFragmentTestEnableNV; impl TypedCommand for vkCmdSetRepresentativeFragmentTestEnableNV { const NAME: &'static str = "vkCmdSetRepresentativeFragmentTestEnableNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetRepresentativeFragmentTestEnableNV; }
+    pub struct vkCmdSetSampleLocationsEXT; impl TypedCommand for vkCmdSetSampleLocationsEXT { const NAME: &'static str = "vkCmdSetSampleLocationsEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetSampleLocationsEXT; }
+    pub struct vkCmdSetSampleLocationsEnableEXT; impl TypedCommand for vkCmdSetSampleLocationsEnableEXT { const NAME: &'static str = "vkCmdSetSampleLocationsEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetSampleLocationsEnableEXT; }
+    pub struct vkCmdSetSampleMaskEXT; impl TypedCommand for vkCmdSetSampleMaskEXT { const NAME: &'static str = "vkCmdSetSampleMaskEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetSampleMaskEXT; }
+    pub struct vkCmdSetScissor; impl TypedCommand for vkCmdSetScissor { const NAME: &'static str = "vkCmdSetScissor"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetScissor; }
+    pub struct vkCmdSetScissorWithCount; impl TypedCommand for vkCmdSetScissorWithCount { const NAME: &'static str = "vkCmdSetScissorWithCount"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetScissorWithCount; }
+    pub struct vkCmdSetScissorWithCountEXT; impl TypedCommand for vkCmdSetScissorWithCountEXT { const NAME: &'static str = "vkCmdSetScissorWithCountEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetScissorWithCount; }
+    pub struct vkCmdSetShadingRateImageEnableNV; impl TypedCommand for vkCmdSetShadingRateImageEnableNV { const NAME: &'static str = "vkCmdSetShadingRateImageEnableNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetShadingRateImageEnableNV; }
+    pub struct vkCmdSetStencilCompareMask; impl TypedCommand for vkCmdSetStencilCompareMask { const NAME: &'static str = "vkCmdSetStencilCompareMask"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetStencilCompareMask; }
+    pub struct vkCmdSetStencilOp; impl TypedCommand for vkCmdSetStencilOp { const NAME: &'static str = "vkCmdSetStencilOp"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetStencilOp; }
+    pub struct vkCmdSetStencilOpEXT; impl TypedCommand for vkCmdSetStencilOpEXT { const NAME: &'static str = "vkCmdSetStencilOpEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetStencilOp; }
+    pub struct vkCmdSetStencilReference; impl TypedCommand for vkCmdSetStencilReference { const NAME: &'static str = "vkCmdSetStencilReference"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetStencilReference; }
+    pub struct vkCmdSetStencilTestEnable; impl TypedCommand for vkCmdSetStencilTestEnable { const NAME: &'static str = "vkCmdSetStencilTestEnable"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetStencilTestEnable; }
+    pub struct vkCmdSetStencilTestEnableEXT; impl TypedCommand for vkCmdSetStencilTestEnableEXT { const NAME: &'static str = "vkCmdSetStencilTestEnableEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetStencilTestEnable; }
+    pub struct vkCmdSetStencilWriteMask; impl TypedCommand for vkCmdSetStencilWriteMask { const NAME: &'static str = "vkCmdSetStencilWriteMask"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetStencilWriteMask; }
+    pub struct vkCmdSetTessellationDomainOriginEXT; impl TypedCommand for vkCmdSetTessellationDomainOriginEXT { const NAME: &'static str = "vkCmdSetTessellationDomainOriginEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetTessellationDomainOriginEXT; }
+    pub struct vkCmdSetVertexInputEXT; impl TypedCommand for vkCmdSetVertexInputEXT { const NAME: &'static str = "vkCmdSetVertexInputEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetVertexInputEXT; }
+    pub struct vkCmdSetViewport; impl TypedCommand for vkCmdSetViewport { const NAME: &'static str = "vkCmdSetViewport"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetViewport; }
+    pub struct vkCmdSetViewportShadingRatePaletteNV; impl TypedCommand for vkCmdSetViewportShadingRatePaletteNV { const NAME: &'static str = "vkCmdSetViewportShadingRatePaletteNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetViewportShadingRatePaletteNV; }
+    pub struct vkCmdSetViewportSwizzleNV; impl TypedCommand for vkCmdSetViewportSwizzleNV { const NAME: &'static str = "vkCmdSetViewportSwizzleNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetViewportSwizzleNV; }
+    pub struct vkCmdSetViewportWScalingEnableNV; impl TypedCommand for vkCmdSetViewportWScalingEnableNV { const NAME: &'static str = "vkCmdSetViewportWScalingEnableNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetViewportWScalingEnableNV; }
+    pub struct vkCmdSetViewportWScalingNV; impl TypedCommand for vkCmdSetViewportWScalingNV { const NAME: &'static str = "vkCmdSetViewportWScalingNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetViewportWScalingNV; }
+    pub struct vkCmdSetViewportWithCount; impl TypedCommand for vkCmdSetViewportWithCount { const NAME: &'static str = "vkCmdSetViewportWithCount"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetViewportWithCount; }
+    pub struct vkCmdSetViewportWithCountEXT; impl TypedCommand for vkCmdSetViewportWithCountEXT { const NAME: &'static str = "vkCmdSetViewportWithCountEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSetViewportWithCount; }
+    pub struct vkCmdSubpassShadingHUAWEI; impl TypedCommand for vkCmdSubpassShadingHUAWEI { const NAME: &'static str = "vkCmdSubpassShadingHUAWEI"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdSubpassShadingHUAWEI; }
+    pub struct vkCmdTraceRaysIndirect2KHR; impl TypedCommand for vkCmdTraceRaysIndirect2KHR { const NAME: &'static str = "vkCmdTraceRaysIndirect2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdTraceRaysIndirect2KHR; }
+    pub struct vkCmdTraceRaysIndirectKHR; impl TypedCommand for vkCmdTraceRaysIndirectKHR { const NAME: &'static str = "vkCmdTraceRaysIndirectKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdTraceRaysIndirectKHR; }
+    pub struct vkCmdTraceRaysKHR; impl TypedCommand for vkCmdTraceRaysKHR { const NAME: &'static str = "vkCmdTraceRaysKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdTraceRaysKHR; }
+    pub struct vkCmdTraceRaysNV; impl TypedCommand for vkCmdTraceRaysNV { const NAME: &'static str = "vkCmdTraceRaysNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdTraceRaysNV; }
+    pub struct vkCmdUpdateBuffer; impl TypedCommand for vkCmdUpdateBuffer { const NAME: &'static str = "vkCmdUpdateBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdUpdateBuffer; }
+    pub struct vkCmdWaitEvents; impl TypedCommand for vkCmdWaitEvents { const NAME: &'static str = "vkCmdWaitEvents"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWaitEvents; }
+    pub struct vkCmdWaitEvents2; impl TypedCommand for vkCmdWaitEvents2 { const NAME: &'static str = "vkCmdWaitEvents2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWaitEvents2; }
+    pub struct vkCmdWaitEvents2KHR; impl TypedCommand for vkCmdWaitEvents2KHR { const NAME: &'static str = "vkCmdWaitEvents2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWaitEvents2; }
+    pub struct vkCmdWriteAccelerationStructuresPropertiesKHR; impl TypedCommand for vkCmdWriteAccelerationStructuresPropertiesKHR { const NAME: &'static str = "vkCmdWriteAccelerationStructuresPropertiesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWriteAccelerationStructuresPropertiesKHR; }
+    pub struct vkCmdWriteAccelerationStructuresPropertiesNV; impl TypedCommand for vkCmdWriteAccelerationStructuresPropertiesNV { const NAME: &'static str = "vkCmdWriteAccelerationStructuresPropertiesNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWriteAccelerationStructuresPropertiesNV; }
+    pub struct vkCmdWriteBufferMarker2AMD; impl TypedCommand for vkCmdWriteBufferMarker2AMD { const NAME: &'static str = "vkCmdWriteBufferMarker2AMD"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWriteBufferMarker2AMD; }
+    pub struct vkCmdWriteBufferMarkerAMD; impl TypedCommand for vkCmdWriteBufferMarkerAMD { const NAME: &'static str = "vkCmdWriteBufferMarkerAMD"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWriteBufferMarkerAMD; }
+    pub struct vkCmdWriteMicromapsPropertiesEXT; impl TypedCommand for vkCmdWriteMicromapsPropertiesEXT { const NAME: &'static str = "vkCmdWriteMicromapsPropertiesEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWriteMicromapsPropertiesEXT; }
+    pub struct vkCmdWriteTimestamp; impl TypedCommand for vkCmdWriteTimestamp { const NAME: &'static str = "vkCmdWriteTimestamp"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWriteTimestamp; }
+    pub struct vkCmdWriteTimestamp2; impl TypedCommand for vkCmdWriteTimestamp2 { const NAME: &'static str = "vkCmdWriteTimestamp2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWriteTimestamp2; }
+    pub struct vkCmdWriteTimestamp2KHR; impl TypedCommand for vkCmdWriteTimestamp2KHR { const NAME: &'static str = "vkCmdWriteTimestamp2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCmdWriteTimestamp2; }
+    pub struct vkCompileDeferredNV; impl TypedCommand for vkCompileDeferredNV { const NAME: &'static str = "vkCompileDeferredNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCompileDeferredNV; }
+    pub struct vkCopyAccelerationStructureKHR; impl TypedCommand for vkCopyAccelerationStructureKHR { const NAME: &'static str = "vkCopyAccelerationStructureKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCopyAccelerationStructureKHR; }
+    pub struct vkCopyAccelerationStructureToMemoryKHR; impl TypedCommand for vkCopyAccelerationStructureToMemoryKHR { const NAME: &'static str = "vkCopyAccelerationStructureToMemoryKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCopyAccelerationStructureToMemoryKHR; }
+    pub struct vkCopyMemoryToAccelerationStructureKHR; impl TypedCommand for vkCopyMemoryToAccelerationStructureKHR { const NAME: &'static str = "vkCopyMemoryToAccelerationStructureKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCopyMemoryToAccelerationStructureKHR; }
+    pub struct vkCopyMemoryToMicromapEXT; impl TypedCommand for vkCopyMemoryToMicromapEXT { const NAME: &'static str = "vkCopyMemoryToMicromapEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCopyMemoryToMicromapEXT; }
+    pub struct vkCopyMicromapEXT; impl TypedCommand for vkCopyMicromapEXT { const NAME: &'static str = "vkCopyMicromapEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCopyMicromapEXT; }
+    pub struct vkCopyMicromapToMemoryEXT; impl TypedCommand for vkCopyMicromapToMemoryEXT { const NAME: &'static str = "vkCopyMicromapToMemoryEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCopyMicromapToMemoryEXT; }
+    pub struct vkCreateAccelerationStructureKHR; impl TypedCommand for vkCreateAccelerationStructureKHR { const NAME: &'static str = "vkCreateAccelerationStructureKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateAccelerationStructureKHR; }
+    pub struct vkCreateAccelerationStructureNV; impl TypedCommand for vkCreateAccelerationStructureNV { const NAME: &'static str = "vkCreateAccelerationStructureNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateAccelerationStructureNV; }
+    pub struct vkCreateAndroidSurfaceKHR; impl TypedCommand for vkCreateAndroidSurfaceKHR { const NAME: &'static str = "vkCreateAndroidSurfaceKHR"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateAndroidSurfaceKHR; }
+    pub struct vkCreateBuffer; impl TypedCommand for vkCreateBuffer { const NAME: &'static str = "vkCreateBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateBuffer; }
+    pub struct vkCreateBufferCollectionFUCHSIA; impl TypedCommand for vkCreateBufferCollectionFUCHSIA { const NAME: &'static str = "vkCreateBufferCollectionFUCHSIA"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateBufferCollectionFUCHSIA; }
+    pub struct vkCreateBufferView; impl TypedCommand for vkCreateBufferView { const NAME: &'static str = "vkCreateBufferView"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateBufferView; }
+    pub struct vkCreateCommandPool; impl TypedCommand for vkCreateCommandPool { const NAME: &'static str = "vkCreateCommandPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateCommandPool; }
+    pub struct vkCreateComputePipelines; impl TypedCommand for vkCreateComputePipelines { const NAME: &'static str = "vkCreateComputePipelines"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateComputePipelines; }
+    pub struct vkCreateCuFunctionNVX; impl TypedCommand for vkCreateCuFunctionNVX { const NAME: &'static str = "vkCreateCuFunctionNVX"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateCuFunctionNVX; }
+    pub struct vkCreateCuModuleNVX; impl TypedCommand for vkCreateCuModuleNVX { const NAME: &'static str = "vkCreateCuModuleNVX"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateCuModuleNVX; }
+    pub struct vkCreateDebugReportCallbackEXT; impl TypedCommand for vkCreateDebugReportCallbackEXT { const NAME: &'static str = "vkCreateDebugReportCallbackEXT"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateDebugReportCallbackEXT; }
+    pub struct vkCreateDebugUtilsMessengerEXT; impl TypedCommand for vkCreateDebugUtilsMessengerEXT { const NAME: &'static str = "vkCreateDebugUtilsMessengerEXT"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateDebugUtilsMessengerEXT; }
+    pub struct vkCreateDeferredOperationKHR; impl TypedCommand for vkCreateDeferredOperationKHR { const NAME: &'static str = "vkCreateDeferredOperationKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateDeferredOperationKHR; }
+    pub struct vkCreateDescriptorPool; impl TypedCommand for vkCreateDescriptorPool { const NAME: &'static str = "vkCreateDescriptorPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateDescriptorPool; }
+    pub struct vkCreateDescriptorSetLayout; impl TypedCommand for vkCreateDescriptorSetLayout { const NAME: &'static str = "vkCreateDescriptorSetLayout"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateDescriptorSetLayout; }
+    pub struct vkCreateDescriptorUpdateTemplate; impl TypedCommand for vkCreateDescriptorUpdateTemplate { const NAME: &'static str = "vkCreateDescriptorUpdateTemplate"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateDescriptorUpdateTemplate; }
+    pub struct vkCreateDescriptorUpdateTemplateKHR; impl TypedCommand for vkCreateDescriptorUpdateTemplateKHR { const NAME: &'static str = "vkCreateDescriptorUpdateTemplateKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateDescriptorUpdateTemplate; }
+    pub struct vkCreateDevice; impl TypedCommand for vkCreateDevice { const NAME: &'static str = "vkCreateDevice"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkCreateDevice; }
+    pub struct vkCreateDirectFBSurfaceEXT; impl TypedCommand for vkCreateDirectFBSurfaceEXT { const NAME: &'static str = "vkCreateDirectFBSurfaceEXT"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateDirectFBSurfaceEXT; }
+    pub struct vkCreateDisplayModeKHR; impl TypedCommand for vkCreateDisplayModeKHR { const NAME: &'static str = "vkCreateDisplayModeKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkCreateDisplayModeKHR; }
+    pub struct vkCreateDisplayPlaneSurfaceKHR; impl TypedCommand for vkCreateDisplayPlaneSurfaceKHR { const NAME: &'static str = "vkCreateDisplayPlaneSurfaceKHR"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateDisplayPlaneSurfaceKHR; }
+    pub struct vkCreateEvent; impl TypedCommand for vkCreateEvent { const NAME: &'static str = "vkCreateEvent"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateEvent; }
+    pub struct vkCreateFence; impl TypedCommand for vkCreateFence { const NAME: &'static str = "vkCreateFence"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateFence; }
+    pub struct vkCreateFramebuffer; impl TypedCommand for vkCreateFramebuffer { const NAME: &'static str = "vkCreateFramebuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateFramebuffer; }
+    pub struct vkCreateGraphicsPipelines; impl TypedCommand for vkCreateGraphicsPipelines { const NAME: &'static str = "vkCreateGraphicsPipelines"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateGraphicsPipelines; }
+    pub struct vkCreateHeadlessSurfaceEXT; impl TypedCommand for vkCreateHeadlessSurfaceEXT { const NAME: &'static str = "vkCreateHeadlessSurfaceEXT"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateHeadlessSurfaceEXT; }
+    pub struct vkCreateIOSSurfaceMVK; impl TypedCommand for vkCreateIOSSurfaceMVK { const NAME: &'static str = "vkCreateIOSSurfaceMVK"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateIOSSurfaceMVK; }
+    pub struct vkCreateImage; impl TypedCommand for vkCreateImage { const NAME: &'static str = "vkCreateImage"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateImage; }
+    pub struct vkCreateImagePipeSurfaceFUCHSIA; impl TypedCommand for vkCreateImagePipeSurfaceFUCHSIA { const NAME: &'static str = "vkCreateImagePipeSurfaceFUCHSIA"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateImagePipeSurfaceFUCHSIA; }
+    pub struct vkCreateImageView; impl TypedCommand for vkCreateImageView { const NAME: &'static str = "vkCreateImageView"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateImageView; }
+    pub struct vkCreateIndirectCommandsLayoutNV; impl TypedCommand for vkCreateIndirectCommandsLayoutNV { const NAME: &'static str = "vkCreateIndirectCommandsLayoutNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateIndirectCommandsLayoutNV; }
+    pub struct vkCreateMacOSSurfaceMVK; impl TypedCommand for vkCreateMacOSSurfaceMVK { const NAME: &'static str = "vkCreateMacOSSurfaceMVK"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateMacOSSurfaceMVK; }
+    pub struct vkCreateMetalSurfaceEXT; impl TypedCommand for vkCreateMetalSurfaceEXT { const NAME: &'static str = "vkCreateMetalSurfaceEXT"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateMetalSurfaceEXT; }
+    pub struct vkCreateMicromapEXT; impl TypedCommand for vkCreateMicromapEXT { const NAME: &'static str = "vkCreateMicromapEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateMicromapEXT; }
+    pub struct vkCreateOpticalFlowSessionNV; impl TypedCommand for vkCreateOpticalFlowSessionNV { const NAME: &'static str = "vkCreateOpticalFlowSessionNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateOpticalFlowSessionNV; }
+    pub struct vkCreatePipelineCache; impl TypedCommand for vkCreatePipelineCache { const NAME: &'static str = "vkCreatePipelineCache"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreatePipelineCache; }
+    pub struct vkCreatePipelineLayout; impl TypedCommand for vkCreatePipelineLayout { const NAME: &'static str = "vkCreatePipelineLayout"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreatePipelineLayout; }
+    pub struct vkCreatePrivateDataSlot; impl TypedCommand for vkCreatePrivateDataSlot { const NAME: &'static str = "vkCreatePrivateDataSlot"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreatePrivateDataSlot; }
+    pub struct vkCreatePrivateDataSlotEXT; impl TypedCommand for vkCreatePrivateDataSlotEXT { const NAME: &'static str = "vkCreatePrivateDataSlotEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreatePrivateDataSlot; }
+    pub struct vkCreateQueryPool; impl TypedCommand for vkCreateQueryPool { const NAME: &'static str = "vkCreateQueryPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateQueryPool; }
+    pub struct vkCreateRayTracingPipelinesKHR; impl TypedCommand for vkCreateRayTracingPipelinesKHR { const NAME: &'static str = "vkCreateRayTracingPipelinesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateRayTracingPipelinesKHR; }
+    pub struct vkCreateRayTracingPipelinesNV; impl TypedCommand for vkCreateRayTracingPipelinesNV { const NAME: &'static str = "vkCreateRayTracingPipelinesNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateRayTracingPipelinesNV; }
+    pub struct vkCreateRenderPass; impl TypedCommand for vkCreateRenderPass { const NAME: &'static str = "vkCreateRenderPass"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateRenderPass; }
+    pub struct vkCreateRenderPass2; impl TypedCommand for vkCreateRenderPass2 { const NAME: &'static str = "vkCreateRenderPass2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateRenderPass2; }
+    pub struct vkCreateRenderPass2KHR; impl TypedCommand for vkCreateRenderPass2KHR { const NAME: &'static str = "vkCreateRenderPass2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateRenderPass2; }
+    pub struct vkCreateSampler; impl TypedCommand for vkCreateSampler { const NAME: &'static str = "vkCreateSampler"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateSampler; }
+    pub struct vkCreateSamplerYcbcrConversion; impl TypedCommand for vkCreateSamplerYcbcrConversion { const NAME: &'static str = "vkCreateSamplerYcbcrConversion"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateSamplerYcbcrConversion; }
+    pub struct vkCreateSamplerYcbcrConversionKHR; impl TypedCommand for vkCreateSamplerYcbcrConversionKHR { const NAME: &'static str = "vkCreateSamplerYcbcrConversionKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateSamplerYcbcrConversion; }
+    pub struct vkCreateScreenSurfaceQNX; impl TypedCommand for vkCreateScreenSurfaceQNX { const NAME: &'static str = "vkCreateScreenSurfaceQNX"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateScreenSurfaceQNX; }
+    pub struct vkCreateSemaphore; impl TypedCommand for vkCreateSemaphore { const NAME: &'static str = "vkCreateSemaphore"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateSemaphore; }
+    pub struct vkCreateShaderModule; impl TypedCommand for vkCreateShaderModule { const NAME: &'static str = "vkCreateShaderModule"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateShaderModule; }
+    pub struct vkCreateShadersEXT; impl TypedCommand for vkCreateShadersEXT { const NAME: &'static str = "vkCreateShadersEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateShadersEXT; }
+    pub struct vkCreateSharedSwapchainsKHR; impl TypedCommand for vkCreateSharedSwapchainsKHR { const NAME: &'static str = "vkCreateSharedSwapchainsKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateSharedSwapchainsKHR; }
+    pub struct vkCreateStreamDescriptorSurfaceGGP; impl TypedCommand for vkCreateStreamDescriptorSurfaceGGP { const NAME: &'static str = "vkCreateStreamDescriptorSurfaceGGP"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateStreamDescriptorSurfaceGGP; }
+    pub struct vkCreateSwapchainKHR; impl TypedCommand for vkCreateSwapchainKHR { const NAME: &'static str = "vkCreateSwapchainKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateSwapchainKHR; }
+    pub struct vkCreateValidationCacheEXT; impl TypedCommand for vkCreateValidationCacheEXT { const NAME: &'static str = "vkCreateValidationCacheEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateValidationCacheEXT; }
+    pub struct vkCreateViSurfaceNN; impl TypedCommand for vkCreateViSurfaceNN { const NAME: &'static str = "vkCreateViSurfaceNN"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateViSurfaceNN; }
+    pub struct vkCreateVideoSessionKHR; impl TypedCommand for vkCreateVideoSessionKHR { const NAME: &'static str = "vkCreateVideoSessionKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateVideoSessionKHR; }
+    pub struct vkCreateVideoSessionParametersKHR; impl TypedCommand for vkCreateVideoSessionParametersKHR { const NAME: &'static str = "vkCreateVideoSessionParametersKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkCreateVideoSessionParametersKHR; }
+    pub struct vkCreateWaylandSurfaceKHR; impl TypedCommand for vkCreateWaylandSurfaceKHR { const NAME: &'static str = "vkCreateWaylandSurfaceKHR"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateWaylandSurfaceKHR; }
+    pub struct vkCreateWin32SurfaceKHR; impl TypedCommand for vkCreateWin32SurfaceKHR { const NAME: &'static str = "vkCreateWin32SurfaceKHR"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateWin32SurfaceKHR; }
+    pub struct vkCreateXcbSurfaceKHR; impl TypedCommand for vkCreateXcbSurfaceKHR { const NAME: &'static str = "vkCreateXcbSurfaceKHR"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateXcbSurfaceKHR; }
+    pub struct vkCreateXlibSurfaceKHR; impl TypedCommand for vkCreateXlibSurfaceKHR { const NAME: &'static str = "vkCreateXlibSurfaceKHR"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkCreateXlibSurfaceKHR; }
+    pub struct vkDebugMarkerSetObjectNameEXT; impl TypedCommand for vkDebugMarkerSetObjectNameEXT { const NAME: &'static str = "vkDebugMarkerSetObjectNameEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDebugMarkerSetObjectNameEXT; }
+    pub struct vkDebugMarkerSetObjectTagEXT; impl TypedCommand for vkDebugMarkerSetObjectTagEXT { const NAME: &'static str = "vkDebugMarkerSetObjectTagEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDebugMarkerSetObjectTagEXT; }
+    pub struct vkDebugReportMessageEXT; impl TypedCommand for vkDebugReportMessageEXT { const NAME: &'static str = "vkDebugReportMessageEXT"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkDebugReportMessageEXT; }
+    pub struct vkDeferredOperationJoinKHR; impl TypedCommand for vkDeferredOperationJoinKHR { const NAME: &'static str = "vkDeferredOperationJoinKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDeferredOperationJoinKHR; }
+    pub struct vkDestroyAccelerationStructureKHR; impl TypedCommand for vkDestroyAccelerationStructureKHR { const NAME: &'static str = "vkDestroyAccelerationStructureKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyAccelerationStructureKHR; }
+    pub struct vkDestroyAccelerationStructureNV; impl TypedCommand for vkDestroyAccelerationStructureNV { const NAME: &'static str = "vkDestroyAccelerationStructureNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyAccelerationStructureNV; }
+    pub struct vkDestroyBuffer; impl TypedCommand for vkDestroyBuffer { const NAME: &'static str = "vkDestroyBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyBuffer; }
+    pub struct vkDestroyBufferCollectionFUCHSIA; impl TypedCommand for vkDestroyBufferCollectionFUCHSIA { const NAME: &'static str = "vkDestroyBufferCollectionFUCHSIA"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyBufferCollectionFUCHSIA; }
+    pub struct vkDestroyBufferView; impl TypedCommand for vkDestroyBufferView { const NAME: &'static str = "vkDestroyBufferView"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyBufferView; }
+    pub struct vkDestroyCommandPool; impl TypedCommand for vkDestroyCommandPool { const NAME: &'static str = "vkDestroyCommandPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyCommandPool; }
+    pub struct vkDestroyCuFunctionNVX; impl TypedCommand for vkDestroyCuFunctionNVX { const NAME: &'static str = "vkDestroyCuFunctionNVX"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyCuFunctionNVX; }
+    pub struct vkDestroyCuModuleNVX; impl TypedCommand for vkDestroyCuModuleNVX { const NAME: &'static str = "vkDestroyCuModuleNVX"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyCuModuleNVX; }
+    pub struct vkDestroyDebugReportCallbackEXT; impl TypedCommand for vkDestroyDebugReportCallbackEXT { const NAME: &'static str = "vkDestroyDebugReportCallbackEXT"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkDestroyDebugReportCallbackEXT; }
+    pub struct vkDestroyDebugUtilsMessengerEXT; impl TypedCommand for vkDestroyDebugUtilsMessengerEXT { const NAME: &'static str = "vkDestroyDebugUtilsMessengerEXT"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkDestroyDebugUtilsMessengerEXT; }
+    pub struct vkDestroyDeferredOperationKHR; impl TypedCommand for vkDestroyDeferredOperationKHR { const NAME: &'static str = "vkDestroyDeferredOperationKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyDeferredOperationKHR; }
+    pub struct vkDestroyDescriptorPool; impl TypedCommand for vkDestroyDescriptorPool { const NAME: &'static str = "vkDestroyDescriptorPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyDescriptorPool; }
+    pub struct vkDestroyDescriptorSetLayout; impl TypedCommand for vkDestroyDescriptorSetLayout { const NAME: &'static str = "vkDestroyDescriptorSetLayout"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyDescriptorSetLayout; }
+    pub struct vkDestroyDescriptorUpdateTemplate; impl TypedCommand for vkDestroyDescriptorUpdateTemplate { const NAME: &'static str = "vkDestroyDescriptorUpdateTemplate"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyDescriptorUpdateTemplate; }
+    pub struct vkDestroyDescriptorUpdateTemplateKHR; impl TypedCommand for vkDestroyDescriptorUpdateTemplateKHR { const NAME: &'static str = "vkDestroyDescriptorUpdateTemplateKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyDescriptorUpdateTemplate; }
+    pub struct vkDestroyDevice; impl TypedCommand for vkDestroyDevice { const NAME: &'static str = "vkDestroyDevice"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyDevice; }
+    pub struct vkDestroyEvent; impl TypedCommand for vkDestroyEvent { const NAME: &'static str = "vkDestroyEvent"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyEvent; }
+    pub struct vkDestroyFence; impl TypedCommand for vkDestroyFence { const NAME: &'static str = "vkDestroyFence"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyFence; }
+    pub struct vkDestroyFramebuffer; impl TypedCommand for vkDestroyFramebuffer { const NAME: &'static str = "vkDestroyFramebuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyFramebuffer; }
+    pub struct vkDestroyImage; impl TypedCommand for vkDestroyImage { const NAME: &'static str = "vkDestroyImage"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyImage; }
+    pub struct vkDestroyImageView; impl TypedCommand for vkDestroyImageView { const NAME: &'static str = "vkDestroyImageView"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyImageView; }
+    pub struct vkDestroyIndirectCommandsLayoutNV; impl TypedCommand for vkDestroyIndirectCommandsLayoutNV { const NAME: &'static str = "vkDestroyIndirectCommandsLayoutNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyIndirectCommandsLayoutNV; }
+    pub struct vkDestroyInstance; impl TypedCommand for vkDestroyInstance { const NAME: &'static str = "vkDestroyInstance"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkDestroyInstance; }
+    pub struct vkDestroyMicromapEXT; impl TypedCommand for vkDestroyMicromapEXT { const NAME: &'static str = "vkDestroyMicromapEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyMicromapEXT; }
+    pub struct vkDestroyOpticalFlowSessionNV; impl TypedCommand for vkDestroyOpticalFlowSessionNV { const NAME: &'static str = "vkDestroyOpticalFlowSessionNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyOpticalFlowSessionNV; }
+    pub struct vkDestroyPipeline; impl TypedCommand for vkDestroyPipeline { const NAME: &'static str = "vkDestroyPipeline"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyPipeline; }
+    pub struct vkDestroyPipelineCache; impl TypedCommand for vkDestroyPipelineCache { const NAME: &'static str = "vkDestroyPipelineCache"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyPipelineCache; }
+    pub struct vkDestroyPipelineLayout; impl TypedCommand for vkDestroyPipelineLayout { const NAME: &'static str = "vkDestroyPipelineLayout"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyPipelineLayout; }
+    pub struct vkDestroyPrivateDataSlot; impl TypedCommand for vkDestroyPrivateDataSlot { const NAME: &'static str = "vkDestroyPrivateDataSlot"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyPrivateDataSlot; }
+    pub struct vkDestroyPrivateDataSlotEXT; impl TypedCommand for vkDestroyPrivateDataSlotEXT { const NAME: &'static str = "vkDestroyPrivateDataSlotEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyPrivateDataSlot; }
+    pub struct vkDestroyQueryPool; impl TypedCommand for vkDestroyQueryPool { const NAME: &'static str = "vkDestroyQueryPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyQueryPool; }
+    pub struct vkDestroyRenderPass; impl TypedCommand for vkDestroyRenderPass { const NAME: &'static str = "vkDestroyRenderPass"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyRenderPass; }
+    pub struct vkDestroySampler; impl TypedCommand for vkDestroySampler { const NAME: &'static str = "vkDestroySampler"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroySampler; }
+    pub struct vkDestroySamplerYcbcrConversion; impl TypedCommand for vkDestroySamplerYcbcrConversion { const NAME: &'static str = "vkDestroySamplerYcbcrConversion"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroySamplerYcbcrConversion; }
+    pub struct vkDestroySamplerYcbcrConversionKHR; impl TypedCommand for vkDestroySamplerYcbcrConversionKHR { const NAME: &'static str = "vkDestroySamplerYcbcrConversionKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroySamplerYcbcrConversion; }
+    pub struct vkDestroySemaphore; impl TypedCommand for vkDestroySemaphore { const NAME: &'static str = "vkDestroySemaphore"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroySemaphore; }
+    pub struct vkDestroyShaderEXT; impl TypedCommand for vkDestroyShaderEXT { const NAME: &'static str = "vkDestroyShaderEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyShaderEXT; }
+    pub struct vkDestroyShaderModule; impl TypedCommand for vkDestroyShaderModule { const NAME: &'static str = "vkDestroyShaderModule"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyShaderModule; }
+    pub struct vkDestroySurfaceKHR; impl TypedCommand for vkDestroySurfaceKHR { const NAME: &'static str = "vkDestroySurfaceKHR"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkDestroySurfaceKHR; }
+    pub struct vkDestroySwapchainKHR; impl TypedCommand for vkDestroySwapchainKHR { const NAME: &'static str = "vkDestroySwapchainKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroySwapchainKHR; }
+    pub struct vkDestroyValidationCacheEXT; impl TypedCommand for vkDestroyValidationCacheEXT { const NAME: &'static str = "vkDestroyValidationCacheEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyValidationCacheEXT; }
+    pub struct vkDestroyVideoSessionKHR; impl TypedCommand for vkDestroyVideoSessionKHR { const NAME: &'static str = "vkDestroyVideoSessionKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyVideoSessionKHR; }
+    pub struct vkDestroyVideoSessionParametersKHR; impl TypedCommand for vkDestroyVideoSessionParametersKHR { const NAME: &'static str = "vkDestroyVideoSessionParametersKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDestroyVideoSessionParametersKHR; }
+    pub struct vkDeviceWaitIdle; impl TypedCommand for vkDeviceWaitIdle { const NAME: &'static str = "vkDeviceWaitIdle"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDeviceWaitIdle; }
+    pub struct vkDisplayPowerControlEXT; impl TypedCommand for vkDisplayPowerControlEXT { const NAME: &'static str = "vkDisplayPowerControlEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkDisplayPowerControlEXT; }
+    pub struct vkEndCommandBuffer; impl TypedCommand for vkEndCommandBuffer { const NAME: &'static str = "vkEndCommandBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkEndCommandBuffer; }
+    pub struct vkEnumerateDeviceExtensionProperties; impl TypedCommand for vkEnumerateDeviceExtensionProperties { const NAME: &'static str = "vkEnumerateDeviceExtensionProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkEnumerateDeviceExtensionProperties; }
+    pub struct vkEnumerateDeviceLayerProperties; impl TypedCommand for vkEnumerateDeviceLayerProperties { const NAME: &'static str = "vkEnumerateDeviceLayerProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkEnumerateDeviceLayerProperties; }
+    pub struct vkEnumeratePhysicalDeviceGroups; impl TypedCommand for vkEnumeratePhysicalDeviceGroups { const NAME: &'static str = "vkEnumeratePhysicalDeviceGroups"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkEnumeratePhysicalDeviceGroups; }
+    pub struct vkEnumeratePhysicalDeviceGroupsKHR; impl TypedCommand for vkEnumeratePhysicalDeviceGroupsKHR { const NAME: &'static str = "vkEnumeratePhysicalDeviceGroupsKHR"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkEnumeratePhysicalDeviceGroups; }
+    pub struct vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR; impl TypedCommand for vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR { const NAME: &'static str = "vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkEnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR; }
+    pub struct vkEnumeratePhysicalDevices; impl TypedCommand for vkEnumeratePhysicalDevices { const NAME: &'static str = "vkEnumeratePhysicalDevices"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkEnumeratePhysicalDevices; }
+    pub struct vkExportMetalObjectsEXT; impl TypedCommand for vkExportMetalObjectsEXT { const NAME: &'static str = "vkExportMetalObjectsEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkExportMetalObjectsEXT; }
+    pub struct vkFlushMappedMemoryRanges; impl TypedCommand for vkFlushMappedMemoryRanges { const NAME: &'static str = "vkFlushMappedMemoryRanges"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkFlushMappedMemoryRanges; }
+    pub struct vkFreeCommandBuffers; impl TypedCommand for vkFreeCommandBuffers { const NAME: &'static str = "vkFreeCommandBuffers"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkFreeCommandBuffers; }
+    pub struct vkFreeDescriptorSets; impl TypedCommand for vkFreeDescriptorSets { const NAME: &'static str = "vkFreeDescriptorSets"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkFreeDescriptorSets; }
+    pub struct vkFreeMemory; impl TypedCommand for vkFreeMemory { const NAME: &'static str = "vkFreeMemory"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkFreeMemory; }
+    pub struct vkGetAccelerationStructureBuildSizesKHR; impl TypedCommand for vkGetAccelerationStructureBuildSizesKHR { const NAME: &'static str = "vkGetAccelerationStructureBuildSizesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetAccelerationStructureBuildSizesKHR; }
+    pub struct vkGetAccelerationStructureDeviceAddressKHR; impl TypedCommand for vkGetAccelerationStructureDeviceAddressKHR { const NAME: &'static str = "vkGetAccelerationStructureDeviceAddressKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetAccelerationStructureDeviceAddressKHR; }
+    pub struct vkGetAccelerationStructureHandleNV; impl TypedCommand for vkGetAccelerationStructureHandleNV { const NAME: &'static str = "vkGetAccelerationStructureHandleNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetAccelerationStructureHandleNV; }
+    pub struct vkGetAccelerationStructureMemoryRequirementsNV; impl TypedCommand for vkGetAccelerationStructureMemoryRequirementsNV { const NAME: &'static str = "vkGetAccelerationStructureMemoryRequirementsNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetAccelerationStructureMemoryRequirementsNV; }
+    pub struct vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT; impl TypedCommand for vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT { const NAME: &'static str = "vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetAccelerationStructureOpaqueCaptureDescriptorDataEXT; }
+    pub struct vkGetAndroidHardwareBufferPropertiesANDROID; impl TypedCommand for vkGetAndroidHardwareBufferPropertiesANDROID { const NAME: &'static str = "vkGetAndroidHardwareBufferPropertiesANDROID"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetAndroidHardwareBufferPropertiesANDROID; }
+    pub struct vkGetBufferCollectionPropertiesFUCHSIA; impl TypedCommand for vkGetBufferCollectionPropertiesFUCHSIA { const NAME: &'static str = "vkGetBufferCollectionPropertiesFUCHSIA"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferCollectionPropertiesFUCHSIA; }
+    pub struct vkGetBufferDeviceAddress; impl TypedCommand for vkGetBufferDeviceAddress { const NAME: &'static str = "vkGetBufferDeviceAddress"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferDeviceAddress; }
+    pub struct vkGetBufferDeviceAddressEXT; impl TypedCommand for vkGetBufferDeviceAddressEXT { const NAME: &'static str = "vkGetBufferDeviceAddressEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferDeviceAddress; }
+    pub struct vkGetBufferDeviceAddressKHR; impl TypedCommand for vkGetBufferDeviceAddressKHR { const NAME: &'static str = "vkGetBufferDeviceAddressKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferDeviceAddress; }
+    pub struct vkGetBufferMemoryRequirements; impl TypedCommand for vkGetBufferMemoryRequirements { const NAME: &'static str = "vkGetBufferMemoryRequirements"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferMemoryRequirements; }
+    pub struct vkGetBufferMemoryRequirements2; impl TypedCommand for vkGetBufferMemoryRequirements2 { const NAME: &'static str = "vkGetBufferMemoryRequirements2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferMemoryRequirements2; }
+    pub struct vkGetBufferMemoryRequirements2KHR; impl TypedCommand for vkGetBufferMemoryRequirements2KHR { const NAME: &'static str = "vkGetBufferMemoryRequirements2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferMemoryRequirements2; }
+    pub struct vkGetBufferOpaqueCaptureAddress; impl TypedCommand for vkGetBufferOpaqueCaptureAddress { const NAME: &'static str = "vkGetBufferOpaqueCaptureAddress"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferOpaqueCaptureAddress; }
+    pub struct vkGetBufferOpaqueCaptureAddressKHR; impl TypedCommand for vkGetBufferOpaqueCaptureAddressKHR { const NAME: &'static str = "vkGetBufferOpaqueCaptureAddressKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferOpaqueCaptureAddress; }
+    pub struct vkGetBufferOpaqueCaptureDescriptorDataEXT; impl TypedCommand for vkGetBufferOpaqueCaptureDescriptorDataEXT { const NAME: &'static str = "vkGetBufferOpaqueCaptureDescriptorDataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetBufferOpaqueCaptureDescriptorDataEXT; }
+    pub struct vkGetCalibratedTimestampsEXT; impl TypedCommand for vkGetCalibratedTimestampsEXT { const NAME: &'static str = "vkGetCalibratedTimestampsEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetCalibratedTimestampsEXT; }
+    pub struct vkGetDeferredOperationMaxConcurrencyKHR; impl TypedCommand for vkGetDeferredOperationMaxConcurrencyKHR { const NAME: &'static str = "vkGetDeferredOperationMaxConcurrencyKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeferredOperationMaxConcurrencyKHR; }
+    pub struct vkGetDeferredOperationResultKHR; impl TypedCommand for vkGetDeferredOperationResultKHR { const NAME: &'static str = "vkGetDeferredOperationResultKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeferredOperationResultKHR; }
+    pub struct vkGetDescriptorEXT; impl TypedCommand for vkGetDescriptorEXT { const NAME: &'static str = "vkGetDescriptorEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDescriptorEXT; }
+    pub struct vkGetDescriptorSetHostMappingVALVE; impl TypedCommand for vkGetDescriptorSetHostMappingVALVE { const NAME: &'static str = "vkGetDescriptorSetHostMappingVALVE"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDescriptorSetHostMappingVALVE; }
+    pub struct vkGetDescriptorSetLayoutBindingOffsetEXT; impl TypedCommand for vkGetDescriptorSetLayoutBindingOffsetEXT { const NAME: &'static str = "vkGetDescriptorSetLayoutBindingOffsetEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDescriptorSetLayoutBindingOffsetEXT; }
+    pub struct vkGetDescriptorSetLayoutHostMappingInfoVALVE; impl TypedCommand for vkGetDescriptorSetLayoutHostMappingInfoVALVE { const NAME: &'static str = "vkGetDescriptorSetLayoutHostMappingInfoVALVE"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDescriptorSetLayoutHostMappingInfoVALVE; }
+    pub struct vkGetDescriptorSetLayoutSizeEXT; impl TypedCommand for vkGetDescriptorSetLayoutSizeEXT { const NAME: &'static str = "vkGetDescriptorSetLayoutSizeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDescriptorSetLayoutSizeEXT; }
+    pub struct vkGetDescriptorSetLayoutSupport; impl TypedCommand for vkGetDescriptorSetLayoutSupport { const NAME: &'static str = "vkGetDescriptorSetLayoutSupport"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDescriptorSetLayoutSupport; }
+    pub struct vkGetDescriptorSetLayoutSupportKHR; impl TypedCommand for vkGetDescriptorSetLayoutSupportKHR { const NAME: &'static str = "vkGetDescriptorSetLayoutSupportKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDescriptorSetLayoutSupport; }
+    pub struct vkGetDeviceAccelerationStructureCompatibilityKHR; impl TypedCommand for vkGetDeviceAccelerationStructureCompatibilityKHR { const NAME: &'static str = "vkGetDeviceAccelerationStructureCompatibilityKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceAccelerationStructureCompatibilityKHR; }
+    pub struct vkGetDeviceBufferMemoryRequirements; impl TypedCommand for vkGetDeviceBufferMemoryRequirements { const NAME: &'static str = "vkGetDeviceBufferMemoryRequirements"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceBufferMemoryRequirements; }
+    pub struct vkGetDeviceBufferMemoryRequirementsKHR; impl TypedCommand for vkGetDeviceBufferMemoryRequirementsKHR { const NAME: &'static str = "vkGetDeviceBufferMemoryRequirementsKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceBufferMemoryRequirements; }
+    pub struct vkGetDeviceFaultInfoEXT; impl TypedCommand for vkGetDeviceFaultInfoEXT { const NAME: &'static str = "vkGetDeviceFaultInfoEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceFaultInfoEXT; }
+    pub struct vkGetDeviceGroupPeerMemoryFeatures; impl TypedCommand for vkGetDeviceGroupPeerMemoryFeatures { const NAME: &'static str = "vkGetDeviceGroupPeerMemoryFeatures"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceGroupPeerMemoryFeatures; }
+    pub struct vkGetDeviceGroupPeerMemoryFeaturesKHR; impl TypedCommand for vkGetDeviceGroupPeerMemoryFeaturesKHR { const NAME: &'static str = "vkGetDeviceGroupPeerMemoryFeaturesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceGroupPeerMemoryFeatures; }
+    pub struct vkGetDeviceGroupPresentCapabilitiesKHR; impl TypedCommand for vkGetDeviceGroupPresentCapabilitiesKHR { const NAME: &'static str = "vkGetDeviceGroupPresentCapabilitiesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceGroupPresentCapabilitiesKHR; }
+    pub struct vkGetDeviceGroupSurfacePresentModes2EXT; impl TypedCommand for vkGetDeviceGroupSurfacePresentModes2EXT { const NAME: &'static str = "vkGetDeviceGroupSurfacePresentModes2EXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceGroupSurfacePresentModes2EXT; }
+    pub struct vkGetDeviceGroupSurfacePresentModesKHR; impl TypedCommand for vkGetDeviceGroupSurfacePresentModesKHR { const NAME: &'static str = "vkGetDeviceGroupSurfacePresentModesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceGroupSurfacePresentModesKHR; }
+    pub struct vkGetDeviceImageMemoryRequirements; impl TypedCommand for vkGetDeviceImageMemoryRequirements { const NAME: &'static str = "vkGetDeviceImageMemoryRequirements"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceImageMemoryRequirements; }
+    pub struct vkGetDeviceImageMemoryRequirementsKHR; impl TypedCommand for vkGetDeviceImageMemoryRequirementsKHR { const NAME: &'static str = "vkGetDeviceImageMemoryRequirementsKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceImageMemoryRequirements; }
+    pub struct vkGetDeviceImageSparseMemoryRequirements; impl TypedCommand for vkGetDeviceImageSparseMemoryRequirements { const NAME: &'static str = "vkGetDeviceImageSparseMemoryRequirements"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceImageSparseMemoryRequirements; }
+    pub struct vkGetDeviceImageSparseMemoryRequirementsKHR; impl TypedCommand for vkGetDeviceImageSparseMemoryRequirementsKHR { const NAME: &'static str = "vkGetDeviceImageSparseMemoryRequirementsKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceImageSparseMemoryRequirements; }
+    pub struct vkGetDeviceMemoryCommitment; impl TypedCommand for vkGetDeviceMemoryCommitment { const NAME: &'static str = "vkGetDeviceMemoryCommitment"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceMemoryCommitment; }
+    pub struct vkGetDeviceMemoryOpaqueCaptureAddress; impl TypedCommand for vkGetDeviceMemoryOpaqueCaptureAddress { const NAME: &'static str = "vkGetDeviceMemoryOpaqueCaptureAddress"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress; }
+    pub struct vkGetDeviceMemoryOpaqueCaptureAddressKHR; impl TypedCommand for vkGetDeviceMemoryOpaqueCaptureAddressKHR { const NAME: &'static str = "vkGetDeviceMemoryOpaqueCaptureAddressKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceMemoryOpaqueCaptureAddress; }
+    pub struct vkGetDeviceMicromapCompatibilityEXT; impl TypedCommand for vkGetDeviceMicromapCompatibilityEXT { const NAME: &'static str = "vkGetDeviceMicromapCompatibilityEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceMicromapCompatibilityEXT; }
+    pub struct vkGetDeviceQueue; impl TypedCommand for vkGetDeviceQueue { const NAME: &'static str = "vkGetDeviceQueue"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceQueue; }
+    pub struct vkGetDeviceQueue2; impl TypedCommand for vkGetDeviceQueue2 { const NAME: &'static str = "vkGetDeviceQueue2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceQueue2; }
+    pub struct vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI; impl TypedCommand for vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI { const NAME: &'static str = "vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI; }
+    pub struct vkGetDisplayModeProperties2KHR; impl TypedCommand for vkGetDisplayModeProperties2KHR { const NAME: &'static str = "vkGetDisplayModeProperties2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetDisplayModeProperties2KHR; }
+    pub struct vkGetDisplayModePropertiesKHR; impl TypedCommand for vkGetDisplayModePropertiesKHR { const NAME: &'static str = "vkGetDisplayModePropertiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetDisplayModePropertiesKHR; }
+    pub struct vkGetDisplayPlaneCapabilities2KHR; impl TypedCommand for vkGetDisplayPlaneCapabilities2KHR { const NAME: &'static str = "vkGetDisplayPlaneCapabilities2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetDisplayPlaneCapabilities2KHR; }
+    pub struct vkGetDisplayPlaneCapabilitiesKHR; impl TypedCommand for vkGetDisplayPlaneCapabilitiesKHR { const NAME: &'static str = "vkGetDisplayPlaneCapabilitiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetDisplayPlaneCapabilitiesKHR; }
+    pub struct vkGetDisplayPlaneSupportedDisplaysKHR; impl TypedCommand for vkGetDisplayPlaneSupportedDisplaysKHR { const NAME: &'static str = "vkGetDisplayPlaneSupportedDisplaysKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetDisplayPlaneSupportedDisplaysKHR; }
+    pub struct vkGetDrmDisplayEXT; impl TypedCommand for vkGetDrmDisplayEXT { const NAME: &'static str = "vkGetDrmDisplayEXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetDrmDisplayEXT; }
+    pub struct vkGetDynamicRenderingTilePropertiesQCOM; impl TypedCommand for vkGetDynamicRenderingTilePropertiesQCOM { const NAME: &'static str = "vkGetDynamicRenderingTilePropertiesQCOM"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetDynamicRenderingTilePropertiesQCOM; }
+    pub struct vkGetEventStatus; impl TypedCommand for vkGetEventStatus { const NAME: &'static str = "vkGetEventStatus"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetEventStatus; }
+    pub struct vkGetFenceFdKHR; impl TypedCommand for vkGetFenceFdKHR { const NAME: &'static str = "vkGetFenceFdKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetFenceFdKHR; }
+    pub struct vkGetFenceStatus; impl TypedCommand for vkGetFenceStatus { const NAME: &'static str = "vkGetFenceStatus"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetFenceStatus; }
+    pub struct vkGetFenceWin32HandleKHR; impl TypedCommand for vkGetFenceWin32HandleKHR { const NAME: &'static str = "vkGetFenceWin32HandleKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetFenceWin32HandleKHR; }
+    pub struct vkGetFramebufferTilePropertiesQCOM; impl TypedCommand for vkGetFramebufferTilePropertiesQCOM { const NAME: &'static str = "vkGetFramebufferTilePropertiesQCOM"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetFramebufferTilePropertiesQCOM; }
+    pub struct vkGetGeneratedCommandsMemoryRequirementsNV; impl TypedCommand for vkGetGeneratedCommandsMemoryRequirementsNV { const NAME: &'static str = "vkGetGeneratedCommandsMemoryRequirementsNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetGeneratedCommandsMemoryRequirementsNV; }
+    pub struct vkGetImageDrmFormatModifierPropertiesEXT; impl TypedCommand for vkGetImageDrmFormatModifierPropertiesEXT { const NAME: &'static str = "vkGetImageDrmFormatModifierPropertiesEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageDrmFormatModifierPropertiesEXT; }
+    pub struct vkGetImageMemoryRequirements; impl TypedCommand for vkGetImageMemoryRequirements { const NAME: &'static str = "vkGetImageMemoryRequirements"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageMemoryRequirements; }
+    pub struct vkGetImageMemoryRequirements2; impl TypedCommand for vkGetImageMemoryRequirements2 { const NAME: &'static str = "vkGetImageMemoryRequirements2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageMemoryRequirements2; }
+    pub struct vkGetImageMemoryRequirements2KHR; impl TypedCommand for vkGetImageMemoryRequirements2KHR { const NAME: &'static str = "vkGetImageMemoryRequirements2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageMemoryRequirements2; }
+    pub struct vkGetImageOpaqueCaptureDescriptorDataEXT; impl TypedCommand for vkGetImageOpaqueCaptureDescriptorDataEXT { const NAME: &'static str = "vkGetImageOpaqueCaptureDescriptorDataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageOpaqueCaptureDescriptorDataEXT; }
+    pub struct vkGetImageSparseMemoryRequirements; impl TypedCommand for vkGetImageSparseMemoryRequirements { const NAME: &'static str = "vkGetImageSparseMemoryRequirements"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageSparseMemoryRequirements; }
+    pub struct vkGetImageSparseMemoryRequirements2; impl TypedCommand for vkGetImageSparseMemoryRequirements2 { const NAME: &'static str = "vkGetImageSparseMemoryRequirements2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageSparseMemoryRequirements2; }
+    pub struct vkGetImageSparseMemoryRequirements2KHR; impl TypedCommand for vkGetImageSparseMemoryRequirements2KHR { const NAME: &'static str = "vkGetImageSparseMemoryRequirements2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageSparseMemoryRequirements2; }
+    pub struct vkGetImageSubresourceLayout; impl TypedCommand for vkGetImageSubresourceLayout { const NAME: &'static str = "vkGetImageSubresourceLayout"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageSubresourceLayout; }
+    pub struct vkGetImageSubresourceLayout2EXT; impl TypedCommand for vkGetImageSubresourceLayout2EXT { const NAME: &'static str = "vkGetImageSubresourceLayout2EXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageSubresourceLayout2EXT; }
+    pub struct vkGetImageViewAddressNVX; impl TypedCommand for vkGetImageViewAddressNVX { const NAME: &'static str = "vkGetImageViewAddressNVX"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageViewAddressNVX; }
+    pub struct vkGetImageViewHandleNVX; impl TypedCommand for vkGetImageViewHandleNVX { const NAME: &'static str = "vkGetImageViewHandleNVX"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageViewHandleNVX; }
+    pub struct vkGetImageViewOpaqueCaptureDescriptorDataEXT; impl TypedCommand for vkGetImageViewOpaqueCaptureDescriptorDataEXT { const NAME: &'static str = "vkGetImageViewOpaqueCaptureDescriptorDataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetImageViewOpaqueCaptureDescriptorDataEXT; }
+    pub struct vkGetMemoryAndroidHardwareBufferANDROID; impl TypedCommand for vkGetMemoryAndroidHardwareBufferANDROID { const NAME: &'static str = "vkGetMemoryAndroidHardwareBufferANDROID"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryAndroidHardwareBufferANDROID; }
+    pub struct vkGetMemoryFdKHR; impl TypedCommand for vkGetMemoryFdKHR { const NAME: &'static str = "vkGetMemoryFdKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryFdKHR; }
+    pub struct vkGetMemoryFdPropertiesKHR; impl TypedCommand for vkGetMemoryFdPropertiesKHR { const NAME: &'static str = "vkGetMemoryFdPropertiesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryFdPropertiesKHR; }
+    pub struct vkGetMemoryHostPointerPropertiesEXT; impl TypedCommand for vkGetMemoryHostPointerPropertiesEXT { const NAME: &'static str = "vkGetMemoryHostPointerPropertiesEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryHostPointerPropertiesEXT; }
+    pub struct vkGetMemoryRemoteAddressNV; impl TypedCommand for vkGetMemoryRemoteAddressNV { const NAME: &'static str = "vkGetMemoryRemoteAddressNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryRemoteAddressNV; }
+    pub struct vkGetMemoryWin32HandleKHR; impl TypedCommand for vkGetMemoryWin32HandleKHR { const NAME: &'static str = "vkGetMemoryWin32HandleKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryWin32HandleKHR; }
+    pub struct vkGetMemoryWin32HandleNV; impl TypedCommand for vkGetMemoryWin32HandleNV { const NAME: &'static str = "vkGetMemoryWin32HandleNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryWin32HandleNV; }
+    pub struct vkGetMemoryWin32HandlePropertiesKHR; impl TypedCommand for vkGetMemoryWin32HandlePropertiesKHR { const NAME: &'static str = "vkGetMemoryWin32HandlePropertiesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryWin32HandlePropertiesKHR; }
+    pub struct vkGetMemoryZirconHandleFUCHSIA; impl TypedCommand for vkGetMemoryZirconHandleFUCHSIA { const NAME: &'static str = "vkGetMemoryZirconHandleFUCHSIA"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryZirconHandleFUCHSIA; }
+    pub struct vkGetMemoryZirconHandlePropertiesFUCHSIA; impl TypedCommand for vkGetMemoryZirconHandlePropertiesFUCHSIA { const NAME: &'static str = "vkGetMemoryZirconHandlePropertiesFUCHSIA"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMemoryZirconHandlePropertiesFUCHSIA; }
+    pub struct vkGetMicromapBuildSizesEXT; impl TypedCommand for vkGetMicromapBuildSizesEXT { const NAME: &'static str = "vkGetMicromapBuildSizesEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetMicromapBuildSizesEXT; }
+    pub struct vkGetPastPresentationTimingGOOGLE; impl TypedCommand for vkGetPastPresentationTimingGOOGLE { const NAME: &'static str = "vkGetPastPresentationTimingGOOGLE"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetPastPresentationTimingGOOGLE; }
+    pub struct vkGetPerformanceParameterINTEL; impl TypedCommand for vkGetPerformanceParameterINTEL { const NAME: &'static str = "vkGetPerformanceParameterINTEL"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetPerformanceParameterINTEL; }
+    pub struct vkGetPhysicalDeviceCalibrateableTimeDomainsEXT; impl TypedCommand for vkGetPhysicalDeviceCalibrateableTimeDomainsEXT { const NAME: &'static str = "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceCalibrateableTimeDomainsEXT; }
+    pub struct vkGetPhysicalDeviceCooperativeMatrixPropertiesNV; impl TypedCommand for vkGetPhysicalDeviceCooperativeMatrixPropertiesNV { const NAME: &'static str = "vkGetPhysicalDeviceCooperativeMatrixPropertiesNV"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceCooperativeMatrixPropertiesNV; }
+    pub struct vkGetPhysicalDeviceDirectFBPresentationSupportEXT; impl TypedCommand for vkGetPhysicalDeviceDirectFBPresentationSupportEXT { const NAME: &'static str = "vkGetPhysicalDeviceDirectFBPresentationSupportEXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceDirectFBPresentationSupportEXT; }
+    pub struct vkGetPhysicalDeviceDisplayPlaneProperties2KHR; impl TypedCommand for vkGetPhysicalDeviceDisplayPlaneProperties2KHR { const NAME: &'static str = "vkGetPhysicalDeviceDisplayPlaneProperties2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceDisplayPlaneProperties2KHR; }
+    pub struct vkGetPhysicalDeviceDisplayPlanePropertiesKHR; impl TypedCommand for vkGetPhysicalDeviceDisplayPlanePropertiesKHR { const NAME: &'static str = "vkGetPhysicalDeviceDisplayPlanePropertiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceDisplayPlanePropertiesKHR; }
+    pub struct vkGetPhysicalDeviceDisplayProperties2KHR; impl TypedCommand for vkGetPhysicalDeviceDisplayProperties2KHR { const NAME: &'static str = "vkGetPhysicalDeviceDisplayProperties2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceDisplayProperties2KHR; }
+    pub struct vkGetPhysicalDeviceDisplayPropertiesKHR; impl TypedCommand for vkGetPhysicalDeviceDisplayPropertiesKHR { const NAME: &'static str = "vkGetPhysicalDeviceDisplayPropertiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceDisplayPropertiesKHR; }
+    pub struct vkGetPhysicalDeviceExternalBufferProperties; impl TypedCommand for vkGetPhysicalDeviceExternalBufferProperties { const NAME: &'static str = "vkGetPhysicalDeviceExternalBufferProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceExternalBufferProperties; }
+    pub struct vkGetPhysicalDeviceExternalBufferPropertiesKHR; impl TypedCommand for vkGetPhysicalDeviceExternalBufferPropertiesKHR { const NAME: &'static str = "vkGetPhysicalDeviceExternalBufferPropertiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceExternalBufferProperties; }
+    pub struct vkGetPhysicalDeviceExternalFenceProperties; impl TypedCommand for vkGetPhysicalDeviceExternalFenceProperties { const NAME: &'static str = "vkGetPhysicalDeviceExternalFenceProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceExternalFenceProperties; }
+    pub struct vkGetPhysicalDeviceExternalFencePropertiesKHR; impl TypedCommand for vkGetPhysicalDeviceExternalFencePropertiesKHR { const NAME: &'static str = "vkGetPhysicalDeviceExternalFencePropertiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceExternalFenceProperties; }
+    pub struct vkGetPhysicalDeviceExternalImageFormatPropertiesNV; impl TypedCommand for vkGetPhysicalDeviceExternalImageFormatPropertiesNV { const NAME: &'static str = "vkGetPhysicalDeviceExternalImageFormatPropertiesNV"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceExternalImageFormatPropertiesNV; }
+    pub struct vkGetPhysicalDeviceExternalSemaphoreProperties; impl TypedCommand for vkGetPhysicalDeviceExternalSemaphoreProperties { const NAME: &'static str = "vkGetPhysicalDeviceExternalSemaphoreProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceExternalSemaphoreProperties; }
+    pub struct vkGetPhysicalDeviceExternalSemaphorePropertiesKHR; impl TypedCommand for vkGetPhysicalDeviceExternalSemaphorePropertiesKHR { const NAME: &'static str = "vkGetPhysicalDeviceExternalSemaphorePropertiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceExternalSemaphoreProperties; }
+    pub struct vkGetPhysicalDeviceFeatures; impl TypedCommand for vkGetPhysicalDeviceFeatures { const NAME: &'static str = "vkGetPhysicalDeviceFeatures"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceFeatures; }
+    pub struct vkGetPhysicalDeviceFeatures2; impl TypedCommand for vkGetPhysicalDeviceFeatures2 { const NAME: &'static str = "vkGetPhysicalDeviceFeatures2"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceFeatures2; }
+    pub struct vkGetPhysicalDeviceFeatures2KHR; impl TypedCommand for vkGetPhysicalDeviceFeatures2KHR { const NAME: &'static str = "vkGetPhysicalDeviceFeatures2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceFeatures2; }
+    pub struct vkGetPhysicalDeviceFormatProperties; impl TypedCommand for vkGetPhysicalDeviceFormatProperties { const NAME: &'static str = "vkGetPhysicalDeviceFormatProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceFormatProperties; }
+    pub struct vkGetPhysicalDeviceFormatProperties2; impl TypedCommand for vkGetPhysicalDeviceFormatProperties2 { const NAME: &'static str = "vkGetPhysicalDeviceFormatProperties2"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceFormatProperties2; }
+    pub struct vkGetPhysicalDeviceFormatProperties2KHR; impl TypedCommand for vkGetPhysicalDeviceFormatProperties2KHR { const NAME: &'static str = "vkGetPhysicalDeviceFormatProperties2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceFormatProperties2; }
+    pub struct vkGetPhysicalDeviceFragmentShadingRatesKHR; impl TypedCommand for vkGetPhysicalDeviceFragmentShadingRatesKHR { const NAME: &'static str = "vkGetPhysicalDeviceFragmentShadingRatesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceFragmentShadingRatesKHR; }
+    pub struct vkGetPhysicalDeviceImageFormatProperties; impl TypedCommand for vkGetPhysicalDeviceImageFormatProperties { const NAME: &'static str = "vkGetPhysicalDeviceImageFormatProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceImageFormatProperties; }
+    pub struct vkGetPhysicalDeviceImageFormatProperties2; impl TypedCommand for vkGetPhysicalDeviceImageFormatProperties2 { const NAME: &'static str = "vkGetPhysicalDeviceImageFormatProperties2"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceImageFormatProperties2; }
+    pub struct vkGetPhysicalDeviceImageFormatProperties2KHR; impl TypedCommand for vkGetPhysicalDeviceImageFormatProperties2KHR { const NAME: &'static str = "vkGetPhysicalDeviceImageFormatProperties2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceImageFormatProperties2; }
+    pub struct vkGetPhysicalDeviceMemoryProperties; impl TypedCommand for vkGetPhysicalDeviceMemoryProperties { const NAME: &'static str = "vkGetPhysicalDeviceMemoryProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceMemoryProperties; }
+    pub struct vkGetPhysicalDeviceMemoryProperties2; impl TypedCommand for vkGetPhysicalDeviceMemoryProperties2 { const NAME: &'static str = "vkGetPhysicalDeviceMemoryProperties2"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceMemoryProperties2; }
+    pub struct vkGetPhysicalDeviceMemoryProperties2KHR; impl TypedCommand for vkGetPhysicalDeviceMemoryProperties2KHR { const NAME: &'static str = "vkGetPhysicalDeviceMemoryProperties2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceMemoryProperties2; }
+    pub struct vkGetPhysicalDeviceMultisamplePropertiesEXT; impl TypedCommand for vkGetPhysicalDeviceMultisamplePropertiesEXT { const NAME: &'static str = "vkGetPhysicalDeviceMultisamplePropertiesEXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceMultisamplePropertiesEXT; }
+    pub struct vkGetPhysicalDeviceOpticalFlowImageFormatsNV; impl TypedCommand for vkGetPhysicalDeviceOpticalFlowImageFormatsNV { const NAME: &'static str = "vkGetPhysicalDeviceOpticalFlowImageFormatsNV"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceOpticalFlowImageFormatsNV; }
+    pub struct vkGetPhysicalDevicePresentRectanglesKHR; impl TypedCommand for vkGetPhysicalDevicePresentRectanglesKHR { const NAME: &'static str = "vkGetPhysicalDevicePresentRectanglesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDevicePresentRectanglesKHR; }
+    pub struct vkGetPhysicalDeviceProperties; impl TypedCommand for vkGetPhysicalDeviceProperties { const NAME: &'static str = "vkGetPhysicalDeviceProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceProperties; }
+    pub struct vkGetPhysicalDeviceProperties2; impl TypedCommand for vkGetPhysicalDeviceProperties2 { const NAME: &'static str = "vkGetPhysicalDeviceProperties2"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceProperties2; }
+    pub struct vkGetPhysicalDeviceProperties2KHR; impl TypedCommand for vkGetPhysicalDeviceProperties2KHR { const NAME: &'static str = "vkGetPhysicalDeviceProperties2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceProperties2; }
+    pub struct vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR; impl TypedCommand for vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR { const NAME: &'static str = "vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR; }
+    pub struct vkGetPhysicalDeviceQueueFamilyProperties; impl TypedCommand for vkGetPhysicalDeviceQueueFamilyProperties { const NAME: &'static str = "vkGetPhysicalDeviceQueueFamilyProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties; }
+    pub struct vkGetPhysicalDeviceQueueFamilyProperties2; impl TypedCommand for vkGetPhysicalDeviceQueueFamilyProperties2 { const NAME: &'static str = "vkGetPhysicalDeviceQueueFamilyProperties2"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2; }
+    pub struct vkGetPhysicalDeviceQueueFamilyProperties2KHR; impl TypedCommand for vkGetPhysicalDeviceQueueFamilyProperties2KHR { const NAME: &'static str = "vkGetPhysicalDeviceQueueFamilyProperties2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2; }
+    pub struct vkGetPhysicalDeviceScreenPresentationSupportQNX; impl TypedCommand for vkGetPhysicalDeviceScreenPresentationSupportQNX { const NAME: &'static str = "vkGetPhysicalDeviceScreenPresentationSupportQNX"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceScreenPresentationSupportQNX; }
+    pub struct vkGetPhysicalDeviceSparseImageFormatProperties; impl TypedCommand for vkGetPhysicalDeviceSparseImageFormatProperties { const NAME: &'static str = "vkGetPhysicalDeviceSparseImageFormatProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties; }
+    pub struct vkGetPhysicalDeviceSparseImageFormatProperties2; impl TypedCommand for vkGetPhysicalDeviceSparseImageFormatProperties2 { const NAME: &'static str = "vkGetPhysicalDeviceSparseImageFormatProperties2"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties2; }
+    pub struct vkGetPhysicalDeviceSparseImageFormatProperties2KHR; impl TypedCommand for vkGetPhysicalDeviceSparseImageFormatProperties2KHR { const NAME: &'static str = "vkGetPhysicalDeviceSparseImageFormatProperties2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSparseImageFormatProperties2; }
+    pub struct vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV; impl TypedCommand for vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV { const NAME: &'static str = "vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV; }
+    pub struct vkGetPhysicalDeviceSurfaceCapabilities2EXT; impl TypedCommand for vkGetPhysicalDeviceSurfaceCapabilities2EXT { const NAME: &'static str = "vkGetPhysicalDeviceSurfaceCapabilities2EXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2EXT; }
+    pub struct vkGetPhysicalDeviceSurfaceCapabilities2KHR; impl TypedCommand for vkGetPhysicalDeviceSurfaceCapabilities2KHR { const NAME: &'static str = "vkGetPhysicalDeviceSurfaceCapabilities2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSurfaceCapabilities2KHR; }
+    pub struct vkGetPhysicalDeviceSurfaceCapabilitiesKHR; impl TypedCommand for vkGetPhysicalDeviceSurfaceCapabilitiesKHR { const NAME: &'static str = "vkGetPhysicalDeviceSurfaceCapabilitiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSurfaceCapabilitiesKHR; }
+    pub struct vkGetPhysicalDeviceSurfaceFormats2KHR; impl TypedCommand for vkGetPhysicalDeviceSurfaceFormats2KHR { const NAME: &'static str = "vkGetPhysicalDeviceSurfaceFormats2KHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSurfaceFormats2KHR; }
+    pub struct vkGetPhysicalDeviceSurfaceFormatsKHR; impl TypedCommand for vkGetPhysicalDeviceSurfaceFormatsKHR { const NAME: &'static str = "vkGetPhysicalDeviceSurfaceFormatsKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSurfaceFormatsKHR; }
+    pub struct vkGetPhysicalDeviceSurfacePresentModes2EXT; impl TypedCommand for vkGetPhysicalDeviceSurfacePresentModes2EXT { const NAME: &'static str = "vkGetPhysicalDeviceSurfacePresentModes2EXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSurfacePresentModes2EXT; }
+    pub struct vkGetPhysicalDeviceSurfacePresentModesKHR; impl TypedCommand for vkGetPhysicalDeviceSurfacePresentModesKHR { const NAME: &'static str = "vkGetPhysicalDeviceSurfacePresentModesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSurfacePresentModesKHR; }
+    pub struct vkGetPhysicalDeviceSurfaceSupportKHR; impl TypedCommand for vkGetPhysicalDeviceSurfaceSupportKHR { const NAME: &'static str = "vkGetPhysicalDeviceSurfaceSupportKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceSurfaceSupportKHR; }
+    pub struct vkGetPhysicalDeviceToolProperties; impl TypedCommand for vkGetPhysicalDeviceToolProperties { const NAME: &'static str = "vkGetPhysicalDeviceToolProperties"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceToolProperties; }
+    pub struct vkGetPhysicalDeviceToolPropertiesEXT; impl TypedCommand for vkGetPhysicalDeviceToolPropertiesEXT { const NAME: &'static str = "vkGetPhysicalDeviceToolPropertiesEXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceToolProperties; }
+    pub struct vkGetPhysicalDeviceVideoCapabilitiesKHR; impl TypedCommand for vkGetPhysicalDeviceVideoCapabilitiesKHR { const NAME: &'static str = "vkGetPhysicalDeviceVideoCapabilitiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceVideoCapabilitiesKHR; }
+    pub struct vkGetPhysicalDeviceVideoFormatPropertiesKHR; impl TypedCommand for vkGetPhysicalDeviceVideoFormatPropertiesKHR { const NAME: &'static str = "vkGetPhysicalDeviceVideoFormatPropertiesKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceVideoFormatPropertiesKHR; }
+    pub struct vkGetPhysicalDeviceWaylandPresentationSupportKHR; impl TypedCommand for vkGetPhysicalDeviceWaylandPresentationSupportKHR { const NAME: &'static str = "vkGetPhysicalDeviceWaylandPresentationSupportKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceWaylandPresentationSupportKHR; }
+    pub struct vkGetPhysicalDeviceWin32PresentationSupportKHR; impl TypedCommand for vkGetPhysicalDeviceWin32PresentationSupportKHR { const NAME: &'static str = "vkGetPhysicalDeviceWin32PresentationSupportKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceWin32PresentationSupportKHR; }
+    pub struct vkGetPhysicalDeviceXcbPresentationSupportKHR; impl TypedCommand for vkGetPhysicalDeviceXcbPresentationSupportKHR { const NAME: &'static str = "vkGetPhysicalDeviceXcbPresentationSupportKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceXcbPresentationSupportKHR; }
+    pub struct vkGetPhysicalDeviceXlibPresentationSupportKHR; impl TypedCommand for vkGetPhysicalDeviceXlibPresentationSupportKHR { const NAME: &'static str = "vkGetPhysicalDeviceXlibPresentationSupportKHR"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetPhysicalDeviceXlibPresentationSupportKHR; }
+    pub struct vkGetPipelineCacheData; impl TypedCommand for vkGetPipelineCacheData { const NAME: &'static str = "vkGetPipelineCacheData"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetPipelineCacheData; }
+    pub struct vkGetPipelineExecutableInternalRepresentationsKHR; impl TypedCommand for vkGetPipelineExecutableInternalRepresentationsKHR { const NAME: &'static str = "vkGetPipelineExecutableInternalRepresentationsKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetPipelineExecutableInternalRepresentationsKHR; }
+    pub struct vkGetPipelineExecutablePropertiesKHR; impl TypedCommand for vkGetPipelineExecutablePropertiesKHR { const NAME: &'static str = "vkGetPipelineExecutablePropertiesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetPipelineExecutablePropertiesKHR; }
+    pub struct vkGetPipelineExecutableStatisticsKHR; impl TypedCommand for vkGetPipelineExecutableStatisticsKHR { const NAME: &'static str = "vkGetPipelineExecutableStatisticsKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetPipelineExecutableStatisticsKHR; }
+    pub struct vkGetPipelinePropertiesEXT; impl TypedCommand for vkGetPipelinePropertiesEXT { const NAME: &'static str = "vkGetPipelinePropertiesEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetPipelinePropertiesEXT; }
+    pub struct vkGetPrivateData; impl TypedCommand for vkGetPrivateData { const NAME: &'static str = "vkGetPrivateData"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetPrivateData; }
+    pub struct vkGetPrivateDataEXT; impl TypedCommand for vkGetPrivateDataEXT { const NAME: &'static str = "vkGetPrivateDataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetPrivateData; }
+    pub struct vkGetQueryPoolResults; impl TypedCommand for vkGetQueryPoolResults { const NAME: &'static str = "vkGetQueryPoolResults"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetQueryPoolResults; }
+    pub struct vkGetQueueCheckpointData2NV; impl TypedCommand for vkGetQueueCheckpointData2NV { const NAME: &'static str = "vkGetQueueCheckpointData2NV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetQueueCheckpointData2NV; }
+    pub struct vkGetQueueCheckpointDataNV; impl TypedCommand for vkGetQueueCheckpointDataNV { const NAME: &'static str = "vkGetQueueCheckpointDataNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetQueueCheckpointDataNV; }
+    pub struct vkGetRandROutputDisplayEXT; impl TypedCommand for vkGetRandROutputDisplayEXT { const NAME: &'static str = "vkGetRandROutputDisplayEXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetRandROutputDisplayEXT; }
+    pub struct vkGetRayTracingCaptureReplayShaderGroupHandlesKHR; impl TypedCommand for vkGetRayTracingCaptureReplayShaderGroupHandlesKHR { const NAME: &'static str = "vkGetRayTracingCaptureReplayShaderGroupHandlesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetRayTracingCaptureReplayShaderGroupHandlesKHR; }
+    pub struct vkGetRayTracingShaderGroupHandlesKHR; impl TypedCommand for vkGetRayTracingShaderGroupHandlesKHR { const NAME: &'static str = "vkGetRayTracingShaderGroupHandlesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetRayTracingShaderGroupHandlesKHR; }
+    pub struct vkGetRayTracingShaderGroupHandlesNV; impl TypedCommand for vkGetRayTracingShaderGroupHandlesNV { const NAME: &'static str = "vkGetRayTracingShaderGroupHandlesNV"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetRayTracingShaderGroupHandlesKHR; }
+    pub struct vkGetRayTracingShaderGroupStackSizeKHR; impl TypedCommand for vkGetRayTracingShaderGroupStackSizeKHR { const NAME: &'static str = "vkGetRayTracingShaderGroupStackSizeKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetRayTracingShaderGroupStackSizeKHR; }
+    pub struct vkGetRefreshCycleDurationGOOGLE; impl TypedCommand for vkGetRefreshCycleDurationGOOGLE { const NAME: &'static str = "vkGetRefreshCycleDurationGOOGLE"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetRefreshCycleDurationGOOGLE; }
+    pub struct vkGetRenderAreaGranularity; impl TypedCommand for vkGetRenderAreaGranularity { const NAME: &'static str = "vkGetRenderAreaGranularity"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetRenderAreaGranularity; }
+    pub struct vkGetSamplerOpaqueCaptureDescriptorDataEXT; impl TypedCommand for vkGetSamplerOpaqueCaptureDescriptorDataEXT { const NAME: &'static str = "vkGetSamplerOpaqueCaptureDescriptorDataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetSamplerOpaqueCaptureDescriptorDataEXT; }
+    pub struct vkGetSemaphoreCounterValue; impl TypedCommand for vkGetSemaphoreCounterValue { const NAME: &'static str = "vkGetSemaphoreCounterValue"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetSemaphoreCounterValue; }
+    pub struct vkGetSemaphoreCounterValueKHR; impl TypedCommand for vkGetSemaphoreCounterValueKHR { const NAME: &'static str = "vkGetSemaphoreCounterValueKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetSemaphoreCounterValue; }
+    pub struct vkGetSemaphoreFdKHR; impl TypedCommand for vkGetSemaphoreFdKHR { const NAME: &'static str = "vkGetSemaphoreFdKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetSemaphoreFdKHR; }
+    pub struct vkGetSemaphoreWin32HandleKHR; impl TypedCommand for vkGetSemaphoreWin32HandleKHR { const NAME: &'static str = "vkGetSemaphoreWin32HandleKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetSemaphoreWin32HandleKHR; }
+    pub struct vkGetSemaphoreZirconHandleFUCHSIA; impl TypedCommand for vkGetSemaphoreZirconHandleFUCHSIA { const NAME: &'static str = "vkGetSemaphoreZirconHandleFUCHSIA"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetSemaphoreZirconHandleFUCHSIA; }
+    pub struct vkGetShaderBinaryDataEXT; impl TypedCommand for vkGetShaderBinaryDataEXT { const NAME: &'static str = "vkGetShaderBinaryDataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetShaderBinaryDataEXT; }
+    pub struct vkGetShaderInfoAMD; impl TypedCommand for vkGetShaderInfoAMD { const NAME: &'static str = "vkGetShaderInfoAMD"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetShaderInfoAMD; }
+    pub struct vkGetShaderModuleCreateInfoIdentifierEXT; impl TypedCommand for vkGetShaderModuleCreateInfoIdentifierEXT { const NAME: &'static str = "vkGetShaderModuleCreateInfoIdentifierEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetShaderModuleCreateInfoIdentifierEXT; }
+    pub struct vkGetShaderModuleIdentifierEXT; impl TypedCommand for vkGetShaderModuleIdentifierEXT { const NAME: &'static str = "vkGetShaderModuleIdentifierEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetShaderModuleIdentifierEXT; }
+    pub struct vkGetSwapchainCounterEXT; impl TypedCommand for vkGetSwapchainCounterEXT { const NAME: &'static str = "vkGetSwapchainCounterEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetSwapchainCounterEXT; }
+    pub struct vkGetSwapchainImagesKHR; impl TypedCommand for vkGetSwapchainImagesKHR { const NAME: &'static str = "vkGetSwapchainImagesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetSwapchainImagesKHR; }
+    pub struct vkGetSwapchainStatusKHR; impl TypedCommand for vkGetSwapchainStatusKHR { const NAME: &'static str = "vkGetSwapchainStatusKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetSwapchainStatusKHR; }
+    pub struct vkGetValidationCacheDataEXT; impl TypedCommand for vkGetValidationCacheDataEXT { const NAME: &'static str = "vkGetValidationCacheDataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetValidationCacheDataEXT; }
+    pub struct vkGetVideoSessionMemoryRequirementsKHR; impl TypedCommand for vkGetVideoSessionMemoryRequirementsKHR { const NAME: &'static str = "vkGetVideoSessionMemoryRequirementsKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkGetVideoSessionMemoryRequirementsKHR; }
+    pub struct vkGetWinrtDisplayNV; impl TypedCommand for vkGetWinrtDisplayNV { const NAME: &'static str = "vkGetWinrtDisplayNV"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkGetWinrtDisplayNV; }
+    pub struct vkImportFenceFdKHR; impl TypedCommand for vkImportFenceFdKHR { const NAME: &'static str = "vkImportFenceFdKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkImportFenceFdKHR; }
+    pub struct vkImportFenceWin32HandleKHR; impl TypedCommand for vkImportFenceWin32HandleKHR { const NAME: &'static str = "vkImportFenceWin32HandleKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkImportFenceWin32HandleKHR; }
+    pub struct vkImportSemaphoreFdKHR; impl TypedCommand for vkImportSemaphoreFdKHR { const NAME: &'static str = "vkImportSemaphoreFdKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkImportSemaphoreFdKHR; }
+    pub struct vkImportSemaphoreWin32HandleKHR; impl TypedCommand for vkImportSemaphoreWin32HandleKHR { const NAME: &'static str = "vkImportSemaphoreWin32HandleKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkImportSemaphoreWin32HandleKHR; }
+    pub struct vkImportSemaphoreZirconHandleFUCHSIA; impl TypedCommand for vkImportSemaphoreZirconHandleFUCHSIA { const NAME: &'static str = "vkImportSemaphoreZirconHandleFUCHSIA"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkImportSemaphoreZirconHandleFUCHSIA; }
+    pub struct vkInitializePerformanceApiINTEL; impl TypedCommand for vkInitializePerformanceApiINTEL { const NAME: &'static str = "vkInitializePerformanceApiINTEL"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkInitializePerformanceApiINTEL; }
+    pub struct vkInvalidateMappedMemoryRanges; impl TypedCommand for vkInvalidateMappedMemoryRanges { const NAME: &'static str = "vkInvalidateMappedMemoryRanges"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkInvalidateMappedMemoryRanges; }
+    pub struct vkMapMemory; impl TypedCommand for vkMapMemory { const NAME: &'static str = "vkMapMemory"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkMapMemory; }
+    pub struct vkMapMemory2KHR; impl TypedCommand for vkMapMemory2KHR { const NAME: &'static str = "vkMapMemory2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkMapMemory2KHR; }
+    pub struct vkMergePipelineCaches; impl TypedCommand for vkMergePipelineCaches { const NAME: &'static str = "vkMergePipelineCaches"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkMergePipelineCaches; }
+    pub struct vkMergeValidationCachesEXT; impl TypedCommand for vkMergeValidationCachesEXT { const NAME: &'static str = "vkMergeValidationCachesEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkMergeValidationCachesEXT; }
+    pub struct vkQueueBeginDebugUtilsLabelEXT; impl TypedCommand for vkQueueBeginDebugUtilsLabelEXT { const NAME: &'static str = "vkQueueBeginDebugUtilsLabelEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueueBeginDebugUtilsLabelEXT; }
+    pub struct vkQueueBindSparse; impl TypedCommand for vkQueueBindSparse { const NAME: &'static str = "vkQueueBindSparse"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueueBindSparse; }
+    pub struct vkQueueEndDebugUtilsLabelEXT; impl TypedCommand for vkQueueEndDebugUtilsLabelEXT { const NAME: &'static str = "vkQueueEndDebugUtilsLabelEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueueEndDebugUtilsLabelEXT; }
+    pub struct vkQueueInsertDebugUtilsLabelEXT; impl TypedCommand for vkQueueInsertDebugUtilsLabelEXT { const NAME: &'static str = "vkQueueInsertDebugUtilsLabelEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueueInsertDebugUtilsLabelEXT; }
+    pub struct vkQueuePresentKHR; impl TypedCommand for vkQueuePresentKHR { const NAME: &'static str = "vkQueuePresentKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueuePresentKHR; }
+    pub struct vkQueueSetPerformanceConfigurationINTEL; impl TypedCommand for vkQueueSetPerformanceConfigurationINTEL { const NAME: &'static str = "vkQueueSetPerformanceConfigurationINTEL"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueueSetPerformanceConfigurationINTEL; }
+    pub struct vkQueueSubmit; impl TypedCommand for vkQueueSubmit { const NAME: &'static str = "vkQueueSubmit"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueueSubmit; }
+    pub struct vkQueueSubmit2; impl TypedCommand for vkQueueSubmit2 { const NAME: &'static str = "vkQueueSubmit2"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueueSubmit2; }
+    pub struct vkQueueSubmit2KHR; impl TypedCommand for vkQueueSubmit2KHR { const NAME: &'static str = "vkQueueSubmit2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueueSubmit2; }
+    pub struct vkQueueWaitIdle; impl TypedCommand for vkQueueWaitIdle { const NAME: &'static str = "vkQueueWaitIdle"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkQueueWaitIdle; }
+    pub struct vkRegisterDeviceEventEXT; impl TypedCommand for vkRegisterDeviceEventEXT { const NAME: &'static str = "vkRegisterDeviceEventEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkRegisterDeviceEventEXT; }
+    pub struct vkRegisterDisplayEventEXT; impl TypedCommand for vkRegisterDisplayEventEXT { const NAME: &'static str = "vkRegisterDisplayEventEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkRegisterDisplayEventEXT; }
+    pub struct vkReleaseDisplayEXT; impl TypedCommand for vkReleaseDisplayEXT { const NAME: &'static str = "vkReleaseDisplayEXT"; const LEVEL: CommandLevel = PhysicalDevice; type Pfn = vk::PFN_vkReleaseDisplayEXT; }
+    pub struct vkReleaseFullScreenExclusiveModeEXT; impl TypedCommand for vkReleaseFullScreenExclusiveModeEXT { const NAME: &'static str = "vkReleaseFullScreenExclusiveModeEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkReleaseFullScreenExclusiveModeEXT; }
+    pub struct vkReleasePerformanceConfigurationINTEL; impl TypedCommand for vkReleasePerformanceConfigurationINTEL { const NAME: &'static str = "vkReleasePerformanceConfigurationINTEL"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkReleasePerformanceConfigurationINTEL; }
+    pub struct vkReleaseProfilingLockKHR; impl TypedCommand for vkReleaseProfilingLockKHR { const NAME: &'static str = "vkReleaseProfilingLockKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkReleaseProfilingLockKHR; }
+    pub struct vkReleaseSwapchainImagesEXT; impl TypedCommand for vkReleaseSwapchainImagesEXT { const NAME: &'static str = "vkReleaseSwapchainImagesEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkReleaseSwapchainImagesEXT; }
+    pub struct vkResetCommandBuffer; impl TypedCommand for vkResetCommandBuffer { const NAME: &'static str = "vkResetCommandBuffer"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkResetCommandBuffer; }
+    pub struct vkResetCommandPool; impl TypedCommand for vkResetCommandPool { const NAME: &'static str = "vkResetCommandPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkResetCommandPool; }
+    pub struct vkResetDescriptorPool; impl TypedCommand for vkResetDescriptorPool { const NAME: &'static str = "vkResetDescriptorPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkResetDescriptorPool; }
+    pub struct vkResetEvent; impl TypedCommand for vkResetEvent { const NAME: &'static str = "vkResetEvent"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkResetEvent; }
+    pub struct vkResetFences; impl TypedCommand for vkResetFences { const NAME: &'static str = "vkResetFences"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkResetFences; }
+    pub struct vkResetQueryPool; impl TypedCommand for vkResetQueryPool { const NAME: &'static str = "vkResetQueryPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkResetQueryPool; }
+    pub struct vkResetQueryPoolEXT; impl TypedCommand for vkResetQueryPoolEXT { const NAME: &'static str = "vkResetQueryPoolEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkResetQueryPool; }
+    pub struct vkSetBufferCollectionBufferConstraintsFUCHSIA; impl TypedCommand for vkSetBufferCollectionBufferConstraintsFUCHSIA { const NAME: &'static str = "vkSetBufferCollectionBufferConstraintsFUCHSIA"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetBufferCollectionBufferConstraintsFUCHSIA; }
+    pub struct vkSetBufferCollectionImageConstraintsFUCHSIA; impl TypedCommand for vkSetBufferCollectionImageConstraintsFUCHSIA { const NAME: &'static str = "vkSetBufferCollectionImageConstraintsFUCHSIA"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetBufferCollectionImageConstraintsFUCHSIA; }
+    pub struct vkSetDebugUtilsObjectNameEXT; impl TypedCommand for vkSetDebugUtilsObjectNameEXT { const NAME: &'static str = "vkSetDebugUtilsObjectNameEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetDebugUtilsObjectNameEXT; }
+    pub struct vkSetDebugUtilsObjectTagEXT; impl TypedCommand for vkSetDebugUtilsObjectTagEXT { const NAME: &'static str = "vkSetDebugUtilsObjectTagEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetDebugUtilsObjectTagEXT; }
+    pub struct vkSetDeviceMemoryPriorityEXT; impl TypedCommand for vkSetDeviceMemoryPriorityEXT { const NAME: &'static str = "vkSetDeviceMemoryPriorityEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetDeviceMemoryPriorityEXT; }
+    pub struct vkSetEvent; impl TypedCommand for vkSetEvent { const NAME: &'static str = "vkSetEvent"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetEvent; }
+    pub struct vkSetHdrMetadataEXT; impl TypedCommand for vkSetHdrMetadataEXT { const NAME: &'static str = "vkSetHdrMetadataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetHdrMetadataEXT; }
+    pub struct vkSetLocalDimmingAMD; impl TypedCommand for vkSetLocalDimmingAMD { const NAME: &'static str = "vkSetLocalDimmingAMD"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetLocalDimmingAMD; }
+    pub struct vkSetPrivateData; impl TypedCommand for vkSetPrivateData { const NAME: &'static str = "vkSetPrivateData"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetPrivateData; }
+    pub struct vkSetPrivateDataEXT; impl TypedCommand for vkSetPrivateDataEXT { const NAME: &'static str = "vkSetPrivateDataEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSetPrivateData; }
+    pub struct vkSignalSemaphore; impl TypedCommand for vkSignalSemaphore { const NAME: &'static str = "vkSignalSemaphore"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSignalSemaphore; }
+    pub struct vkSignalSemaphoreKHR; impl TypedCommand for vkSignalSemaphoreKHR { const NAME: &'static str = "vkSignalSemaphoreKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkSignalSemaphore; }
+    pub struct vkSubmitDebugUtilsMessageEXT; impl TypedCommand for vkSubmitDebugUtilsMessageEXT { const NAME: &'static str = "vkSubmitDebugUtilsMessageEXT"; const LEVEL: CommandLevel = Instance; type Pfn = vk::PFN_vkSubmitDebugUtilsMessageEXT; }
+    pub struct vkTrimCommandPool; impl TypedCommand for vkTrimCommandPool { const NAME: &'static str = "vkTrimCommandPool"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkTrimCommandPool; }
+    pub struct vkTrimCommandPoolKHR; impl TypedCommand for vkTrimCommandPoolKHR { const NAME: &'static str = "vkTrimCommandPoolKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkTrimCommandPool; }
+    pub struct vkUninitializePerformanceApiINTEL; impl TypedCommand for vkUninitializePerformanceApiINTEL { const NAME: &'static str = "vkUninitializePerformanceApiINTEL"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkUninitializePerformanceApiINTEL; }
+    pub struct vkUnmapMemory; impl TypedCommand for vkUnmapMemory { const NAME: &'static str = "vkUnmapMemory"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkUnmapMemory; }
+    pub struct vkUnmapMemory2KHR; impl TypedCommand for vkUnmapMemory2KHR { const NAME: &'static str = "vkUnmapMemory2KHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkUnmapMemory2KHR; }
+    pub struct vkUpdateDescriptorSetWithTemplate; impl TypedCommand for vkUpdateDescriptorSetWithTemplate { const NAME: &'static str = "vkUpdateDescriptorSetWithTemplate"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkUpdateDescriptorSetWithTemplate; }
+    pub struct vkUpdateDescriptorSetWithTemplateKHR; impl TypedCommand for vkUpdateDescriptorSetWithTemplateKHR { const NAME: &'static str = "vkUpdateDescriptorSetWithTemplateKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkUpdateDescriptorSetWithTemplate; }
+    pub struct vkUpdateDescriptorSets; impl TypedCommand for vkUpdateDescriptorSets { const NAME: &'static str = "vkUpdateDescriptorSets"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkUpdateDescriptorSets; }
+    pub struct vkUpdateVideoSessionParametersKHR; impl TypedCommand for vkUpdateVideoSessionParametersKHR { const NAME: &'static str = "vkUpdateVideoSessionParametersKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkUpdateVideoSessionParametersKHR; }
+    pub struct vkWaitForFences; impl TypedCommand for vkWaitForFences { const NAME: &'static str = "vkWaitForFences"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkWaitForFences; }
+    pub struct vkWaitForPresentKHR; impl TypedCommand for vkWaitForPresentKHR { const NAME: &'static str = "vkWaitForPresentKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkWaitForPresentKHR; }
+    pub struct vkWaitSemaphores; impl TypedCommand for vkWaitSemaphores { const NAME: &'static str = "vkWaitSemaphores"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkWaitSemaphores; }
+    pub struct vkWaitSemaphoresKHR; impl TypedCommand for vkWaitSemaphoresKHR { const NAME: &'static str = "vkWaitSemaphoresKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkWaitSemaphores; }
+    pub struct vkWriteAccelerationStructuresPropertiesKHR; impl TypedCommand for vkWriteAccelerationStructuresPropertiesKHR { const NAME: &'static str = "vkWriteAccelerationStructuresPropertiesKHR"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkWriteAccelerationStructuresPropertiesKHR; }
+    pub struct vkWriteMicromapsPropertiesEXT; impl TypedCommand for vkWriteMicromapsPropertiesEXT { const NAME: &'static str = "vkWriteMicromapsPropertiesEXT"; const LEVEL: CommandLevel = Device; type Pfn = vk::PFN_vkWriteMicromapsPropertiesEXT; }
+}
@@ -0,0 +1,106 @@
+//! Commands of the Vulkan registry and what provides them, to tell which commands an instance or
+//! device has enabled.
+//!
+//! Generated from `vk.xml` by the `generator` crate of the repository.
+
+#[rustfmt::skip]
+mod generated;
+
+pub use generated::{commands, hooks};
+
+use crate::{CommandLevel, Signature};
+
+use core::ffi::CStr;
+
+use ash::vk;
+
+/// Core version or extension providing a command.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Provider {
+    /// Core since the version, e.g. [`vk::API_VERSION_1_2`].
+    Core(u32),
+    Extension(&'static str),
+}
+
+/// Type of the dispatchable handle a command is called on.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Dispatchable {
+    Instance,
+    PhysicalDevice,
+    Device,
+    Queue,
+    CommandBuffer,
+}
+
+/// Command of the Vulkan registry.
+///
+/// Aliases are commands of their own, e.g. `vkCmdDrawIndirectCountKHR` is provided by
+/// `VK_KHR_draw_indirect_count` only while `vkCmdDrawIndirectCount` is by Vulkan 1.2 only.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy)]
+pub struct Command {
+    pub name: &'static str,
+    pub level: CommandLevel,
+    /// Type of the first parameter, `None` for global commands.
+    pub dispatchable: Option<Dispatchable>,
+    pub providers: &'static [Provider],
+    /// Empty if the command does not return a `VkResult`.
+    pub success_codes: &'static [vk::Result],
+    pub error_codes: &'static [vk::Result],
+}
+
+impl Command {
+    #[inline]
+    const fn new(
+        name: &'static str,
+        dispatchable: Option<Dispatchable>,
+        providers: &'static [Provider],
+        success_codes: &'static [vk::Result],
+        error_codes: &'static [vk::Result],
+    ) -> Self {
+        let level = match dispatchable {
+            None => CommandLevel::Global,
+            Some(Dispatchable::Instance) => CommandLevel::Instance,
+            Some(Dispatchable::PhysicalDevice) => CommandLevel::PhysicalDevice,
+            Some(_) => CommandLevel::Device,
+        };
+        Self {
+            name,
+            level,
+            dispatchable,
+            providers,
+            success_codes,
+            error_codes,
+        }
+    }
+}
+
+/// Command with a typed signature, implemented by the marker types of [`commands`].
+pub trait TypedCommand: 'static {
+    const NAME: &'static str;
+    const LEVEL: CommandLevel;
+    type Pfn: Signature;
+}
+
+/// Command `name`, `None` if unknown to this registry version.
+pub fn command(name: &CStr) -> Option<&'static Command> {
+    let name = name.to_bytes();
+    let index = generated::COMMANDS
+        .binary_search_by(|v| v.name.as_bytes().cmp(name))
+        .ok()?;
+    Some(&generated::COMMANDS[index])
+}
+
+/// Every command of this registry version, sorted by name.
+pub fn all_commands() -> &'static [Command] {
+    generated::COMMANDS
+}
+
+/// Whether `name` is an instance rather than a device extension.
+pub fn is_instance_extension(name: &str) -> bool {
+    generated::INSTANCE_EXTENSIONS
+        .binary_search_by(|v| v.as_bytes().cmp(name.as_bytes()))
+        .is_ok()
+}
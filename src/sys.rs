@@ -1,4 +1,7 @@
+use crate::ChainError;
+
 use core::ffi::{c_char, c_void};
+use core::mem;
 
 use ash::vk::TaggedStructure;
 use ash::vk::{self, PFN_vkGetDeviceProcAddr};
@@ -37,12 +40,17 @@ pub struct NegotiateLayerInterface {
     pub pfn_get_physical_device_proc_addr: Option<PFN_vk_layerGetPhysicalDeviceProcAddr>,
 }
 
+pub const CURRENT_LOADER_LAYER_INTERFACE_VERSION: u32 = 2;
+pub const MIN_SUPPORTED_LOADER_LAYER_INTERFACE_VERSION: u32 = 1;
+
 #[allow(non_camel_case_types)]
 pub type PFN_vkNegotiateLoaderLayerInterfaceVersion =
     unsafe extern "system" fn(p_version_struct: *mut NegotiateLayerInterface) -> vk::Result;
-pub const VK_NEGOTIATE_LOADER_LAYER_INTERFACE_VERSION_SYM: &'static str =
+pub const VK_NEGOTIATE_LOADER_LAYER_INTERFACE_VERSION_SYM: &str =
     "vkNegotiateLoaderLayerInterfaceVersion";
 
+/// Type-erased physical-device command of an extension unknown to the loader, as returned by
+/// `vk_layerGetPhysicalDeviceProcAddr`.
 #[allow(non_camel_case_types)]
 pub type PFN_PhysDevExt = unsafe extern "system" fn(phys_device: vk::PhysicalDevice) -> vk::Result;
 
@@ -88,7 +96,10 @@ pub type PFN_vkSetInstanceLoaderData =
 pub type PFN_vkSetDeviceLoaderData =
     unsafe extern "system" fn(device: vk::Device, p_object: *mut c_void) -> vk::Result;
 
-#[allow(non_camel_case_types)]
+/// Creates a device through the layers below the one whose `vkGetInstanceProcAddr` is
+/// `pfn_layer_GIPA`, writing the `vkGetDeviceProcAddr` to resolve its commands with to
+/// `p_pfn_next_GDPA`.
+#[allow(non_camel_case_types, non_snake_case)]
 pub type PFN_vkLayerCreateDevice = unsafe extern "system" fn(
     instance: vk::Instance,
     physical_device: vk::PhysicalDevice,
@@ -96,12 +107,13 @@ pub type PFN_vkLayerCreateDevice = unsafe extern "system" fn(
     p_allocator: *const vk::AllocationCallbacks,
     p_device: *mut vk::Device,
     pfn_layer_GIPA: Option<vk::PFN_vkGetInstanceProcAddr>,
-    p_pfn_next_GDPA: *mut vk::PFN_vkGetDeviceProcAddr,
+    p_pfn_next_GDPA: *mut Option<vk::PFN_vkGetDeviceProcAddr>,
 ) -> vk::Result;
 
+/// Destroys a device created by [`PFN_vkLayerCreateDevice`] with its `vkDestroyDevice`.
 #[allow(non_camel_case_types)]
 pub type PFN_vkLayerDestroyDevice = unsafe extern "system" fn(
-    physical_device: vk::PhysicalDevice,
+    device: vk::Device,
     p_allocator: *const vk::AllocationCallbacks,
     pfn_destroy_function: vk::PFN_vkDestroyDevice,
 ) -> ();
@@ -116,6 +128,7 @@ impl LoaderFeatureFlagBits {
 }
 
 #[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Copy, Clone)]
 pub struct LayerInstanceCreateInfoLayerDevice {
     pub pfn_layer_create_device: Option<PFN_vkLayerCreateDevice>,
@@ -155,6 +168,68 @@ impl ::core::default::Default for LayerInstanceCreateInfo {
     }
 }
 
+/// Member of [`LayerInstanceCreateInfoUnion`] selected by [`LayerInstanceCreateInfo::function`].
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy)]
+pub enum InstanceChainKind {
+    LinkInfo(*mut LayerInstanceLink),
+    LoaderDataCallback(Option<PFN_vkSetInstanceLoaderData>),
+    CreateDeviceCallback(LayerInstanceCreateInfoLayerDevice),
+    LoaderFeatures(LoaderFeatureFlagBits),
+    Unknown(LayerFunction),
+}
+
+impl LayerInstanceCreateInfo {
+    /// Creates a chain entry with `function` and the union set from `kind`.
+    pub fn new(kind: InstanceChainKind) -> Self {
+        let mut u: LayerInstanceCreateInfoUnion = unsafe { ::core::mem::zeroed() };
+        let function = match kind {
+            InstanceChainKind::LinkInfo(v) => {
+                u.p_layer_info = v;
+                LayerFunction::LAYER_LINK_INFO
+            }
+            InstanceChainKind::LoaderDataCallback(v) => {
+                u.pfn_set_instance_loader_data = v;
+                LayerFunction::LOADER_DATA_CALLBACK
+            }
+            InstanceChainKind::CreateDeviceCallback(v) => {
+                u.layer_device = v;
+                LayerFunction::LOADER_LAYER_CREATE_DEVICE_CALLBACK
+            }
+            InstanceChainKind::LoaderFeatures(v) => {
+                u.loader_features = v;
+                LayerFunction::LOADER_FEATURES
+            }
+            InstanceChainKind::Unknown(v) => v,
+        };
+        Self {
+            function,
+            u,
+            ..Default::default()
+        }
+    }
+
+    /// Reads the union member selected by `function`.
+    ///
+    /// The union must hold that member, as is the case for chain entries passed by the loader.
+    #[inline]
+    pub unsafe fn kind(&self) -> InstanceChainKind {
+        match self.function {
+            LayerFunction::LAYER_LINK_INFO => InstanceChainKind::LinkInfo(self.u.p_layer_info),
+            LayerFunction::LOADER_DATA_CALLBACK => {
+                InstanceChainKind::LoaderDataCallback(self.u.pfn_set_instance_loader_data)
+            }
+            LayerFunction::LOADER_LAYER_CREATE_DEVICE_CALLBACK => {
+                InstanceChainKind::CreateDeviceCallback(self.u.layer_device)
+            }
+            LayerFunction::LOADER_FEATURES => {
+                InstanceChainKind::LoaderFeatures(self.u.loader_features)
+            }
+            function => InstanceChainKind::Unknown(function),
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct LayerDeviceLink {
@@ -194,6 +269,52 @@ impl ::core::default::Default for LayerDeviceCreateInfo {
     }
 }
 
+/// Member of [`LayerDeviceCreateInfoUnion`] selected by [`LayerDeviceCreateInfo::function`].
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy)]
+pub enum DeviceChainKind {
+    LinkInfo(*mut LayerDeviceLink),
+    LoaderDataCallback(Option<PFN_vkSetDeviceLoaderData>),
+    Unknown(LayerFunction),
+}
+
+impl LayerDeviceCreateInfo {
+    /// Creates a chain entry with `function` and the union set from `kind`.
+    pub fn new(kind: DeviceChainKind) -> Self {
+        let mut u: LayerDeviceCreateInfoUnion = unsafe { ::core::mem::zeroed() };
+        let function = match kind {
+            DeviceChainKind::LinkInfo(v) => {
+                u.p_layer_info = v;
+                LayerFunction::LAYER_LINK_INFO
+            }
+            DeviceChainKind::LoaderDataCallback(v) => {
+                u.pfn_set_device_loader_data = v;
+                LayerFunction::LOADER_DATA_CALLBACK
+            }
+            DeviceChainKind::Unknown(v) => v,
+        };
+        Self {
+            function,
+            u,
+            ..Default::default()
+        }
+    }
+
+    /// Reads the union member selected by `function`.
+    ///
+    /// The union must hold that member, as is the case for chain entries passed by the loader.
+    #[inline]
+    pub unsafe fn kind(&self) -> DeviceChainKind {
+        match self.function {
+            LayerFunction::LAYER_LINK_INFO => DeviceChainKind::LinkInfo(self.u.p_layer_info),
+            LayerFunction::LOADER_DATA_CALLBACK => {
+                DeviceChainKind::LoaderDataCallback(self.u.pfn_set_device_loader_data)
+            }
+            function => DeviceChainKind::Unknown(function),
+        }
+    }
+}
+
 #[repr(transparent)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -213,14 +334,45 @@ impl ChainType {
     }
 }
 
+pub const CURRENT_CHAIN_VERSION: u32 = 1;
+
 #[repr(C)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct ChainHeader {
     ty: ChainType,
     version: u32,
     size: u32,
 }
+impl ChainHeader {
+    #[inline]
+    pub const fn new(ty: ChainType, version: u32, size: u32) -> Self {
+        Self { ty, version, size }
+    }
+    #[inline]
+    pub const fn ty(&self) -> ChainType {
+        self.ty
+    }
+    #[inline]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+    #[inline]
+    pub const fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Checks the header describes a chain link of type `ty` at least `size` bytes large.
+    ///
+    /// Newer versions than [`CURRENT_CHAIN_VERSION`] are accepted, as links only grow by
+    /// appending fields.
+    pub fn validate(&self, ty: ChainType, size: usize) -> Result<(), ChainError> {
+        if self.ty != ty || self.version == 0 || (self.size as usize) < size {
+            return Err(ChainError::InvalidHeader(*self));
+        }
+        Ok(())
+    }
+}
 
 #[allow(non_camel_case_types)]
 pub type PFN_layer_vkEnumerateInstanceExtensionProperties = unsafe extern "system" fn(
@@ -239,19 +391,49 @@ pub struct EnumerateInstanceExtensionPropertiesChain {
     pub p_next_link: *const EnumerateInstanceExtensionPropertiesChain,
 }
 impl EnumerateInstanceExtensionPropertiesChain {
+    /// Creates a link calling `pfn_next_layer` with `p_next_link`, which is null at the bottom.
+    #[inline]
+    pub const fn new(
+        pfn_next_layer: PFN_layer_vkEnumerateInstanceExtensionProperties,
+        p_next_link: *const Self,
+    ) -> Self {
+        Self {
+            header: ChainHeader::new(
+                ChainType::ENUMERATE_INSTANCE_EXTENSION_PROPERTIES,
+                CURRENT_CHAIN_VERSION,
+                mem::size_of::<Self>() as u32,
+            ),
+            pfn_next_layer: Some(pfn_next_layer),
+            p_next_link,
+        }
+    }
+
+    #[inline]
+    pub fn validate(&self) -> Result<(), ChainError> {
+        self.header.validate(
+            ChainType::ENUMERATE_INSTANCE_EXTENSION_PROPERTIES,
+            mem::size_of::<Self>(),
+        )
+    }
+
+    /// Calls the next layer after validating this link.
     #[inline]
     pub unsafe fn call_down(
         &self,
         p_layer_name: *const c_char,
         p_property_count: *mut u32,
         p_properties: *mut vk::ExtensionProperties,
-    ) -> vk::Result {
-        (self.pfn_next_layer.expect("pfn_next_layer not set"))(
+    ) -> Result<vk::Result, ChainError> {
+        self.validate()?;
+        let pfn_next_layer = self.pfn_next_layer.ok_or(ChainError::MissingProcAddr(
+            "vkEnumerateInstanceExtensionProperties",
+        ))?;
+        Ok(pfn_next_layer(
             self.p_next_link,
             p_layer_name,
             p_property_count,
             p_properties,
-        )
+        ))
     }
 }
 
@@ -270,17 +452,47 @@ pub struct EnumerateInstanceLayerPropertiesChain {
     pub p_next_link: *const EnumerateInstanceLayerPropertiesChain,
 }
 impl EnumerateInstanceLayerPropertiesChain {
+    /// Creates a link calling `pfn_next_layer` with `p_next_link`, which is null at the bottom.
+    #[inline]
+    pub const fn new(
+        pfn_next_layer: PFN_layer_vkEnumerateInstanceLayerProperties,
+        p_next_link: *const Self,
+    ) -> Self {
+        Self {
+            header: ChainHeader::new(
+                ChainType::ENUMERATE_INSTANCE_LAYER_PROPERTIES,
+                CURRENT_CHAIN_VERSION,
+                mem::size_of::<Self>() as u32,
+            ),
+            pfn_next_layer: Some(pfn_next_layer),
+            p_next_link,
+        }
+    }
+
+    #[inline]
+    pub fn validate(&self) -> Result<(), ChainError> {
+        self.header.validate(
+            ChainType::ENUMERATE_INSTANCE_LAYER_PROPERTIES,
+            mem::size_of::<Self>(),
+        )
+    }
+
+    /// Calls the next layer after validating this link.
     #[inline]
     pub unsafe fn call_down(
         &self,
         p_property_count: *mut u32,
         p_properties: *mut vk::LayerProperties,
-    ) -> vk::Result {
-        (self.pfn_next_layer.expect("pfn_next_layer not ser"))(
+    ) -> Result<vk::Result, ChainError> {
+        self.validate()?;
+        let pfn_next_layer = self.pfn_next_layer.ok_or(ChainError::MissingProcAddr(
+            "vkEnumerateInstanceLayerProperties",
+        ))?;
+        Ok(pfn_next_layer(
             self.p_next_link,
             p_property_count,
             p_properties,
-        )
+        ))
     }
 }
 
@@ -298,8 +510,273 @@ pub struct EnumerateInstanceVersionChain {
     pub p_next_link: *const EnumerateInstanceVersionChain,
 }
 impl EnumerateInstanceVersionChain {
+    /// Creates a link calling `pfn_next_layer` with `p_next_link`, which is null at the bottom.
+    #[inline]
+    pub const fn new(
+        pfn_next_layer: PFN_layer_vkEnumerateInstanceVersion,
+        p_next_link: *const Self,
+    ) -> Self {
+        Self {
+            header: ChainHeader::new(
+                ChainType::ENUMERATE_INSTANCE_VERSION,
+                CURRENT_CHAIN_VERSION,
+                mem::size_of::<Self>() as u32,
+            ),
+            pfn_next_layer: Some(pfn_next_layer),
+            p_next_link,
+        }
+    }
+
+    #[inline]
+    pub fn validate(&self) -> Result<(), ChainError> {
+        self.header.validate(
+            ChainType::ENUMERATE_INSTANCE_VERSION,
+            mem::size_of::<Self>(),
+        )
+    }
+
+    /// Calls the next layer after validating this link.
+    #[inline]
+    pub unsafe fn call_down(&self, p_api_version: *mut u32) -> Result<vk::Result, ChainError> {
+        self.validate()?;
+        let pfn_next_layer = self
+            .pfn_next_layer
+            .ok_or(ChainError::MissingProcAddr("vkEnumerateInstanceVersion"))?;
+        Ok(pfn_next_layer(self.p_next_link, p_api_version))
+    }
+}
+
+// vk_icd.h
+
+pub const CURRENT_LOADER_ICD_INTERFACE_VERSION: u32 = 7;
+pub const MIN_SUPPORTED_LOADER_ICD_INTERFACE_VERSION: u32 = 0;
+pub const MIN_PHYS_DEV_EXTENSION_ICD_INTERFACE_VERSION: u32 = 4;
+
+#[allow(non_camel_case_types)]
+pub type PFN_vkNegotiateLoaderICDInterfaceVersion =
+    unsafe extern "system" fn(p_supported_version: *mut u32) -> vk::Result;
+pub const VK_ICD_NEGOTIATE_LOADER_ICD_INTERFACE_VERSION_SYM: &str =
+    "vk_icdNegotiateLoaderICDInterfaceVersion";
+
+#[allow(non_camel_case_types)]
+pub type PFN_vk_icdGetInstanceProcAddr = unsafe extern "system" fn(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction;
+pub const VK_ICD_GET_INSTANCE_PROC_ADDR_SYM: &str = "vk_icdGetInstanceProcAddr";
+
+#[allow(non_camel_case_types)]
+pub type PFN_vk_icdGetPhysicalDeviceProcAddr = unsafe extern "system" fn(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction;
+pub const VK_ICD_GET_PHYSICAL_DEVICE_PROC_ADDR_SYM: &str = "vk_icdGetPhysicalDeviceProcAddr";
+
+pub const ICD_LOADER_MAGIC: usize = 0x01CDC0DE;
+
+/// First member of every dispatchable object created by an ICD, overwritten by the loader with
+/// its dispatch table.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union LoaderData {
+    pub loader_magic: usize,
+    pub loader_data: *mut c_void,
+}
+impl LoaderData {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            loader_magic: ICD_LOADER_MAGIC,
+        }
+    }
+    /// Whether the loader has not yet replaced the magic value.
+    #[inline]
+    pub fn is_magic_valid(&self) -> bool {
+        unsafe { self.loader_magic & 0xffffffff == ICD_LOADER_MAGIC }
+    }
+}
+impl ::core::default::Default for LoaderData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[inline]
+pub unsafe fn set_loader_magic_value(p_new_object: *mut c_void) {
+    p_new_object.cast::<LoaderData>().write(LoaderData::new())
+}
+
+#[inline]
+pub unsafe fn valid_loader_magic_value(p_new_object: *const c_void) -> bool {
+    (*p_new_object.cast::<LoaderData>()).is_magic_valid()
+}
+
+#[repr(transparent)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IcdWsiPlatform(pub(crate) i32);
+impl IcdWsiPlatform {
+    pub const MIR: Self = Self(0);
+    pub const WAYLAND: Self = Self(1);
+    pub const WIN32: Self = Self(2);
+    pub const XCB: Self = Self(3);
+    pub const XLIB: Self = Self(4);
+    pub const ANDROID: Self = Self(5);
+    pub const MACOS: Self = Self(6);
+    pub const IOS: Self = Self(7);
+    pub const DISPLAY: Self = Self(8);
+    pub const HEADLESS: Self = Self(9);
+    pub const METAL: Self = Self(10);
+    pub const DIRECTFB: Self = Self(11);
+    pub const VI: Self = Self(12);
+    pub const GGP: Self = Self(13);
+    pub const SCREEN: Self = Self(14);
+    pub const FUCHSIA: Self = Self(15);
     #[inline]
-    pub unsafe fn call_down(&self, p_api_version: *mut u32) -> vk::Result {
-        (self.pfn_next_layer.expect("pfn_next_layer not set"))(self.p_next_link, p_api_version)
+    pub const fn from_raw(x: i32) -> Self {
+        Self(x)
+    }
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
     }
 }
+
+/// Common header of the `VkSurfaceKHR` objects created by the loader for ICDs.
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceBase {
+    pub platform: IcdWsiPlatform,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceWayland {
+    pub base: IcdSurfaceBase,
+    pub display: *mut vk::wl_display,
+    pub surface: *mut vk::wl_surface,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceWin32 {
+    pub base: IcdSurfaceBase,
+    pub hinstance: vk::HINSTANCE,
+    pub hwnd: vk::HWND,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceXcb {
+    pub base: IcdSurfaceBase,
+    pub connection: *mut vk::xcb_connection_t,
+    pub window: vk::xcb_window_t,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceXlib {
+    pub base: IcdSurfaceBase,
+    pub dpy: *mut vk::Display,
+    pub window: vk::Window,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceDirectFB {
+    pub base: IcdSurfaceBase,
+    pub dfb: *mut vk::IDirectFB,
+    pub surface: *mut vk::IDirectFBSurface,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceAndroid {
+    pub base: IcdSurfaceBase,
+    pub window: *mut vk::ANativeWindow,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceMacOS {
+    pub base: IcdSurfaceBase,
+    pub p_view: *const c_void,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceIOS {
+    pub base: IcdSurfaceBase,
+    pub p_view: *const c_void,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceGgp {
+    pub base: IcdSurfaceBase,
+    pub stream_descriptor: vk::GgpStreamDescriptor,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceMetal {
+    pub base: IcdSurfaceBase,
+    pub p_layer: *const vk::CAMetalLayer,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceDisplay {
+    pub base: IcdSurfaceBase,
+    pub display_mode: vk::DisplayModeKHR,
+    pub plane_index: u32,
+    pub plane_stack_index: u32,
+    pub transform: vk::SurfaceTransformFlagsKHR,
+    pub global_alpha: f32,
+    pub alpha_mode: vk::DisplayPlaneAlphaFlagsKHR,
+    pub image_extent: vk::Extent2D,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceHeadless {
+    pub base: IcdSurfaceBase,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceVi {
+    pub base: IcdSurfaceBase,
+    pub window: *mut c_void,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceScreen {
+    pub base: IcdSurfaceBase,
+    pub context: *mut vk::_screen_context,
+    pub window: *mut vk::_screen_window,
+}
+
+#[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Copy, Clone)]
+pub struct IcdSurfaceImagePipe {
+    pub base: IcdSurfaceBase,
+    pub image_pipe_handle: vk::zx_handle_t,
+}
@@ -1,40 +1,271 @@
 use crate::*;
 
+use ash::prelude::VkResult;
 use ash::vk;
 use ash::vk::TaggedStructure;
 
-use core::ptr::NonNull;
+use core::ffi::{c_char, c_void, CStr};
+use core::ptr::{self, NonNull};
+
+/// Maximum number of `p_next` structures walked before a chain is considered malformed.
+pub const MAX_CHAIN_DEPTH: usize = 1024;
+
+/// Iterator over a `p_next` chain, created by [`iter_chain`].
+pub struct ChainIter {
+    next: *const vk::BaseInStructure,
+    slow: *const vk::BaseInStructure,
+    depth: usize,
+    error: Option<ChainError>,
+    done: bool,
+}
+
+impl Iterator for ChainIter {
+    type Item = Result<NonNull<vk::BaseInStructure>, ChainError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if let Some(err) = self.error.take() {
+            self.done = true;
+            return Some(Err(err));
+        }
+        let node = NonNull::new(self.next as *mut vk::BaseInStructure)?;
+        if self.depth == MAX_CHAIN_DEPTH {
+            self.done = true;
+            return Some(Err(ChainError::TooDeep));
+        }
+        self.depth += 1;
+        // only the header is read, the node may be any structure smaller than the ones we look for
+        self.next = unsafe { node.as_ref().p_next };
+        // Floyd's cycle detection, the slow pointer advances every other node
+        if self.depth % 2 == 0 {
+            self.slow = unsafe { (*self.slow).p_next };
+        }
+        if !self.next.is_null() && self.next == self.slow {
+            self.error = Some(ChainError::Cyclic);
+        }
+        Some(Ok(node))
+    }
+}
+
+/// Walks the `p_next` chain starting at `p_next` through the `vk::BaseInStructure` headers.
+///
+/// Every node must be a valid structure header, a cycle or a chain deeper than
+/// [`MAX_CHAIN_DEPTH`] yields an error as the last item.
+pub unsafe fn iter_chain(p_next: *const c_void) -> ChainIter {
+    ChainIter {
+        next: p_next.cast(),
+        slow: p_next.cast(),
+        depth: 0,
+        error: None,
+        done: false,
+    }
+}
+
+/// Finds the first structure of type `T` in a `p_next` chain matching `predicate`.
+pub unsafe fn find_in_chain<T: TaggedStructure>(
+    p_next: *const c_void,
+    mut predicate: impl FnMut(&T) -> bool,
+) -> Result<Option<NonNull<T>>, ChainError> {
+    for node in iter_chain(p_next) {
+        let node = node?;
+        if node.as_ref().s_type == T::STRUCTURE_TYPE {
+            let node = node.cast::<T>();
+            if predicate(node.as_ref()) {
+                return Ok(Some(node));
+            }
+        }
+    }
+    Ok(None)
+}
 
 pub unsafe fn get_instance_chain_info(
     create_info: &vk::InstanceCreateInfo,
     function: LayerFunction,
-) -> Option<NonNull<LayerInstanceCreateInfo>> {
-    let mut chain_info_ptr = create_info.p_next.cast::<LayerInstanceCreateInfo>();
-    while !chain_info_ptr.is_null() {
-        let chain_info = chain_info_ptr.read();
-        if chain_info.s_type == LayerInstanceCreateInfo::STRUCTURE_TYPE
-            && chain_info.function == function
-        {
-            return Some(NonNull::new_unchecked(chain_info_ptr as _));
-        }
-        chain_info_ptr = chain_info.p_next.cast()
-    }
-    None
+) -> Result<NonNull<LayerInstanceCreateInfo>, ChainError> {
+    find_in_chain(create_info.p_next, |info: &LayerInstanceCreateInfo| {
+        info.function == function
+    })?
+    .ok_or(ChainError::MissingChainInfo(function))
 }
 
 pub unsafe fn get_device_chain_info(
     create_info: &vk::DeviceCreateInfo,
     function: LayerFunction,
-) -> Option<NonNull<LayerDeviceCreateInfo>> {
-    let mut chain_info_ptr = create_info.p_next.cast::<LayerDeviceCreateInfo>();
-    while !chain_info_ptr.is_null() {
-        let chain_info = chain_info_ptr.read();
-        if chain_info.s_type == LayerDeviceCreateInfo::STRUCTURE_TYPE
-            && chain_info.function == function
-        {
-            return Some(NonNull::new_unchecked(chain_info_ptr as _));
-        }
-        chain_info_ptr = chain_info.p_next.cast()
+) -> Result<NonNull<LayerDeviceCreateInfo>, ChainError> {
+    find_in_chain(create_info.p_next, |info: &LayerDeviceCreateInfo| {
+        info.function == function
+    })?
+    .ok_or(ChainError::MissingChainInfo(function))
+}
+
+/// Error of a malformed layer chain passed by the loader.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ChainError {
+    /// No chain entry with the requested [`LayerFunction`].
+    MissingChainInfo(LayerFunction),
+    /// The layer link list is exhausted.
+    EndOfLinks,
+    /// A link lacks a mandatory next-layer entry point.
+    MissingProcAddr(&'static str),
+    /// The `p_next` chain loops back onto itself.
+    Cyclic,
+    /// The `p_next` chain is longer than [`MAX_CHAIN_DEPTH`].
+    TooDeep,
+    /// A pre-instance chain link has an unexpected type, unknown version or is too small.
+    InvalidHeader(ChainHeader),
+}
+
+impl core::fmt::Display for ChainError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingChainInfo(function) => {
+                write!(f, "no layer chain info for function {}", function.as_raw())
+            }
+            Self::EndOfLinks => f.write_str("no next layer link"),
+            Self::MissingProcAddr(name) => write!(f, "next layer link lacks {}", name),
+            Self::Cyclic => f.write_str("cyclic p_next chain"),
+            Self::TooDeep => write!(f, "p_next chain deeper than {}", MAX_CHAIN_DEPTH),
+            Self::InvalidHeader(header) => write!(
+                f,
+                "invalid chain header of type {}, version {} and size {}",
+                header.ty().as_raw(),
+                header.version(),
+                header.size()
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "debug")]
+impl std::error::Error for ChainError {}
+
+impl From<ChainError> for vk::Result {
+    fn from(_: ChainError) -> Self {
+        vk::Result::ERROR_INITIALIZATION_FAILED
+    }
+}
+
+/// Entry points of the next layer popped from an instance chain.
+#[derive(Clone, Copy)]
+pub struct NextInstanceProcs {
+    pub get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    pub get_physical_device_proc_addr: Option<PFN_vk_layerGetPhysicalDeviceProcAddr>,
+}
+
+/// Entry points of the next layer popped from a device chain.
+#[derive(Clone, Copy)]
+pub struct NextDeviceProcs {
+    pub get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
+    pub get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
+}
+
+/// Pops this layer's link off the instance chain, which must be done before calling down
+/// `vkCreateInstance`.
+///
+/// The chain is left untouched if the link is invalid.
+pub unsafe fn advance_instance_link(
+    create_info: &vk::InstanceCreateInfo,
+) -> Result<NextInstanceProcs, ChainError> {
+    let function = LayerFunction::LAYER_LINK_INFO;
+    let mut chain_info = get_instance_chain_info(create_info, function)?;
+    let chain_info = chain_info.as_mut();
+    let layer_info = match chain_info.kind() {
+        InstanceChainKind::LinkInfo(v) if !v.is_null() => v.read(),
+        _ => return Err(ChainError::EndOfLinks),
+    };
+    let procs = NextInstanceProcs {
+        get_instance_proc_addr: layer_info
+            .pfn_next_get_instance_proc_addr
+            .ok_or(ChainError::MissingProcAddr("vkGetInstanceProcAddr"))?,
+        get_physical_device_proc_addr: layer_info.pfn_next_get_physical_device_proc_addr,
+    };
+    chain_info.u.p_layer_info = layer_info.p_next;
+    Ok(procs)
+}
+
+/// Pops this layer's link off the device chain, which must be done before calling down
+/// `vkCreateDevice`.
+///
+/// The chain is left untouched if the link is invalid.
+pub unsafe fn advance_device_link(
+    create_info: &vk::DeviceCreateInfo,
+) -> Result<NextDeviceProcs, ChainError> {
+    let function = LayerFunction::LAYER_LINK_INFO;
+    let mut chain_info = get_device_chain_info(create_info, function)?;
+    let chain_info = chain_info.as_mut();
+    let layer_info = match chain_info.kind() {
+        DeviceChainKind::LinkInfo(v) if !v.is_null() => v.read(),
+        _ => return Err(ChainError::EndOfLinks),
+    };
+    let procs = NextDeviceProcs {
+        get_instance_proc_addr: layer_info
+            .pfn_next_get_instance_proc_addr
+            .ok_or(ChainError::MissingProcAddr("vkGetInstanceProcAddr"))?,
+        get_device_proc_addr: layer_info
+            .pfn_next_get_device_proc_addr
+            .ok_or(ChainError::MissingProcAddr("vkGetDeviceProcAddr"))?,
+    };
+    chain_info.u.p_layer_info = layer_info.p_next;
+    Ok(procs)
+}
+
+/// Reads an array through the two-call idiom of `f`, retrying while it reports `INCOMPLETE`.
+pub unsafe fn read_array<T: Default + Clone>(
+    mut f: impl FnMut(*mut u32, *mut T) -> vk::Result,
+) -> VkResult<Vec<T>> {
+    loop {
+        let mut count = 0;
+        f(&mut count, ptr::null_mut()).result()?;
+        let mut items = vec![T::default(); count as usize];
+        let res = f(&mut count, items.as_mut_ptr());
+        if res != vk::Result::INCOMPLETE {
+            items.truncate(count as usize);
+            return res.result_with_success(items);
+        }
+    }
+}
+
+/// Writes `items` out following the two-call idiom, returning `INCOMPLETE` if truncated.
+pub unsafe fn write_array<T: Copy>(items: &[T], p_count: *mut u32, p_items: *mut T) -> vk::Result {
+    let mut out = OutArray::new(p_count, p_items);
+    out.extend(items.iter().copied());
+    out.finish()
+}
+
+/// Builds the properties of an extension, `name` is truncated to fit.
+pub fn extension_properties(name: &CStr, spec_version: u32) -> vk::ExtensionProperties {
+    let mut properties = vk::ExtensionProperties {
+        spec_version,
+        ..Default::default()
+    };
+    copy_c_str(&mut properties.extension_name, name.to_bytes());
+    properties
+}
+
+/// Builds the properties of a layer, `name` and `description` are truncated to fit.
+pub fn layer_properties(
+    name: &str,
+    spec_version: u32,
+    implementation_version: u32,
+    description: &str,
+) -> vk::LayerProperties {
+    let mut properties = vk::LayerProperties {
+        spec_version,
+        implementation_version,
+        ..Default::default()
+    };
+    copy_c_str(&mut properties.layer_name, name.as_bytes());
+    copy_c_str(&mut properties.description, description.as_bytes());
+    properties
+}
+
+// leaves the zeroed terminator of `dst` in place
+fn copy_c_str(dst: &mut [c_char], src: &[u8]) {
+    let len = dst.len() - 1;
+    for (dst, src) in dst[..len].iter_mut().zip(src) {
+        *dst = *src as c_char;
     }
-    None
 }
@@ -0,0 +1,192 @@
+use ash::vk;
+
+use core::ffi::{c_char, CStr};
+use core::mem::MaybeUninit;
+use core::slice;
+
+/// Slice of `count` items at `ptr`, empty if `ptr` is null or `count` is zero.
+///
+/// Otherwise `ptr` must point to `count` valid items living for `'a`.
+#[inline]
+pub unsafe fn slice_from_raw<'a, T>(ptr: *const T, count: u32) -> &'a [T] {
+    if ptr.is_null() || count == 0 {
+        return &[];
+    }
+    slice::from_raw_parts(ptr, count as usize)
+}
+
+/// Mutable version of [`slice_from_raw`], e.g. for the items the next layer wrote to an output
+/// array, `slice_from_raw_mut(p_properties, *p_property_count)`.
+#[inline]
+pub unsafe fn slice_from_raw_mut<'a, T>(ptr: *mut T, count: u32) -> &'a mut [T] {
+    if ptr.is_null() || count == 0 {
+        return &mut [];
+    }
+    slice::from_raw_parts_mut(ptr, count as usize)
+}
+
+/// String at `ptr`, `None` if null.
+#[inline]
+pub unsafe fn c_str_from_raw<'a>(ptr: *const c_char) -> Option<&'a CStr> {
+    if ptr.is_null() {
+        return None;
+    }
+    Some(CStr::from_ptr(ptr))
+}
+
+/// List of strings such as the enabled extension names of a create info.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, Default)]
+pub struct CStrList<'a> {
+    names: &'a [*const c_char],
+}
+
+impl<'a> CStrList<'a> {
+    /// List of `count` strings at `names`, empty if null. Null strings are skipped.
+    #[inline]
+    pub unsafe fn new(count: u32, names: *const *const c_char) -> Self {
+        Self {
+            names: slice_from_raw(names, count),
+        }
+    }
+
+    pub unsafe fn instance_extensions(create_info: &'a vk::InstanceCreateInfo) -> Self {
+        Self::new(
+            create_info.enabled_extension_count,
+            create_info.pp_enabled_extension_names,
+        )
+    }
+
+    pub unsafe fn instance_layers(create_info: &'a vk::InstanceCreateInfo) -> Self {
+        Self::new(
+            create_info.enabled_layer_count,
+            create_info.pp_enabled_layer_names,
+        )
+    }
+
+    pub unsafe fn device_extensions(create_info: &'a vk::DeviceCreateInfo) -> Self {
+        Self::new(
+            create_info.enabled_extension_count,
+            create_info.pp_enabled_extension_names,
+        )
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a CStr> + Clone {
+        let names = self.names.iter();
+        names.filter_map(|&v| unsafe { c_str_from_raw(v) })
+    }
+
+    pub fn contains(&self, name: &CStr) -> bool {
+        self.iter().any(|v| v == name)
+    }
+
+    /// Number of pointers, including null ones.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+/// Output array of the two-call idiom, counting the items pushed if `p_items` is null and
+/// writing as many as fit otherwise.
+///
+/// ```no_run
+/// # use ash::vk;
+/// # use ash_layer::OutArray;
+/// unsafe extern "system" fn enumerate_properties(
+///     p_property_count: *mut u32,
+///     p_properties: *mut vk::ExtensionProperties,
+/// ) -> vk::Result {
+///     let mut out = OutArray::new(p_property_count, p_properties);
+///     out.extend([vk::ExtensionProperties::default()]);
+///     out.finish()
+/// }
+/// ```
+pub struct OutArray<'a, T> {
+    p_count: &'a mut u32,
+    items: Option<&'a mut [MaybeUninit<T>]>,
+    len: usize,
+    incomplete: bool,
+}
+
+impl<'a, T> OutArray<'a, T> {
+    /// `p_count` must be valid, and `p_items` null or valid for `*p_count` items.
+    pub unsafe fn new(p_count: *mut u32, p_items: *mut T) -> Self {
+        let p_count = &mut *p_count;
+        let items = if p_items.is_null() {
+            None
+        } else {
+            Some(slice::from_raw_parts_mut(
+                p_items.cast::<MaybeUninit<T>>(),
+                *p_count as usize,
+            ))
+        };
+        Self {
+            p_count,
+            items,
+            len: 0,
+            incomplete: false,
+        }
+    }
+
+    /// Whether only the count is queried.
+    #[inline]
+    pub fn is_query(&self) -> bool {
+        self.items.is_none()
+    }
+
+    /// Number of items that fit, `None` if only the count is queried.
+    #[inline]
+    pub fn capacity(&self) -> Option<usize> {
+        self.items.as_ref().map(|v| v.len())
+    }
+
+    /// Items written so far.
+    pub fn as_slice(&self) -> &[T] {
+        match &self.items {
+            Some(v) => unsafe { slice::from_raw_parts(v.as_ptr().cast(), self.len) },
+            None => &[],
+        }
+    }
+
+    /// Writes or counts `item`, returning `false` if it does not fit.
+    pub fn push(&mut self, item: T) -> bool {
+        match &mut self.items {
+            None => {}
+            Some(items) if self.len < items.len() => {
+                items[self.len].write(item);
+            }
+            Some(_) => {
+                self.incomplete = true;
+                return false;
+            }
+        }
+        self.len += 1;
+        true
+    }
+
+    /// Stores the count, returning `INCOMPLETE` if items did not fit.
+    pub fn finish(self) -> vk::Result {
+        *self.p_count = self.len as u32;
+        if self.incomplete {
+            vk::Result::INCOMPLETE
+        } else {
+            vk::Result::SUCCESS
+        }
+    }
+}
+
+impl<'a, T> Extend<T> for OutArray<'a, T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            if !self.push(item) {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use ash::vk;
+use ash_layer::*;
+
+use std::ffi::{c_char, c_void};
+use std::ptr;
+
+unsafe extern "system" fn next_get_instance_proc_addr(
+    _instance: vk::Instance,
+    _p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    None
+}
+
+unsafe extern "system" fn next_get_device_proc_addr(
+    _device: vk::Device,
+    _p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    None
+}
+
+fn instance_link(p_next: *mut LayerInstanceLink) -> LayerInstanceLink {
+    LayerInstanceLink {
+        p_next,
+        pfn_next_get_instance_proc_addr: Some(next_get_instance_proc_addr),
+        pfn_next_get_physical_device_proc_addr: None,
+    }
+}
+
+#[test]
+fn advance_instance_link_pops_links_in_order() {
+    let mut second = instance_link(ptr::null_mut());
+    let mut first = instance_link(&mut second);
+    let chain_info = LayerInstanceCreateInfo::new(InstanceChainKind::LinkInfo(&mut first));
+    let create_info = vk::InstanceCreateInfo {
+        p_next: &chain_info as *const _ as *const c_void,
+        ..Default::default()
+    };
+    unsafe {
+        assert!(advance_instance_link(&create_info).is_ok());
+        assert!(matches!(
+            chain_info.kind(),
+            InstanceChainKind::LinkInfo(v) if ptr::eq(v, &second)
+        ));
+        assert!(advance_instance_link(&create_info).is_ok());
+        assert!(advance_instance_link(&create_info).err() == Some(ChainError::EndOfLinks));
+    }
+}
+
+#[test]
+fn advance_instance_link_keeps_invalid_link() {
+    let mut link = LayerInstanceLink {
+        pfn_next_get_instance_proc_addr: None,
+        ..instance_link(ptr::null_mut())
+    };
+    let chain_info = LayerInstanceCreateInfo::new(InstanceChainKind::LinkInfo(&mut link));
+    let create_info = vk::InstanceCreateInfo {
+        p_next: &chain_info as *const _ as *const c_void,
+        ..Default::default()
+    };
+    unsafe {
+        assert!(
+            advance_instance_link(&create_info).err()
+                == Some(ChainError::MissingProcAddr("vkGetInstanceProcAddr"))
+        );
+        assert!(matches!(
+            chain_info.kind(),
+            InstanceChainKind::LinkInfo(v) if ptr::eq(v, &link)
+        ));
+        let function = LayerFunction::LAYER_LINK_INFO;
+        assert!(
+            advance_instance_link(&Default::default()).err()
+                == Some(ChainError::MissingChainInfo(function))
+        );
+    }
+}
+
+#[test]
+fn advance_device_link_returns_next_procs() {
+    let mut link = LayerDeviceLink {
+        p_next: ptr::null_mut(),
+        pfn_next_get_instance_proc_addr: Some(next_get_instance_proc_addr),
+        pfn_next_get_device_proc_addr: Some(next_get_device_proc_addr),
+    };
+    let chain_info = LayerDeviceCreateInfo::new(DeviceChainKind::LinkInfo(&mut link));
+    let create_info = vk::DeviceCreateInfo {
+        p_next: &chain_info as *const _ as *const c_void,
+        ..Default::default()
+    };
+    unsafe {
+        let next = advance_device_link(&create_info).ok().unwrap();
+        let name = b"vkDestroyDevice\0".as_ptr().cast();
+        assert!((next.get_device_proc_addr)(vk::Device::null(), name).is_none());
+        assert!(advance_device_link(&create_info).err() == Some(ChainError::EndOfLinks));
+    }
+}
+
+fn linked_nodes(len: usize) -> Vec<vk::BaseInStructure> {
+    let mut nodes = vec![vk::BaseInStructure::default(); len];
+    for i in 1..len {
+        nodes[i - 1].p_next = &nodes[i];
+    }
+    nodes
+}
+
+#[test]
+fn iter_chain_walks_every_node() {
+    let nodes = linked_nodes(5);
+    let walked: Vec<_> = unsafe { iter_chain(nodes.as_ptr().cast()) }
+        .map(|v| v.ok().unwrap().as_ptr() as *const vk::BaseInStructure)
+        .collect();
+    let expected: Vec<_> = nodes.iter().map(|v| v as *const _).collect();
+    assert_eq!(walked, expected);
+    assert_eq!(unsafe { iter_chain(ptr::null()) }.count(), 0);
+}
+
+#[test]
+fn iter_chain_reports_cycles() {
+    for (len, back_to) in [(1, 0), (2, 0), (5, 2), (6, 5)] {
+        let mut nodes = linked_nodes(len);
+        let back: *const vk::BaseInStructure = &nodes[back_to];
+        nodes[len - 1].p_next = back;
+        let items: Vec<_> = unsafe { iter_chain(nodes.as_ptr().cast()) }.collect();
+        assert!(items.len() <= 2 * len + 1, "{} {}", len, back_to);
+        let (last, rest) = items.split_last().unwrap();
+        assert!(rest.iter().all(Result::is_ok));
+        assert!(
+            last.err() == Some(ChainError::Cyclic),
+            "{} {}",
+            len,
+            back_to
+        );
+    }
+}
+
+#[test]
+fn iter_chain_limits_depth() {
+    let nodes = linked_nodes(MAX_CHAIN_DEPTH + 1);
+    let items: Vec<_> = unsafe { iter_chain(nodes.as_ptr().cast()) }.collect();
+    assert_eq!(items.len(), MAX_CHAIN_DEPTH + 1);
+    assert!(items[MAX_CHAIN_DEPTH].err() == Some(ChainError::TooDeep));
+
+    let nodes = linked_nodes(MAX_CHAIN_DEPTH);
+    let items = unsafe { iter_chain(nodes.as_ptr().cast()) };
+    assert!(items.map(|v| v.ok()).all(|v| v.is_some()));
+}
+
+#[test]
+fn find_in_chain_matches_type_and_predicate() {
+    let features = vk::PhysicalDeviceFeatures2::default();
+    let mut second = LayerInstanceCreateInfo::new(InstanceChainKind::LoaderFeatures(
+        LoaderFeatureFlagBits::PHYSICAL_DEVICE_SORTING,
+    ));
+    second.p_next = &features as *const _ as *const c_void;
+    let mut first = LayerInstanceCreateInfo::new(InstanceChainKind::LinkInfo(ptr::null_mut()));
+    first.p_next = &second as *const _ as *const c_void;
+    unsafe {
+        let p_next = &first as *const _ as *const c_void;
+        let found = find_in_chain(p_next, |v: &LayerInstanceCreateInfo| {
+            v.function == LayerFunction::LOADER_FEATURES
+        });
+        assert!(found.ok().flatten().map(|v| v.as_ptr() as *const _) == Some(&second as *const _));
+        let found = find_in_chain(p_next, |_: &vk::PhysicalDeviceFeatures2| true);
+        assert!(found.ok().flatten().is_some());
+        let found = find_in_chain(p_next, |_: &vk::PhysicalDeviceVulkan11Features| true);
+        assert!(found.ok().flatten().is_none());
+    }
+}
@@ -0,0 +1,394 @@
+#![cfg(feature = "mock")]
+
+use ash::vk;
+use ash_layer::mock::*;
+use ash_layer::*;
+
+use std::ffi::{c_char, c_void, CStr};
+use std::mem;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+static EVENTS: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+struct OrderedLayer;
+
+impl Layer for OrderedLayer {
+    unsafe fn create_device(
+        _create_info: &vk::DeviceCreateInfo,
+        _instance: &InstanceDispatch,
+        _device: &DeviceDispatch,
+    ) -> vk::Result {
+        EVENTS.lock().unwrap().push("layer create");
+        vk::Result::SUCCESS
+    }
+
+    unsafe fn destroy_device(_device: &DeviceDispatch) {
+        EVENTS.lock().unwrap().push("layer destroy");
+    }
+}
+
+// Hand-written layer stacked above `OrderedLayer`, seeing the device chain like any other
+// layer would.
+
+static RAW_NEXT_GIPA: Mutex<Option<vk::PFN_vkGetInstanceProcAddr>> = Mutex::new(None);
+static RAW_NEXT_GDPA: Mutex<Option<vk::PFN_vkGetDeviceProcAddr>> = Mutex::new(None);
+
+unsafe fn raw_next_device_proc_addr<F>(device: vk::Device, name: &[u8]) -> Option<F> {
+    let get_device_proc_addr = RAW_NEXT_GDPA.lock().unwrap().unwrap();
+    let pfn = get_device_proc_addr(device, name.as_ptr().cast())?;
+    Some(mem::transmute_copy(&pfn))
+}
+
+unsafe extern "system" fn raw_create_instance(
+    p_create_info: *const vk::InstanceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    let next = advance_instance_link(&*p_create_info).ok().unwrap();
+    *RAW_NEXT_GIPA.lock().unwrap() = Some(next.get_instance_proc_addr);
+    let name = b"vkCreateInstance\0".as_ptr().cast();
+    let create_instance: vk::PFN_vkCreateInstance =
+        mem::transmute((next.get_instance_proc_addr)(vk::Instance::null(), name).unwrap());
+    create_instance(p_create_info, p_allocator, p_instance)
+}
+
+unsafe extern "system" fn raw_create_device(
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    let next = advance_device_link(&*p_create_info).ok().unwrap();
+    *RAW_NEXT_GDPA.lock().unwrap() = Some(next.get_device_proc_addr);
+    let name = b"vkCreateDevice\0".as_ptr().cast();
+    let create_device: vk::PFN_vkCreateDevice =
+        mem::transmute((next.get_instance_proc_addr)(vk::Instance::null(), name).unwrap());
+    EVENTS.lock().unwrap().push("raw create");
+    let res = create_device(physical_device, p_create_info, p_allocator, p_device);
+    EVENTS.lock().unwrap().push("raw created");
+    res
+}
+
+unsafe extern "system" fn raw_destroy_device(
+    device: vk::Device,
+    p_allocator: *const vk::AllocationCallbacks,
+) {
+    EVENTS.lock().unwrap().push("raw destroy");
+    let destroy_device: vk::PFN_vkDestroyDevice =
+        raw_next_device_proc_addr(device, b"vkDestroyDevice\0").unwrap();
+    destroy_device(device, p_allocator);
+}
+
+unsafe extern "system" fn raw_get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    match CStr::from_ptr(p_name).to_bytes() {
+        b"vkCreateInstance" => Some(mem::transmute::<
+            vk::PFN_vkCreateInstance,
+            unsafe extern "system" fn(),
+        >(raw_create_instance)),
+        b"vkCreateDevice" => Some(mem::transmute::<
+            vk::PFN_vkCreateDevice,
+            unsafe extern "system" fn(),
+        >(raw_create_device)),
+        _ => (RAW_NEXT_GIPA.lock().unwrap().unwrap())(instance, p_name),
+    }
+}
+
+unsafe extern "system" fn raw_get_device_proc_addr(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    match CStr::from_ptr(p_name).to_bytes() {
+        b"vkDestroyDevice" => Some(mem::transmute::<
+            vk::PFN_vkDestroyDevice,
+            unsafe extern "system" fn(),
+        >(raw_destroy_device)),
+        _ => (RAW_NEXT_GDPA.lock().unwrap().unwrap())(device, p_name),
+    }
+}
+
+unsafe extern "system" fn raw_negotiate(
+    p_version_struct: *mut NegotiateLayerInterface,
+) -> vk::Result {
+    (*p_version_struct).pfn_get_instance_proc_addr = Some(raw_get_instance_proc_addr);
+    (*p_version_struct).pfn_get_device_proc_addr = Some(raw_get_device_proc_addr);
+    vk::Result::SUCCESS
+}
+
+#[test]
+fn device_chain_runs_top_down() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader.add_layer(raw_negotiate).unwrap();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<OrderedLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = instance.table().enumerate_physical_devices().unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+        assert_eq!(
+            *EVENTS.lock().unwrap(),
+            ["raw create", "layer create", "raw created"]
+        );
+
+        // the layer below resolves device commands once it created the device
+        let queue: vk::PFN_vkGetDeviceQueue =
+            raw_next_device_proc_addr(device.handle(), b"vkGetDeviceQueue\0").unwrap();
+        let mut handle = vk::Queue::null();
+        queue(device.handle(), 0, 0, &mut handle);
+        assert!(dispatch_of(handle).is_some());
+
+        EVENTS.lock().unwrap().clear();
+        drop(device);
+        assert_eq!(*EVENTS.lock().unwrap(), ["raw destroy", "layer destroy"]);
+        assert_eq!(loader.driver().call_count("vkDestroyDevice"), 1);
+    }
+}
+
+static PRIVATE_CREATED: AtomicUsize = AtomicUsize::new(0);
+
+struct PrivateDeviceLayer;
+
+impl Layer for PrivateDeviceLayer {
+    unsafe fn create_device(
+        _create_info: &vk::DeviceCreateInfo,
+        _instance: &InstanceDispatch,
+        _device: &DeviceDispatch,
+    ) -> vk::Result {
+        PRIVATE_CREATED.fetch_add(1, Ordering::SeqCst);
+        vk::Result::SUCCESS
+    }
+}
+
+#[test]
+fn layer_device_bypasses_own_hooks() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<PrivateDeviceLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let dispatch = InstanceDispatch::from_handle(instance.handle()).unwrap();
+        assert!(dispatch.layer_device_callbacks().is_some());
+        let physical_device = dispatch.table().enumerate_physical_devices().unwrap()[0];
+        let queue_infos = [vk::DeviceQueueCreateInfo::builder()
+            .queue_family_index(0)
+            .queue_priorities(&[1.0])
+            .build()];
+        let create_info = vk::DeviceCreateInfo::builder().queue_create_infos(&queue_infos);
+
+        let device = dispatch
+            .create_layer_device(physical_device, &create_info, None)
+            .unwrap();
+        assert_eq!(PRIVATE_CREATED.load(Ordering::SeqCst), 0);
+        device.get_device_queue(0, 0);
+        device.device_wait_idle().unwrap();
+        assert!(DeviceDispatch::from_handle(device.handle()).is_none());
+        dispatch.destroy_layer_device(&device, None);
+        let driver = loader.driver();
+        assert_eq!(driver.call_count("vkCreateDevice"), 1);
+        assert_eq!(driver.call_count("vkDestroyDevice"), 1);
+
+        let _device = instance
+            .create_device(physical_device, &create_info)
+            .unwrap();
+        assert_eq!(PRIVATE_CREATED.load(Ordering::SeqCst), 1);
+    }
+}
+
+struct PlainLayer;
+
+impl Layer for PlainLayer {}
+
+#[test]
+fn init_dispatchable_copies_loader_data() {
+    use ash::vk::Handle;
+
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<PlainLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let instance_dispatch = InstanceDispatch::from_handle(instance.handle()).unwrap();
+        assert!(instance_dispatch.set_instance_loader_data().is_some());
+        let physical_device = instance_dispatch
+            .table()
+            .enumerate_physical_devices()
+            .unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+        let device_dispatch = DeviceDispatch::from_handle(device.handle()).unwrap();
+        assert!(device_dispatch.set_device_loader_data().is_some());
+
+        // objects created by a layer start with the loader magic instead of dispatch data
+        let mut queue_object = Box::new(ICD_LOADER_MAGIC);
+        let queue = vk::Queue::from_raw(&mut *queue_object as *mut usize as u64);
+        assert!(dispatch_of(queue).is_none());
+        device_dispatch.init_dispatchable(queue).unwrap();
+        assert_eq!(dispatch_of(queue).unwrap().handle(), device.handle());
+
+        let mut physical_device_object = Box::new(ICD_LOADER_MAGIC);
+        let physical_device =
+            vk::PhysicalDevice::from_raw(&mut *physical_device_object as *mut usize as u64);
+        instance_dispatch
+            .init_dispatchable(physical_device)
+            .unwrap();
+        assert_eq!(
+            dispatch_of(physical_device).unwrap().handle(),
+            instance.handle()
+        );
+
+        assert!(device_dispatch
+            .init_dispatchable(vk::CommandBuffer::null())
+            .is_err());
+    }
+}
+
+struct LoadingLayer;
+
+impl Layer for LoadingLayer {
+    unsafe fn create_device(
+        _create_info: &vk::DeviceCreateInfo,
+        instance: &InstanceDispatch,
+        device: &DeviceDispatch,
+    ) -> vk::Result {
+        // what ash extension loaders do
+        let name = CStr::from_bytes_with_nul_unchecked(b"vkDeviceWaitIdle\0");
+        if instance
+            .table()
+            .get_device_proc_addr(device.handle(), name.as_ptr())
+            .is_none()
+        {
+            return vk::Result::ERROR_INITIALIZATION_FAILED;
+        }
+        let loaded = ash::Device::load(instance.table().fp_v1_0(), device.handle());
+        match loaded.device_wait_idle() {
+            Ok(()) => vk::Result::SUCCESS,
+            Err(err) => err,
+        }
+    }
+}
+
+#[test]
+fn create_device_hook_loads_device_commands() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<LoadingLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = instance.table().enumerate_physical_devices().unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+        assert_eq!(loader.driver().call_count("vkDeviceWaitIdle"), 1);
+        assert!(DeviceDispatch::from_handle(device.handle()).is_some());
+    }
+}
+
+static FAILED_DEVICE_KEY: Mutex<Option<DispatchKey>> = Mutex::new(None);
+
+struct FailingDeviceLayer;
+
+impl Layer for FailingDeviceLayer {
+    unsafe fn create_device(
+        _create_info: &vk::DeviceCreateInfo,
+        _instance: &InstanceDispatch,
+        device: &DeviceDispatch,
+    ) -> vk::Result {
+        *FAILED_DEVICE_KEY.lock().unwrap() = Some(device.key());
+        vk::Result::ERROR_FEATURE_NOT_PRESENT
+    }
+}
+
+#[test]
+fn failed_create_device_unregisters_dispatch() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<FailingDeviceLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = instance.table().enumerate_physical_devices().unwrap()[0];
+        assert!(
+            instance
+                .create_device(physical_device, &Default::default())
+                .err()
+                == Some(vk::Result::ERROR_FEATURE_NOT_PRESENT)
+        );
+        let key = FAILED_DEVICE_KEY.lock().unwrap().unwrap();
+        assert!(DeviceDispatch::from_key(key).is_none());
+        assert_eq!(loader.driver().call_count("vkDestroyDevice"), 1);
+    }
+}
+
+static LINKED_NEXT_GIPA: Mutex<Option<vk::PFN_vkGetInstanceProcAddr>> = Mutex::new(None);
+static LINKED_CREATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "system" fn linked_get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    if CStr::from_ptr(p_name).to_bytes() == b"vkCreateDevice" {
+        LINKED_CREATED.fetch_add(1, Ordering::SeqCst);
+    }
+    (LINKED_NEXT_GIPA.lock().unwrap().unwrap())(instance, p_name)
+}
+
+#[test]
+fn create_device_calls_down_the_device_link() {
+    unsafe {
+        let bare_loader = MockLoader::new();
+        let bare_instance = bare_loader.create_instance(&Default::default()).unwrap();
+        let resolve = |name: &[u8]| {
+            let name = CStr::from_bytes_with_nul(name).unwrap();
+            bare_instance.get_instance_proc_addr(name).unwrap()
+        };
+        let driver_gipa: vk::PFN_vkGetInstanceProcAddr =
+            mem::transmute(resolve(b"vkGetInstanceProcAddr\0"));
+        let driver_gdpa: vk::PFN_vkGetDeviceProcAddr =
+            mem::transmute(resolve(b"vkGetDeviceProcAddr\0"));
+        *LINKED_NEXT_GIPA.lock().unwrap() = Some(driver_gipa);
+
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<PlainLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = instance.table().enumerate_physical_devices().unwrap()[0];
+
+        // a chain of its own, as the loader builds for vkLayerCreateDevice
+        let mut link = LayerDeviceLink {
+            p_next: ptr::null_mut(),
+            pfn_next_get_instance_proc_addr: Some(linked_get_instance_proc_addr),
+            pfn_next_get_device_proc_addr: Some(driver_gdpa),
+        };
+        let link_info = LayerDeviceCreateInfo::new(DeviceChainKind::LinkInfo(&mut link));
+        let create_info = vk::DeviceCreateInfo {
+            p_next: &link_info as *const _ as *const c_void,
+            ..Default::default()
+        };
+        let name = b"vkCreateDevice\0".as_ptr().cast();
+        let create_device: vk::PFN_vkCreateDevice =
+            mem::transmute((layer.get_instance_proc_addr)(instance.handle(), name).unwrap());
+        let mut device = vk::Device::null();
+        let res = create_device(physical_device, &create_info, ptr::null(), &mut device);
+        assert_eq!(res, vk::Result::SUCCESS);
+        assert_eq!(LINKED_CREATED.load(Ordering::SeqCst), 1);
+
+        let name = b"vkDestroyDevice\0".as_ptr().cast();
+        let get_device_proc_addr = layer.get_device_proc_addr.unwrap();
+        let destroy_device: vk::PFN_vkDestroyDevice =
+            mem::transmute(get_device_proc_addr(device, name).unwrap());
+        destroy_device(device, ptr::null());
+        assert_eq!(loader.driver().call_count("vkDestroyDevice"), 1);
+    }
+}
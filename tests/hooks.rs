@@ -0,0 +1,289 @@
+#![cfg(feature = "mock")]
+
+use ash::vk;
+use ash_layer::mock::*;
+use ash_layer::*;
+
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+fn c_name(name: &str) -> CString {
+    CString::new(name).unwrap()
+}
+
+unsafe extern "system" fn enumerate_physical_devices(
+    _instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    _p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    *p_physical_device_count = 42;
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn device_wait_idle(_device: vk::Device) -> vk::Result {
+    vk::Result::TIMEOUT
+}
+
+unsafe extern "system" fn foo(_physical_device: vk::PhysicalDevice) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn bar() {}
+
+struct TableLayer;
+
+impl Layer for TableLayer {
+    const HOOKS: HookTable = hook_table![
+        Instance "vkEnumeratePhysicalDevices" => enumerate_physical_devices as vk::PFN_vkEnumeratePhysicalDevices,
+        Device "vkDeviceWaitIdle" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        PhysicalDevice "vkFooEXT" => foo as PFN_PhysDevExt,
+        Device "vkA" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        Device "vkB" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        Device "vkC" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        Device "vkD" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        Device "vkE" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        Device "vkF" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        Device "vkG" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        Device "vkH" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        Device "vkI" => device_wait_idle as vk::PFN_vkDeviceWaitIdle,
+        Device "vkBarEXT" => bar as unsafe extern "system" fn(),
+    ];
+
+    fn implements_command(name: &CStr) -> bool {
+        name.to_bytes() == b"vkFooEXT"
+    }
+}
+
+#[test]
+fn hook_table_finds_every_hook() {
+    for hook in TableLayer::HOOKS.hooks() {
+        let found = TableLayer::HOOKS.get(&c_name(hook.name)).unwrap();
+        assert!(ptr::eq(found, hook));
+    }
+    assert!(TableLayer::HOOKS.get(&c_name("vkJ")).is_none());
+    assert!(TableLayer::HOOKS.get(&c_name("vkCreateInstance")).is_none());
+    assert!(HookTable::EMPTY.get(&c_name("vkA")).is_none());
+}
+
+#[test]
+fn proc_addr_entry_points_use_hook_levels() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<TableLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let mut count = 0;
+        let enumerate_physical_devices = instance.table().fp_v1_0().enumerate_physical_devices;
+        let res = enumerate_physical_devices(instance.handle(), &mut count, ptr::null_mut());
+        assert_eq!(res, vk::Result::SUCCESS);
+        assert_eq!(count, 42);
+
+        let gipa = layer.get_instance_proc_addr;
+        let null = vk::Instance::null();
+        assert!(gipa(null, c_name("vkCreateInstance").as_ptr()).is_some());
+        assert!(gipa(null, c_name("vkCreateDevice").as_ptr()).is_some());
+        assert!(gipa(null, c_name("vkEnumeratePhysicalDevices").as_ptr()).is_none());
+        assert!(gipa(null, c_name("vkDestroyInstance").as_ptr()).is_none());
+        assert!(gipa(null, c_name("vkDeviceWaitIdle").as_ptr()).is_none());
+        let instance_gipa = |name| gipa(instance.handle(), c_name(name).as_ptr());
+        assert!(instance_gipa("vkEnumeratePhysicalDevices").is_some());
+        assert!(instance_gipa("vkDeviceWaitIdle").is_some());
+
+        let gphypa = layer.get_physical_device_proc_addr.unwrap();
+        assert!(gphypa(instance.handle(), c_name("vkFooEXT").as_ptr()).is_some());
+        assert!(gphypa(instance.handle(), c_name("vkCreateDevice").as_ptr()).is_some());
+        assert!(gphypa(instance.handle(), c_name("vkDeviceWaitIdle").as_ptr()).is_none());
+
+        let physical_device = InstanceDispatch::from_handle(instance.handle())
+            .unwrap()
+            .table()
+            .enumerate_physical_devices()
+            .unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+        assert_eq!(device.table().device_wait_idle(), Err(vk::Result::TIMEOUT));
+        assert!(device
+            .get_device_proc_addr(&c_name("vkEnumeratePhysicalDevices"))
+            .is_none());
+        assert!(device
+            .get_device_proc_addr(&c_name("vkDestroyDevice"))
+            .is_some());
+        assert!(device
+            .get_device_proc_addr(&c_name("vkCreateDevice"))
+            .is_none());
+    }
+}
+
+#[test]
+fn proc_addr_entry_points_reject_null_name() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<TableLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = InstanceDispatch::from_handle(instance.handle())
+            .unwrap()
+            .table()
+            .enumerate_physical_devices()
+            .unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+
+        let gipa = layer.get_instance_proc_addr;
+        assert!(gipa(vk::Instance::null(), ptr::null()).is_none());
+        assert!(gipa(instance.handle(), ptr::null()).is_none());
+        let gphypa = layer.get_physical_device_proc_addr.unwrap();
+        assert!(gphypa(instance.handle(), ptr::null()).is_none());
+        let gdpa = layer.get_device_proc_addr.unwrap();
+        assert!(gdpa(device.handle(), ptr::null()).is_none());
+    }
+}
+
+// never called, only resolved
+unsafe extern "system" fn any_command(_device: vk::Device) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn acquire_winrt_display(
+    _physical_device: vk::PhysicalDevice,
+    _display: vk::DisplayKHR,
+) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+struct ExtensionLayer;
+
+impl Layer for ExtensionLayer {
+    const HOOKS: HookTable = hook_table![
+        Device "vkCmdDrawIndirectCountKHR" => any_command as vk::PFN_vkDeviceWaitIdle,
+        Device "vkCmdDrawIndirectCount" => any_command as vk::PFN_vkDeviceWaitIdle,
+        Device "vkSetDebugUtilsObjectNameEXT" => any_command as vk::PFN_vkDeviceWaitIdle,
+        Instance "vkCreateDebugUtilsMessengerEXT" => any_command as vk::PFN_vkDeviceWaitIdle,
+        Device "vkCreateSwapchainKHR" => any_command as vk::PFN_vkDeviceWaitIdle,
+        Device "vkUnknownCommandXYZ" => any_command as vk::PFN_vkDeviceWaitIdle,
+        PhysicalDevice "vkAcquireWinrtDisplayNV" => acquire_winrt_display as vk::PFN_vkAcquireWinrtDisplayNV,
+    ];
+}
+
+#[test]
+fn disabled_commands_resolve_null() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<ExtensionLayer>)
+            .unwrap();
+        let get_instance_proc_addr = layer.get_instance_proc_addr;
+        let instance_extensions = [c_name("VK_EXT_debug_utils")];
+        let device_extensions = [c_name("VK_KHR_draw_indirect_count")];
+        for enabled in [false, true] {
+            let api_version = if enabled {
+                vk::API_VERSION_1_2
+            } else {
+                vk::API_VERSION_1_0
+            };
+            let application_info = vk::ApplicationInfo::builder().api_version(api_version);
+            let extension_names: Vec<_> = instance_extensions
+                .iter()
+                .filter(|_| enabled)
+                .map(|v| v.as_ptr())
+                .collect();
+            let create_info = vk::InstanceCreateInfo::builder()
+                .application_info(&application_info)
+                .enabled_extension_names(&extension_names);
+            let instance = loader.create_instance(&create_info).unwrap();
+            let handle = instance.handle();
+            let resolves =
+                |name: &str| get_instance_proc_addr(handle, c_name(name).as_ptr()).is_some();
+            assert_eq!(resolves("vkCreateDebugUtilsMessengerEXT"), enabled);
+            // device commands are not filtered by GIPA, the device may enable more
+            assert!(resolves("vkCreateSwapchainKHR"));
+            assert!(resolves("vkCmdDrawIndirectCount"));
+            // of a device extension, which the instance can not enable
+            assert!(resolves("vkAcquireWinrtDisplayNV"));
+
+            let dispatch = InstanceDispatch::from_handle(handle).unwrap();
+            assert_eq!(dispatch.api_version(), api_version);
+            let physical_device = dispatch.table().enumerate_physical_devices().unwrap()[0];
+            let extension_names: Vec<_> = device_extensions
+                .iter()
+                .filter(|_| enabled)
+                .map(|v| v.as_ptr())
+                .collect();
+            let create_info =
+                vk::DeviceCreateInfo::builder().enabled_extension_names(&extension_names);
+            let device = instance
+                .create_device(physical_device, &create_info)
+                .unwrap();
+            for (name, expected) in [
+                ("vkCmdDrawIndirectCountKHR", enabled),
+                ("vkCmdDrawIndirectCount", enabled),
+                ("vkSetDebugUtilsObjectNameEXT", enabled),
+                ("vkCreateSwapchainKHR", false),
+                ("vkUnknownCommandXYZ", true),
+                ("vkDestroyDevice", true),
+                ("vkQueueSubmit", true),
+                ("vkQueueSubmit2", false),
+                ("vkCreateDebugUtilsMessengerEXT", false),
+            ] {
+                let found = device.get_device_proc_addr(&c_name(name)).is_some();
+                assert_eq!(found, expected, "{} with {}", name, enabled);
+            }
+            let dispatch = DeviceDispatch::from_handle(device.handle()).unwrap();
+            assert_eq!(dispatch.api_version(), api_version);
+        }
+    }
+}
+
+#[test]
+fn registry_knows_command_providers() {
+    let command = registry::command(&c_name("vkCmdDrawIndirectCount")).unwrap();
+    assert!(command.providers == [registry::Provider::Core(vk::API_VERSION_1_2)]);
+    let command = registry::command(&c_name("vkCmdDrawIndirectCountKHR")).unwrap();
+    assert!(command.providers == [registry::Provider::Extension("VK_KHR_draw_indirect_count")]);
+    assert!(registry::command(&c_name("vkUnknownCommandXYZ")).is_none());
+
+    // including those without commands
+    let instance_extensions = [
+        "VK_KHR_surface",
+        "VK_KHR_portability_enumeration",
+        "VK_EXT_validation_features",
+    ];
+    assert!(instance_extensions
+        .into_iter()
+        .all(registry::is_instance_extension));
+    // device extensions of physical device commands only
+    let device_extensions = ["VK_NV_acquire_winrt_display", "VK_EXT_tooling_info"];
+    assert!(!device_extensions
+        .into_iter()
+        .any(registry::is_instance_extension));
+}
+
+#[test]
+fn registry_knows_command_result_codes() {
+    let command = registry::command(&c_name("vkAcquireNextImageKHR")).unwrap();
+    for code in [
+        vk::Result::SUCCESS,
+        vk::Result::TIMEOUT,
+        vk::Result::NOT_READY,
+        vk::Result::SUBOPTIMAL_KHR,
+    ] {
+        assert!(command.success_codes.contains(&code));
+    }
+    assert!(command
+        .error_codes
+        .contains(&vk::Result::ERROR_OUT_OF_DATE_KHR));
+    assert!(!command
+        .success_codes
+        .contains(&vk::Result::ERROR_OUT_OF_DATE_KHR));
+
+    let command = registry::command(&c_name("vkDeviceWaitIdle")).unwrap();
+    assert!(command.success_codes == [vk::Result::SUCCESS]);
+    assert!(command.error_codes.contains(&vk::Result::ERROR_DEVICE_LOST));
+    // void commands
+    let command = registry::command(&c_name("vkDestroyDevice")).unwrap();
+    assert!(command.success_codes.is_empty() && command.error_codes.is_empty());
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "mock")]
+
+use ash::vk;
+use ash_layer::mock::*;
+use ash_layer::*;
+
+use std::sync::Mutex;
+
+struct PlainLayer;
+
+impl Layer for PlainLayer {}
+
+#[test]
+fn loader_features_come_from_chain() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<PlainLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let dispatch = InstanceDispatch::from_handle(instance.handle()).unwrap();
+        assert!(dispatch.loader_features().is_empty());
+        drop(instance);
+
+        loader.set_loader_features(LoaderFeatureFlagBits::PHYSICAL_DEVICE_SORTING);
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let dispatch = InstanceDispatch::from_handle(instance.handle()).unwrap();
+        assert!(dispatch.loader_features() == LoaderFeatureFlagBits::PHYSICAL_DEVICE_SORTING);
+    }
+}
+
+struct LookupLayer;
+
+impl Layer for LookupLayer {
+    unsafe fn create_instance(
+        _create_info: &vk::InstanceCreateInfo,
+        instance: &InstanceDispatch,
+    ) -> vk::Result {
+        let physical_devices = match instance.table().enumerate_physical_devices() {
+            Ok(v) => v,
+            Err(err) => return err,
+        };
+        match dispatch_of(physical_devices[0]) {
+            Some(v) if v.handle() == instance.handle() => vk::Result::SUCCESS,
+            _ => vk::Result::ERROR_INITIALIZATION_FAILED,
+        }
+    }
+}
+
+#[test]
+fn create_instance_hook_looks_up_dispatch() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<LookupLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        assert!(InstanceDispatch::from_handle(instance.handle()).is_some());
+    }
+}
+
+static FAILED_INSTANCE_KEY: Mutex<Option<DispatchKey>> = Mutex::new(None);
+
+struct FailingInstanceLayer;
+
+impl Layer for FailingInstanceLayer {
+    unsafe fn create_instance(
+        _create_info: &vk::InstanceCreateInfo,
+        instance: &InstanceDispatch,
+    ) -> vk::Result {
+        *FAILED_INSTANCE_KEY.lock().unwrap() = Some(instance.key());
+        vk::Result::ERROR_EXTENSION_NOT_PRESENT
+    }
+}
+
+#[test]
+fn failed_create_instance_unregisters_dispatch() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<FailingInstanceLayer>)
+            .unwrap();
+        assert!(
+            loader.create_instance(&Default::default()).err()
+                == Some(vk::Result::ERROR_EXTENSION_NOT_PRESENT)
+        );
+        let key = FAILED_INSTANCE_KEY.lock().unwrap().unwrap();
+        assert!(InstanceDispatch::from_key(key).is_none());
+    }
+}
@@ -0,0 +1,363 @@
+#![cfg(feature = "mock")]
+
+use ash::vk;
+use ash_layer::mock::*;
+use ash_layer::registry::commands::*;
+use ash_layer::*;
+
+use std::ffi::CStr;
+use std::mem;
+use std::ops::ControlFlow;
+use std::ptr;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+static LOG: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+const CONTINUE: usize = 0;
+const BREAK: usize = 1;
+const PANIC: usize = 2;
+static INNER_ACTION: AtomicUsize = AtomicUsize::new(CONTINUE);
+
+unsafe fn outer_pre(
+    _device: &DeviceDispatch,
+    _args: &mut Args<vkDeviceWaitIdle>,
+) -> ControlFlow<vk::Result> {
+    LOG.lock().unwrap().push("outer pre");
+    ControlFlow::Continue(())
+}
+
+unsafe fn outer_post(
+    _device: &DeviceDispatch,
+    _args: &Args<vkDeviceWaitIdle>,
+    res: &mut vk::Result,
+) {
+    LOG.lock().unwrap().push("outer post");
+    if *res == vk::Result::TIMEOUT {
+        *res = vk::Result::NOT_READY;
+    }
+}
+
+unsafe fn inner_pre(
+    _device: &DeviceDispatch,
+    _args: &mut Args<vkDeviceWaitIdle>,
+) -> ControlFlow<vk::Result> {
+    LOG.lock().unwrap().push("inner pre");
+    match INNER_ACTION.load(Ordering::SeqCst) {
+        BREAK => ControlFlow::Break(vk::Result::TIMEOUT),
+        PANIC => panic!("inner pre"),
+        _ => ControlFlow::Continue(()),
+    }
+}
+
+unsafe fn inner_post(
+    _device: &DeviceDispatch,
+    _args: &Args<vkDeviceWaitIdle>,
+    _res: &mut vk::Result,
+) {
+    LOG.lock().unwrap().push("inner post");
+}
+
+static ENUMERATED: AtomicU32 = AtomicU32::new(0);
+
+unsafe fn enumerate_pre(
+    instance: &InstanceDispatch,
+    args: &mut Args<vkEnumeratePhysicalDevices>,
+) -> ControlFlow<vk::Result> {
+    assert_eq!(instance.handle(), args.0);
+    ControlFlow::Continue(())
+}
+
+unsafe fn enumerate_post(
+    _instance: &InstanceDispatch,
+    args: &Args<vkEnumeratePhysicalDevices>,
+    res: &mut vk::Result,
+) {
+    assert_eq!(*res, vk::Result::SUCCESS);
+    ENUMERATED.store(*args.1, Ordering::SeqCst);
+}
+
+const OUTER: Module<InterceptingLayer> = Module::new(
+    "outer",
+    &[
+        Interceptor::new::<vkDeviceWaitIdle>(Some(outer_pre), Some(outer_post)),
+        Interceptor::new::<vkEnumeratePhysicalDevices>(Some(enumerate_pre), Some(enumerate_post)),
+        // not implemented by the mock driver
+        Interceptor::new::<vkResetFences>(None, None),
+    ],
+);
+
+const INNER: Module<InterceptingLayer> = Module::new(
+    "inner",
+    &[Interceptor::new::<vkDeviceWaitIdle>(
+        Some(inner_pre),
+        Some(inner_post),
+    )],
+);
+
+struct InterceptingLayer;
+
+impl Layer for InterceptingLayer {
+    const MODULES: &'static [Module<Self>] = &[OUTER, INNER];
+
+    fn panic_policy() -> PanicPolicy {
+        PanicPolicy::ReturnError
+    }
+}
+
+#[test]
+fn instance_interceptors_wrap_next_command() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<InterceptingLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let name = b"vkEnumeratePhysicalDevices\0".as_ptr().cast();
+        let enumerate_physical_devices: vk::PFN_vkEnumeratePhysicalDevices =
+            mem::transmute((layer.get_instance_proc_addr)(instance.handle(), name).unwrap());
+        let mut count = 0;
+        assert_eq!(
+            enumerate_physical_devices(instance.handle(), &mut count, ptr::null_mut()),
+            vk::Result::SUCCESS
+        );
+        assert_eq!(count, 1);
+        assert_eq!(ENUMERATED.load(Ordering::SeqCst), 1);
+    }
+}
+
+#[test]
+fn device_interceptors_run_in_module_order() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<InterceptingLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = InstanceDispatch::from_handle(instance.handle())
+            .unwrap()
+            .table()
+            .enumerate_physical_devices()
+            .unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+        let name = CStr::from_bytes_with_nul_unchecked(b"vkDeviceWaitIdle\0");
+        let device_wait_idle: vk::PFN_vkDeviceWaitIdle =
+            mem::transmute(device.get_device_proc_addr(name).unwrap());
+
+        assert_eq!(device_wait_idle(device.handle()), vk::Result::SUCCESS);
+        assert_eq!(
+            mem::take(&mut *LOG.lock().unwrap()),
+            ["outer pre", "inner pre", "inner post", "outer post"]
+        );
+
+        // the post calls of modules before the one breaking still run
+        INNER_ACTION.store(BREAK, Ordering::SeqCst);
+        assert_eq!(device_wait_idle(device.handle()), vk::Result::NOT_READY);
+        assert_eq!(
+            mem::take(&mut *LOG.lock().unwrap()),
+            ["outer pre", "inner pre", "outer post"]
+        );
+
+        INNER_ACTION.store(PANIC, Ordering::SeqCst);
+        assert_eq!(device_wait_idle(device.handle()), vk::Result::ERROR_UNKNOWN);
+        assert_eq!(loader.driver().call_count("vkDeviceWaitIdle"), 1);
+    }
+}
+
+#[test]
+fn interceptors_without_next_command_fail() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<InterceptingLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = InstanceDispatch::from_handle(instance.handle())
+            .unwrap()
+            .table()
+            .enumerate_physical_devices()
+            .unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+        let name = CStr::from_bytes_with_nul_unchecked(b"vkResetFences\0");
+        assert!(device.get_device_proc_addr(name).is_none());
+
+        // resolved for any device of the instance
+        let reset_fences: vk::PFN_vkResetFences = mem::transmute(
+            (layer.get_instance_proc_addr)(instance.handle(), name.as_ptr()).unwrap(),
+        );
+        let res = reset_fences(device.handle(), 0, ptr::null());
+        assert_eq!(res, vk::Result::ERROR_UNKNOWN);
+    }
+}
+
+#[test]
+fn interceptors_on_unknown_handle_fail() {
+    use ash::vk::Handle;
+
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<InterceptingLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = InstanceDispatch::from_handle(instance.handle())
+            .unwrap()
+            .table()
+            .enumerate_physical_devices()
+            .unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+        let name = CStr::from_bytes_with_nul_unchecked(b"vkDeviceWaitIdle\0");
+        let device_wait_idle: vk::PFN_vkDeviceWaitIdle =
+            mem::transmute(device.get_device_proc_addr(name).unwrap());
+
+        // follows the panic policy of the layer
+        let mut device_object = Box::new(ICD_LOADER_MAGIC);
+        let unknown = vk::Device::from_raw(&mut *device_object as *mut usize as u64);
+        assert_eq!(device_wait_idle(unknown), vk::Result::ERROR_UNKNOWN);
+        assert_eq!(loader.driver().call_count("vkDeviceWaitIdle"), 0);
+    }
+}
+
+static PASS_THROUGH_LOG: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+unsafe fn logging_post(
+    _device: &DeviceDispatch,
+    _args: &Args<vkDeviceWaitIdle>,
+    _res: &mut vk::Result,
+) {
+    PASS_THROUGH_LOG.lock().unwrap().push("logging post");
+}
+
+unsafe fn panicking_pre(
+    _device: &DeviceDispatch,
+    _args: &mut Args<vkDeviceWaitIdle>,
+) -> ControlFlow<vk::Result> {
+    panic!("panicking pre")
+}
+
+unsafe fn panicking_post(
+    _device: &DeviceDispatch,
+    _args: &Args<vkDeviceWaitIdle>,
+    _res: &mut vk::Result,
+) {
+    PASS_THROUGH_LOG.lock().unwrap().push("panicking post");
+}
+
+struct PassThroughLayer;
+
+impl Layer for PassThroughLayer {
+    const MODULES: &'static [Module<Self>] = &[
+        Module::new(
+            "logging",
+            &[Interceptor::new::<vkDeviceWaitIdle>(
+                None,
+                Some(logging_post),
+            )],
+        ),
+        Module::new(
+            "panicking",
+            &[Interceptor::new::<vkDeviceWaitIdle>(
+                Some(panicking_pre),
+                Some(panicking_post),
+            )],
+        ),
+    ];
+
+    fn panic_policy() -> PanicPolicy {
+        PanicPolicy::PassThrough
+    }
+}
+
+#[test]
+fn panicking_pre_call_skips_its_post_call() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<PassThroughLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = InstanceDispatch::from_handle(instance.handle())
+            .unwrap()
+            .table()
+            .enumerate_physical_devices()
+            .unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+        assert_eq!(device.table().device_wait_idle(), Ok(()));
+        assert_eq!(loader.driver().call_count("vkDeviceWaitIdle"), 1);
+        assert_eq!(*PASS_THROUGH_LOG.lock().unwrap(), ["logging post"]);
+    }
+}
+
+static PASSED_DOWN: Mutex<Vec<(u32, u32)>> = Mutex::new(Vec::new());
+
+unsafe fn observing_post(
+    _device: &DeviceDispatch,
+    &(_, queue_family_index, queue_index, _): &Args<vkGetDeviceQueue>,
+    _res: &mut (),
+) {
+    let passed_down = (queue_family_index, queue_index);
+    PASSED_DOWN.lock().unwrap().push(passed_down);
+}
+
+unsafe fn tampering_pre(
+    _device: &DeviceDispatch,
+    args: &mut Args<vkGetDeviceQueue>,
+) -> ControlFlow<()> {
+    args.2 = 7;
+    panic!("tampering pre")
+}
+
+struct TamperingLayer;
+
+impl Layer for TamperingLayer {
+    const MODULES: &'static [Module<Self>] = &[
+        Module::new(
+            "observing",
+            &[Interceptor::new::<vkGetDeviceQueue>(
+                None,
+                Some(observing_post),
+            )],
+        ),
+        Module::new(
+            "tampering",
+            &[Interceptor::new::<vkGetDeviceQueue>(
+                Some(tampering_pre),
+                None,
+            )],
+        ),
+    ];
+
+    fn panic_policy() -> PanicPolicy {
+        PanicPolicy::PassThrough
+    }
+}
+
+#[test]
+fn panicking_pre_call_drops_its_changes() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<TamperingLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let physical_device = InstanceDispatch::from_handle(instance.handle())
+            .unwrap()
+            .table()
+            .enumerate_physical_devices()
+            .unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &Default::default())
+            .unwrap();
+        let queue = device.table().get_device_queue(0, 0);
+        assert!(dispatch_of(queue).is_some());
+        assert_eq!(*PASSED_DOWN.lock().unwrap(), [(0, 0)]);
+    }
+}
@@ -0,0 +1,104 @@
+#![cfg(all(feature = "manifest", feature = "debug"))]
+
+use ash_layer::manifest::*;
+
+const DUMMY_MANIFEST: &str = r#"{
+  "file_format_version": "1.2.0",
+  "layer": {
+    "name": "VK_LAYER_ASH_LAYER_dummy",
+    "type": "GLOBAL",
+    "library_path": "./liblayer_dummy.so",
+    "api_version": "1.0.0",
+    "implementation_version": "1",
+    "description": "Ash Layer Example - dummy",
+    "disable_environment": {
+      "DISABLE_ASH_LAYER_DUMMY": ""
+    },
+    "pre_instance_functions": {
+      "vkEnumerateInstanceExtensionProperties": "ash_layer_vkEnumerateInstanceExtensionProperties",
+      "vkEnumerateInstanceLayerProperties": "ash_layer_vkEnumerateInstanceLayerProperties",
+      "vkEnumerateInstanceVersion": "ash_layer_vkEnumerateInstanceVersion"
+    }
+  }
+}"#;
+
+const EXPORTS: [&str; 4] = [
+    "vkNegotiateLoaderLayerInterfaceVersion",
+    "ash_layer_vkEnumerateInstanceExtensionProperties",
+    "ash_layer_vkEnumerateInstanceLayerProperties",
+    "ash_layer_vkEnumerateInstanceVersion",
+];
+
+#[test]
+fn parse_and_validate_exports() {
+    let manifest = ManifestFile::from_json(DUMMY_MANIFEST).unwrap();
+    manifest.validate().unwrap();
+    let layer = manifest.iter_layers().next().unwrap();
+    assert_eq!(layer.pre_instance_functions.len(), 3);
+    layer
+        .validate_exports(|name| EXPORTS.contains(&name))
+        .unwrap();
+    assert!(matches!(
+        layer.validate_exports(|name| name == EXPORTS[0]),
+        Err(ManifestError::MissingExport { .. })
+    ));
+
+    assert!(ManifestFile::from_json(r#"{"file_format_version":"1.x","layer":{}}"#).is_err());
+}
+
+#[test]
+fn generated_manifest_round_trips() {
+    let manifest = ManifestFile::new(LayerManifest::new("VK_LAYER_x", "libx.so"));
+    manifest.validate().unwrap();
+    let json = manifest.to_json().unwrap();
+    assert_eq!(ManifestFile::from_json(&json).unwrap(), manifest);
+    manifest
+        .iter_layers()
+        .next()
+        .unwrap()
+        .validate_exports(|name| EXPORTS.contains(&name))
+        .unwrap();
+
+    let mut old = manifest;
+    old.file_format_version = FileFormatVersion::V1_0_0;
+    assert!(matches!(
+        old.validate(),
+        Err(ManifestError::FileFormatVersionTooOld { .. })
+    ));
+}
+
+#[test]
+fn builder_writes_named_manifest() {
+    let dir = std::env::temp_dir().join("ash_layer_manifest_builder");
+    std::fs::create_dir_all(&dir).unwrap();
+    let builder = ManifestBuilder::new("VK_LAYER_builder_test", 3).library_name("builder_test");
+    let library_file = builder.library_file();
+    assert!(library_file.contains("builder_test"));
+    let path = builder.write_to(&dir).unwrap();
+    assert_eq!(path, dir.join("VK_LAYER_builder_test.json"));
+
+    let manifest = ManifestFile::from_json(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let layer = manifest.iter_layers().next().unwrap();
+    let expected = std::path::Path::new(".").join(library_file);
+    assert_eq!(layer.library_path.as_deref(), Some(expected.as_path()));
+    assert_eq!(layer.implementation_version, "3");
+
+    // as set for build scripts, read by the same test only
+    let artifact_dir = dir.join("target").join("debug");
+    let out_dir = artifact_dir
+        .join("build")
+        .join("builder_test-0123")
+        .join("out");
+    std::env::set_var("OUT_DIR", &out_dir);
+    std::fs::create_dir_all(&out_dir).unwrap();
+    assert_eq!(ManifestBuilder::artifact_dir(), Some(artifact_dir.clone()));
+    let builder = || ManifestBuilder::new("VK_LAYER_builder_test", 3).library_name("builder_test");
+    let path = builder().write().unwrap();
+    assert_eq!(path, artifact_dir.join("VK_LAYER_builder_test.json"));
+    // elsewhere the library is still the one in the artifact directory
+    let path = builder().write_to(&dir).unwrap();
+    let manifest = ManifestFile::from_json(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    let layer = manifest.iter_layers().next().unwrap();
+    let expected = artifact_dir.join(builder().library_file());
+    assert_eq!(layer.library_path.as_deref(), Some(expected.as_path()));
+}
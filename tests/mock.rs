@@ -0,0 +1,228 @@
+#![cfg(feature = "mock")]
+
+use ash::vk;
+use ash_layer::mock::*;
+use ash_layer::*;
+
+use std::ffi::CStr;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+static CREATED: AtomicUsize = AtomicUsize::new(0);
+static DESTROYED: AtomicUsize = AtomicUsize::new(0);
+
+struct CountingLayer;
+
+impl Layer for CountingLayer {
+    unsafe fn create_instance(
+        _create_info: &vk::InstanceCreateInfo,
+        instance: &InstanceDispatch,
+    ) -> vk::Result {
+        CREATED.fetch_add(1, Ordering::SeqCst);
+        let physical_devices = instance.table().enumerate_physical_devices().unwrap();
+        assert_eq!(physical_devices.len(), 1);
+        vk::Result::SUCCESS
+    }
+
+    unsafe fn destroy_instance(_instance: &InstanceDispatch) {
+        DESTROYED.fetch_add(1, Ordering::SeqCst);
+    }
+
+    unsafe fn create_device(
+        _create_info: &vk::DeviceCreateInfo,
+        _instance: &InstanceDispatch,
+        device: &DeviceDispatch,
+    ) -> vk::Result {
+        CREATED.fetch_add(10, Ordering::SeqCst);
+        let queue = device.table().get_device_queue(0, 0);
+        assert!(DeviceDispatch::from_handle(queue).is_some());
+        vk::Result::SUCCESS
+    }
+
+    unsafe fn destroy_device(_device: &DeviceDispatch) {
+        DESTROYED.fetch_add(10, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn create_instance_and_device() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<CountingLayer>)
+            .unwrap();
+        let instance = loader
+            .create_instance(&vk::InstanceCreateInfo::default())
+            .unwrap();
+        assert_eq!(CREATED.load(Ordering::SeqCst), 1);
+        let physical_devices = instance.table().enumerate_physical_devices().unwrap();
+        let properties = instance
+            .table()
+            .get_physical_device_properties(physical_devices[0]);
+        assert_eq!(properties.api_version, vk::API_VERSION_1_3);
+        assert!(InstanceDispatch::from_handle(physical_devices[0]).is_some());
+        {
+            let device = instance
+                .create_device(physical_devices[0], &vk::DeviceCreateInfo::default())
+                .unwrap();
+            assert_eq!(CREATED.load(Ordering::SeqCst), 11);
+            let queue = device.table().get_device_queue(0, 0);
+            let dispatch = dispatch_of(queue).unwrap();
+            assert_eq!(dispatch.handle(), device.handle());
+            assert_eq!(dispatch.physical_device(), physical_devices[0]);
+        }
+        assert_eq!(DESTROYED.load(Ordering::SeqCst), 10);
+        drop(instance);
+        assert_eq!(DESTROYED.load(Ordering::SeqCst), 11);
+    }
+}
+
+#[test]
+fn driver_records_calls_and_injects_results() {
+    unsafe {
+        let driver = Arc::new(MockDriver::default());
+        let loader = MockLoader::with_driver(driver.clone());
+        let instance = loader
+            .create_instance(&vk::InstanceCreateInfo::default())
+            .unwrap();
+        let physical_device = instance.table().enumerate_physical_devices().unwrap()[0];
+        let queue_families = instance
+            .table()
+            .get_physical_device_queue_family_properties(physical_device);
+        assert_eq!(queue_families.len(), 1);
+        let memory_properties = instance
+            .table()
+            .get_physical_device_memory_properties(physical_device);
+        assert_eq!(memory_properties.memory_type_count, 1);
+
+        let device = instance
+            .create_device(physical_device, &vk::DeviceCreateInfo::default())
+            .unwrap();
+        let table = device.table();
+        let pool = table
+            .create_command_pool(&vk::CommandPoolCreateInfo::default(), None)
+            .unwrap();
+        let command_buffers = table
+            .allocate_command_buffers(&vk::CommandBufferAllocateInfo {
+                command_pool: pool,
+                command_buffer_count: 2,
+                ..Default::default()
+            })
+            .unwrap();
+        table
+            .begin_command_buffer(command_buffers[0], &Default::default())
+            .unwrap();
+        table.end_command_buffer(command_buffers[0]).unwrap();
+
+        let memory = table
+            .allocate_memory(
+                &vk::MemoryAllocateInfo {
+                    allocation_size: 64,
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+        let mapped = table
+            .map_memory(memory, 0, 64, vk::MemoryMapFlags::empty())
+            .unwrap();
+        mapped.cast::<u8>().write(3);
+        table.unmap_memory(memory);
+        table.free_memory(memory, None);
+
+        let queue = table.get_device_queue(0, 0);
+        driver.take_calls();
+        driver.inject_result("vkQueueSubmit", vk::Result::ERROR_DEVICE_LOST);
+        assert_eq!(
+            table.queue_submit(queue, &[], vk::Fence::null()),
+            Err(vk::Result::ERROR_DEVICE_LOST)
+        );
+        driver.clear_result("vkQueueSubmit");
+        table.queue_submit(queue, &[], vk::Fence::null()).unwrap();
+        let submit = MockCall {
+            command: "vkQueueSubmit",
+            handle: vk::Handle::as_raw(queue),
+        };
+        assert!(driver.take_calls() == [submit, submit]);
+
+        table.free_command_buffers(pool, &command_buffers[1..]);
+        table.destroy_command_pool(pool, None);
+        drop(device);
+        assert_eq!(driver.call_count("vkDestroyDevice"), 1);
+
+        driver.inject_result("vkCreateInstance", vk::Result::ERROR_OUT_OF_HOST_MEMORY);
+        assert!(
+            loader
+                .create_instance(&vk::InstanceCreateInfo::default())
+                .err()
+                == Some(vk::Result::ERROR_OUT_OF_HOST_MEMORY)
+        );
+    }
+}
+
+#[test]
+fn driver_rejects_layer_queries() {
+    unsafe {
+        let driver = Arc::new(MockDriver::default());
+        let loader = MockLoader::with_driver(driver.clone());
+        let instance = loader
+            .create_instance(&vk::InstanceCreateInfo::default())
+            .unwrap();
+        let physical_device = instance.table().enumerate_physical_devices().unwrap()[0];
+        driver.take_calls();
+
+        let enumerate = instance
+            .table()
+            .fp_v1_0()
+            .enumerate_device_extension_properties;
+        let mut count = 0;
+        let res = enumerate(
+            physical_device,
+            b"VK_LAYER_test\0".as_ptr().cast(),
+            &mut count,
+            ptr::null_mut(),
+        );
+        assert_eq!(res, vk::Result::ERROR_LAYER_NOT_PRESENT);
+        let res = enumerate(physical_device, ptr::null(), &mut count, ptr::null_mut());
+        assert_eq!(res, vk::Result::SUCCESS);
+        assert_eq!(driver.call_count("vkEnumerateDeviceExtensionProperties"), 2);
+    }
+}
+
+#[test]
+fn driver_stubs_unimplemented_commands() {
+    unsafe {
+        let driver = Arc::new(MockDriver::default());
+        let loader = MockLoader::with_driver(driver.clone());
+        let instance = loader
+            .create_instance(&vk::InstanceCreateInfo::default())
+            .unwrap();
+        let physical_device = instance.table().enumerate_physical_devices().unwrap()[0];
+        let device = instance
+            .create_device(physical_device, &vk::DeviceCreateInfo::default())
+            .unwrap();
+        let name = |v: &'static [u8]| CStr::from_bytes_with_nul(v).unwrap();
+        assert!(instance
+            .get_instance_proc_addr(name(b"vkDestroySurfaceKHR\0"))
+            .is_none());
+        assert!(device
+            .get_device_proc_addr(name(b"vkQueuePresentKHR\0"))
+            .is_none());
+
+        driver.set_stub_commands(true);
+        assert!(instance
+            .get_instance_proc_addr(name(b"vkDestroySurfaceKHR\0"))
+            .is_some());
+        assert!(device
+            .get_device_proc_addr(name(b"vkQueuePresentKHR\0"))
+            .is_some());
+        assert!(device
+            .get_device_proc_addr(name(b"vkDestroySurfaceKHR\0"))
+            .is_none());
+        assert!(instance
+            .get_instance_proc_addr(name(b"vkNotACommand\0"))
+            .is_none());
+        driver.set_stub_commands(false);
+    }
+}
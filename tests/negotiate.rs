@@ -0,0 +1,124 @@
+use ash::vk;
+use ash_layer::*;
+
+use std::ffi::CStr;
+use std::ptr;
+
+struct DefaultLayer;
+
+impl Layer for DefaultLayer {}
+
+struct NamedLayer;
+
+impl Layer for NamedLayer {
+    const NAME: &'static str = "VK_LAYER_ash_layer_test_named";
+    const MIN_INTERFACE_VERSION: u32 = 2;
+
+    fn instance_extension_properties() -> Vec<vk::ExtensionProperties> {
+        let name = unsafe { CStr::from_bytes_with_nul_unchecked(b"VK_EXT_named\0") };
+        vec![extension_properties(name, 1)]
+    }
+}
+
+fn interface(version: u32) -> NegotiateLayerInterface {
+    NegotiateLayerInterface {
+        s_type: NegotiateLayerStructType::INTERFACE_STRUCT,
+        p_next: ptr::null_mut(),
+        loader_layer_interface_version: version,
+        pfn_get_instance_proc_addr: None,
+        pfn_get_device_proc_addr: None,
+        pfn_get_physical_device_proc_addr: None,
+    }
+}
+
+#[test]
+fn negotiate_interface_versions() {
+    unsafe {
+        let mut newer = interface(5);
+        assert_eq!(
+            layer_negotiate_loader_layer_interface_version::<DefaultLayer>(&mut newer),
+            vk::Result::SUCCESS
+        );
+        assert_eq!(newer.loader_layer_interface_version, 2);
+        assert!(newer.pfn_get_instance_proc_addr.is_some());
+        assert!(newer.pfn_get_device_proc_addr.is_some());
+        assert!(newer.pfn_get_physical_device_proc_addr.is_some());
+
+        let mut v1 = interface(1);
+        assert_eq!(
+            layer_negotiate_loader_layer_interface_version::<DefaultLayer>(&mut v1),
+            vk::Result::SUCCESS
+        );
+        assert_eq!(v1.loader_layer_interface_version, 1);
+        assert!(v1.pfn_get_instance_proc_addr.is_some());
+        assert!(v1.pfn_get_physical_device_proc_addr.is_none());
+
+        let mut v0 = interface(0);
+        assert_eq!(
+            layer_negotiate_loader_layer_interface_version::<DefaultLayer>(&mut v0),
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+        let mut v1 = interface(1);
+        assert_eq!(
+            layer_negotiate_loader_layer_interface_version::<NamedLayer>(&mut v1),
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+        let mut bad_type = NegotiateLayerInterface {
+            s_type: NegotiateLayerStructType::UNINTIALIZED,
+            ..interface(2)
+        };
+        assert_eq!(
+            layer_negotiate_loader_layer_interface_version::<DefaultLayer>(&mut bad_type),
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+        assert_eq!(
+            layer_negotiate_loader_layer_interface_version::<DefaultLayer>(ptr::null_mut()),
+            vk::Result::ERROR_INITIALIZATION_FAILED
+        );
+    }
+}
+
+#[test]
+fn legacy_enumeration_reports_own_layer() {
+    unsafe {
+        let layers = read_array(|p_count, p_items| {
+            layer_legacy_enumerate_instance_layer_properties::<NamedLayer>(p_count, p_items)
+        })
+        .unwrap();
+        assert_eq!(layers.len(), 1);
+        let name = CStr::from_ptr(layers[0].layer_name.as_ptr());
+        assert_eq!(name.to_bytes(), NamedLayer::NAME.as_bytes());
+        let layers = read_array(|p_count, p_items| {
+            layer_legacy_enumerate_instance_layer_properties::<DefaultLayer>(p_count, p_items)
+        })
+        .unwrap();
+        assert!(layers.is_empty());
+
+        let extensions = read_array(|p_count, p_items| {
+            layer_legacy_enumerate_instance_extension_properties::<NamedLayer>(
+                b"VK_LAYER_ash_layer_test_named\0".as_ptr().cast(),
+                p_count,
+                p_items,
+            )
+        })
+        .unwrap();
+        assert_eq!(extensions.len(), 1);
+        let mut count = 0;
+        assert_eq!(
+            layer_legacy_enumerate_instance_extension_properties::<NamedLayer>(
+                b"VK_LAYER_other\0".as_ptr().cast(),
+                &mut count,
+                ptr::null_mut(),
+            ),
+            vk::Result::ERROR_LAYER_NOT_PRESENT
+        );
+        assert_eq!(
+            layer_legacy_enumerate_instance_extension_properties::<NamedLayer>(
+                ptr::null(),
+                &mut count,
+                ptr::null_mut(),
+            ),
+            vk::Result::ERROR_LAYER_NOT_PRESENT
+        );
+    }
+}
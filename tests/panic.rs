@@ -0,0 +1,204 @@
+#![cfg(feature = "mock")]
+
+use ash::vk;
+use ash_layer::mock::*;
+use ash_layer::*;
+
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::ptr;
+
+trait Policy: 'static {
+    const POLICY: PanicPolicy;
+}
+
+struct ReturnError;
+
+impl Policy for ReturnError {
+    const POLICY: PanicPolicy = PanicPolicy::ReturnError;
+}
+
+struct PassThrough;
+
+impl Policy for PassThrough {
+    const POLICY: PanicPolicy = PanicPolicy::PassThrough;
+}
+
+struct PanickingLayer<P>(PhantomData<P>);
+
+impl<P: Policy> Layer for PanickingLayer<P> {
+    fn panic_policy() -> PanicPolicy {
+        P::POLICY
+    }
+
+    unsafe fn create_instance(
+        _create_info: &vk::InstanceCreateInfo,
+        _instance: &InstanceDispatch,
+    ) -> vk::Result {
+        panic!("create_instance")
+    }
+
+    fn instance_hook(name: &CStr) -> vk::PFN_vkVoidFunction {
+        if name.to_bytes() == b"vkEnumeratePhysicalDevices" {
+            panic!("instance_hook")
+        }
+        None
+    }
+
+    fn enumerate_instance_version(_api_version: u32) -> u32 {
+        panic!("enumerate_instance_version")
+    }
+}
+
+unsafe extern "system" fn driver_enumerate_instance_version(
+    _p_chain: *const EnumerateInstanceVersionChain,
+    p_api_version: *mut u32,
+) -> vk::Result {
+    *p_api_version = vk::API_VERSION_1_2;
+    vk::Result::SUCCESS
+}
+
+#[test]
+fn return_error_policy_fails_calls() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(
+                layer_negotiate_loader_layer_interface_version::<PanickingLayer<ReturnError>>,
+            )
+            .unwrap();
+        assert_eq!(
+            loader.create_instance(&Default::default()).err(),
+            Some(vk::Result::ERROR_INITIALIZATION_FAILED)
+        );
+
+        let chain =
+            EnumerateInstanceVersionChain::new(driver_enumerate_instance_version, ptr::null());
+        let mut version = 0;
+        assert_eq!(
+            layer_enumerate_instance_version::<PanickingLayer<ReturnError>>(&chain, &mut version),
+            vk::Result::ERROR_UNKNOWN
+        );
+    }
+}
+
+#[test]
+fn pass_through_policy_skips_layer() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(
+                layer_negotiate_loader_layer_interface_version::<PanickingLayer<PassThrough>>,
+            )
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        assert_eq!(
+            instance.table().enumerate_physical_devices().unwrap().len(),
+            1
+        );
+        let name = b"vkEnumeratePhysicalDevices\0".as_ptr().cast();
+        assert!((layer.get_instance_proc_addr)(instance.handle(), name).is_some());
+
+        let chain =
+            EnumerateInstanceVersionChain::new(driver_enumerate_instance_version, ptr::null());
+        let mut version = 0;
+        assert_eq!(
+            layer_enumerate_instance_version::<PanickingLayer<PassThrough>>(&chain, &mut version),
+            vk::Result::SUCCESS
+        );
+        assert_eq!(version, vk::API_VERSION_1_2);
+    }
+}
+
+struct PanickingHookLayer;
+
+impl Layer for PanickingHookLayer {
+    fn panic_policy() -> PanicPolicy {
+        PanicPolicy::ReturnError
+    }
+
+    fn instance_hook(name: &CStr) -> vk::PFN_vkVoidFunction {
+        if name.to_bytes() == b"vkEnumeratePhysicalDevices" {
+            panic!("instance_hook")
+        }
+        None
+    }
+}
+
+#[test]
+fn return_error_policy_resolves_null() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<PanickingHookLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let name = CStr::from_bytes_with_nul_unchecked(b"vkEnumeratePhysicalDevices\0");
+        assert!(instance.get_instance_proc_addr(name).is_none());
+    }
+}
+
+struct ReturnErrorHookLayer;
+
+impl Layer for ReturnErrorHookLayer {
+    const HOOKS: HookTable = hook_table![vkEnumeratePhysicalDevices => return_error_enumerate];
+
+    fn panic_policy() -> PanicPolicy {
+        PanicPolicy::ReturnError
+    }
+}
+
+hook!(ReturnErrorHookLayer, vkEnumeratePhysicalDevices =>
+    unsafe fn return_error_enumerate(
+        _instance: vk::Instance,
+        _p_physical_device_count: *mut u32,
+        _p_physical_devices: *mut vk::PhysicalDevice,
+    ) -> vk::Result {
+        panic!("vkEnumeratePhysicalDevices")
+    }
+);
+
+struct PassThroughHookLayer;
+
+impl Layer for PassThroughHookLayer {
+    const HOOKS: HookTable = hook_table![vkEnumeratePhysicalDevices => pass_through_enumerate];
+
+    fn panic_policy() -> PanicPolicy {
+        PanicPolicy::PassThrough
+    }
+}
+
+hook!(PassThroughHookLayer, vkEnumeratePhysicalDevices =>
+    unsafe fn pass_through_enumerate(
+        _instance: vk::Instance,
+        _p_physical_device_count: *mut u32,
+        _p_physical_devices: *mut vk::PhysicalDevice,
+    ) -> vk::Result {
+        panic!("vkEnumeratePhysicalDevices")
+    }
+);
+
+#[test]
+fn hooks_apply_panic_policy() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<ReturnErrorHookLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        assert_eq!(
+            instance.table().enumerate_physical_devices().err(),
+            Some(vk::Result::ERROR_UNKNOWN)
+        );
+
+        let mut loader = MockLoader::new();
+        loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<PassThroughHookLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        assert_eq!(
+            instance.table().enumerate_physical_devices().unwrap().len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "mock")]
+
+use ash::vk;
+use ash_layer::mock::*;
+use ash_layer::*;
+
+use std::ffi::{c_char, CStr};
+use std::mem;
+use std::sync::Mutex;
+
+const FOO: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"vkFooEXT\0") };
+const BAR: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"vkBarEXT\0") };
+
+unsafe extern "system" fn foo_impl(_physical_device: vk::PhysicalDevice) -> vk::Result {
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn foo_wrap(_physical_device: vk::PhysicalDevice) -> vk::Result {
+    vk::Result::INCOMPLETE
+}
+
+/// Wraps `vkFooEXT` of the layers below.
+struct WrappingLayer;
+
+impl Layer for WrappingLayer {
+    fn physical_device_hook(name: &CStr) -> vk::PFN_vkVoidFunction {
+        (name == FOO).then(|| unsafe { mem::transmute(foo_wrap as PFN_PhysDevExt) })
+    }
+}
+
+/// Implements `vkFooEXT` itself.
+struct ImplementingLayer;
+
+impl Layer for ImplementingLayer {
+    fn physical_device_hook(name: &CStr) -> vk::PFN_vkVoidFunction {
+        (name == FOO).then(|| unsafe { mem::transmute(foo_impl as PFN_PhysDevExt) })
+    }
+
+    fn implements_command(name: &CStr) -> bool {
+        name == FOO
+    }
+}
+
+// Hand-written layer implementing `vkFooEXT`, so that it can sit below an ash-layer layer
+// without sharing its dispatch registries.
+
+static RAW_NEXT: Mutex<Option<vk::PFN_vkGetInstanceProcAddr>> = Mutex::new(None);
+
+unsafe extern "system" fn raw_create_instance(
+    p_create_info: *const vk::InstanceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    let next = advance_instance_link(&*p_create_info).ok().unwrap();
+    *RAW_NEXT.lock().unwrap() = Some(next.get_instance_proc_addr);
+    let name = b"vkCreateInstance\0".as_ptr().cast();
+    let create_instance: vk::PFN_vkCreateInstance =
+        mem::transmute((next.get_instance_proc_addr)(vk::Instance::null(), name).unwrap());
+    create_instance(p_create_info, p_allocator, p_instance)
+}
+
+unsafe extern "system" fn raw_get_instance_proc_addr(
+    instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    if CStr::from_ptr(p_name).to_bytes() == b"vkCreateInstance" {
+        return Some(mem::transmute::<
+            vk::PFN_vkCreateInstance,
+            unsafe extern "system" fn(),
+        >(raw_create_instance));
+    }
+    (RAW_NEXT.lock().unwrap().unwrap())(instance, p_name)
+}
+
+unsafe extern "system" fn raw_get_physical_device_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::PFN_vkVoidFunction {
+    (CStr::from_ptr(p_name) == FOO).then(|| mem::transmute(foo_impl as PFN_PhysDevExt))
+}
+
+unsafe extern "system" fn raw_negotiate(
+    p_version_struct: *mut NegotiateLayerInterface,
+) -> vk::Result {
+    (*p_version_struct).pfn_get_instance_proc_addr = Some(raw_get_instance_proc_addr);
+    (*p_version_struct).pfn_get_physical_device_proc_addr = Some(raw_get_physical_device_proc_addr);
+    vk::Result::SUCCESS
+}
+
+#[test]
+fn wrapping_hook_hidden_without_support_below() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<WrappingLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let gphypa = layer.get_physical_device_proc_addr.unwrap();
+        assert!(gphypa(instance.handle(), FOO.as_ptr()).is_none());
+    }
+}
+
+#[test]
+fn implementing_hook_exposed_alone() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<ImplementingLayer>)
+            .unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let gphypa = layer.get_physical_device_proc_addr.unwrap();
+        assert!(gphypa(instance.handle(), FOO.as_ptr()).is_some());
+        assert!(gphypa(instance.handle(), BAR.as_ptr()).is_none());
+    }
+}
+
+#[test]
+fn wrapping_hook_exposed_and_calls_down() {
+    unsafe {
+        let mut loader = MockLoader::new();
+        let layer = loader
+            .add_layer(layer_negotiate_loader_layer_interface_version::<WrappingLayer>)
+            .unwrap();
+        loader.add_layer(raw_negotiate).unwrap();
+        let instance = loader.create_instance(&Default::default()).unwrap();
+        let gphypa = layer.get_physical_device_proc_addr.unwrap();
+        let foo: PFN_PhysDevExt = mem::transmute(gphypa(instance.handle(), FOO.as_ptr()).unwrap());
+        assert_eq!(foo(vk::PhysicalDevice::null()), vk::Result::INCOMPLETE);
+        assert!(gphypa(instance.handle(), BAR.as_ptr()).is_none());
+
+        let dispatch = InstanceDispatch::from_handle(instance.handle()).unwrap();
+        let foo: PFN_PhysDevExt =
+            mem::transmute(dispatch.get_physical_device_proc_addr(FOO).unwrap());
+        assert_eq!(foo(vk::PhysicalDevice::null()), vk::Result::SUCCESS);
+        assert!(dispatch.get_physical_device_proc_addr(BAR).is_none());
+    }
+}
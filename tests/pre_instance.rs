@@ -0,0 +1,128 @@
+use ash::vk;
+use ash_layer::*;
+
+use std::ffi::{c_char, CStr};
+use std::mem;
+use std::ptr;
+
+unsafe extern "system" fn driver_enumerate_instance_version(
+    _p_chain: *const EnumerateInstanceVersionChain,
+    p_api_version: *mut u32,
+) -> vk::Result {
+    *p_api_version = vk::API_VERSION_1_3;
+    vk::Result::SUCCESS
+}
+
+#[test]
+fn call_down_validates_chain_link() {
+    unsafe {
+        let chain =
+            EnumerateInstanceVersionChain::new(driver_enumerate_instance_version, ptr::null());
+        let mut version = 0;
+        let size = mem::size_of::<EnumerateInstanceVersionChain>() as u32;
+        assert!(chain.call_down(&mut version) == Ok(vk::Result::SUCCESS));
+        assert_eq!(version, vk::API_VERSION_1_3);
+
+        for header in [
+            ChainHeader::new(ChainType::ENUMERATE_INSTANCE_LAYER_PROPERTIES, 1, size),
+            ChainHeader::new(ChainType::ENUMERATE_INSTANCE_VERSION, 0, size),
+            ChainHeader::new(ChainType::ENUMERATE_INSTANCE_VERSION, 1, size - 1),
+        ] {
+            let invalid = EnumerateInstanceVersionChain { header, ..chain };
+            assert!(invalid.call_down(&mut version) == Err(ChainError::InvalidHeader(header)));
+        }
+
+        // newer loaders may append fields to the link
+        let header = ChainHeader::new(ChainType::ENUMERATE_INSTANCE_VERSION, 2, size + 8);
+        let newer = EnumerateInstanceVersionChain { header, ..chain };
+        version = 0;
+        assert!(newer.call_down(&mut version) == Ok(vk::Result::SUCCESS));
+        assert_eq!(version, vk::API_VERSION_1_3);
+
+        let unlinked = EnumerateInstanceVersionChain {
+            pfn_next_layer: None,
+            ..chain
+        };
+        assert!(
+            unlinked.call_down(&mut version)
+                == Err(ChainError::MissingProcAddr("vkEnumerateInstanceVersion"))
+        );
+    }
+}
+
+struct FilteringLayer;
+
+impl Layer for FilteringLayer {
+    fn enumerate_instance_extensions(extensions: &mut Vec<vk::ExtensionProperties>) {
+        extensions.retain(|v| unsafe { CStr::from_ptr(v.extension_name.as_ptr()) } != HIDDEN);
+        extensions.push(extension_properties(OWN, 3));
+    }
+
+    fn enumerate_instance_version(api_version: u32) -> u32 {
+        api_version.min(vk::API_VERSION_1_1)
+    }
+}
+
+const HIDDEN: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"VK_EXT_hidden\0") };
+const KEPT: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"VK_EXT_kept\0") };
+const OWN: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"VK_EXT_own\0") };
+
+unsafe extern "system" fn driver_enumerate_instance_extension_properties(
+    _p_chain: *const EnumerateInstanceExtensionPropertiesChain,
+    _p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let extensions = [
+        extension_properties(HIDDEN, 1),
+        extension_properties(KEPT, 1),
+    ];
+    write_array(&extensions, p_property_count, p_properties)
+}
+
+#[test]
+fn layer_filters_pre_instance_queries() {
+    unsafe {
+        let chain = EnumerateInstanceExtensionPropertiesChain::new(
+            driver_enumerate_instance_extension_properties,
+            ptr::null(),
+        );
+        let extensions = read_array(|p_count, p_items| {
+            layer_enumerate_instance_extension_properties::<FilteringLayer>(
+                &chain,
+                ptr::null(),
+                p_count,
+                p_items,
+            )
+        })
+        .unwrap();
+        let names: Vec<_> = extensions
+            .iter()
+            .map(|v| CStr::from_ptr(v.extension_name.as_ptr()))
+            .collect();
+        assert_eq!(names, [KEPT, OWN]);
+        assert_eq!(extensions[1].spec_version, 3);
+
+        let mut count = 1;
+        let mut first = [vk::ExtensionProperties::default()];
+        assert_eq!(
+            layer_enumerate_instance_extension_properties::<FilteringLayer>(
+                &chain,
+                ptr::null(),
+                &mut count,
+                first.as_mut_ptr(),
+            ),
+            vk::Result::INCOMPLETE
+        );
+        assert_eq!(CStr::from_ptr(first[0].extension_name.as_ptr()), KEPT);
+
+        let chain =
+            EnumerateInstanceVersionChain::new(driver_enumerate_instance_version, ptr::null());
+        let mut version = 0;
+        assert_eq!(
+            layer_enumerate_instance_version::<FilteringLayer>(&chain, &mut version),
+            vk::Result::SUCCESS
+        );
+        assert_eq!(version, vk::API_VERSION_1_1);
+    }
+}
@@ -0,0 +1,77 @@
+use ash::vk;
+use ash_layer::*;
+
+use std::ffi::{c_char, CStr};
+use std::ptr;
+
+#[test]
+fn raw_views_treat_null_as_empty() {
+    unsafe {
+        assert!(slice_from_raw::<u32>(ptr::null(), 3).is_empty());
+        let items = [1u32, 2, 3];
+        assert_eq!(slice_from_raw(items.as_ptr(), 2), [1, 2]);
+        assert!(c_str_from_raw(ptr::null()).is_none());
+        assert_eq!(
+            c_str_from_raw(b"A\0".as_ptr().cast()).map(CStr::to_bytes),
+            Some(&b"A"[..])
+        );
+    }
+}
+
+#[test]
+fn c_str_list_skips_null_names() {
+    unsafe {
+        let names: [*const c_char; 3] = [
+            b"VK_A\0".as_ptr().cast(),
+            ptr::null(),
+            b"VK_B\0".as_ptr().cast(),
+        ];
+        let list = CStrList::new(3, names.as_ptr());
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().count(), 2);
+        assert!(list.contains(CStr::from_bytes_with_nul_unchecked(b"VK_B\0")));
+        assert!(!list.contains(CStr::from_bytes_with_nul_unchecked(b"VK_C\0")));
+        assert!(CStrList::new(3, ptr::null()).is_empty());
+
+        let create_info = vk::DeviceCreateInfo::builder().enabled_extension_names(&names[..1]);
+        let extensions = CStrList::device_extensions(&create_info);
+        assert!(extensions.contains(CStr::from_bytes_with_nul_unchecked(b"VK_A\0")));
+    }
+}
+
+#[test]
+fn out_array_queries_count() {
+    unsafe {
+        let mut count = 0;
+        let mut out = OutArray::<u32>::new(&mut count, ptr::null_mut());
+        assert!(out.is_query());
+        assert_eq!(out.capacity(), None);
+        out.extend([1, 2, 3]);
+        assert_eq!(out.finish(), vk::Result::SUCCESS);
+        assert_eq!(count, 3);
+    }
+}
+
+#[test]
+fn out_array_reports_incomplete() {
+    unsafe {
+        let mut items = [0u32; 2];
+        let mut count = 2;
+        let mut out = OutArray::new(&mut count, items.as_mut_ptr());
+        assert_eq!(out.capacity(), Some(2));
+        assert!(out.push(1));
+        out.extend([2, 3]);
+        assert!(!out.push(4));
+        assert_eq!(out.as_slice(), [1, 2]);
+        assert_eq!(out.finish(), vk::Result::INCOMPLETE);
+        assert_eq!((count, items), (2, [1, 2]));
+
+        let mut items = [0u32; 5];
+        let mut count = 5;
+        let mut out = OutArray::new(&mut count, items.as_mut_ptr());
+        out.extend([7, 8]);
+        assert_eq!(out.finish(), vk::Result::SUCCESS);
+        assert_eq!(count, 2);
+        assert_eq!(items[..2], [7, 8]);
+    }
+}